@@ -0,0 +1,129 @@
+// config.rs - Logging configuration
+// Log folder, rotation and retention, set through API, environment variable or config file
+//
+// 2026-10-19   PV      First version
+// 2026-10-19   PV      json option
+// 2026-10-19   PV      No retention by default, old log files are only deleted on request
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::expand_tilde;
+
+/// Environment variable overriding log folder
+pub const LOG_DIR_ENV_VAR: &str = "LOGGING_DIR";
+
+/// Where log files are written, and how long they are kept.
+/// Each run creates a new file {app_name}-{timestamp}.log in dir
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogConfig {
+    pub dir: Option<PathBuf>,        // None for OS temp folder, C:\Temp on Windows, ~/temp on Linux, ~/Temp on MacOS
+    pub max_size: Option<u64>,       // In bytes, a new file is started when current file reaches this size
+    pub max_files: Option<usize>,    // Maximum number of log files kept per app, oldest are deleted
+    pub retention_days: Option<u64>, // Log files of the app older than this are deleted, None (default) keeps them
+    pub json: bool,                  // Also write JSON-lines records in {app_name}-{timestamp}.jsonl
+}
+
+impl LogConfig {
+    pub fn dir(mut self, dir: &Path) -> Self {
+        self.dir = Some(dir.to_path_buf());
+        self
+    }
+
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    pub fn max_files(mut self, count: usize) -> Self {
+        self.max_files = Some(count);
+        self
+    }
+
+    /// 0 keeps log files forever
+    pub fn retention_days(mut self, days: u64) -> Self {
+        self.retention_days = if days == 0 { None } else { Some(days) };
+        self
+    }
+
+    pub fn json(mut self, json: bool) -> Self {
+        self.json = json;
+        self
+    }
+
+    /// Parses a config file such as:
+    /// dir = "~/logs"
+    /// max_size = 10000000
+    /// max_files = 20
+    /// retention_days = 7
+    /// json = true
+    /// Missing keys keep default values
+    pub fn from_toml_str(content: &str) -> Result<LogConfig, String> {
+        let table = content.parse::<toml::Table>().map_err(|e| format!("Invalid logging config: {e}"))?;
+        let mut config = LogConfig::default();
+        for (key, value) in table.iter() {
+            let int_value = || {
+                value
+                    .as_integer()
+                    .and_then(|i| u64::try_from(i).ok())
+                    .ok_or(format!("Invalid logging config: {key} must be a positive integer"))
+            };
+            match key.as_str() {
+                "dir" => {
+                    let dir = value.as_str().ok_or("Invalid logging config: dir must be a string")?;
+                    config.dir = Some(expand_tilde(dir));
+                }
+                "max_size" => config.max_size = Some(int_value()?),
+                "max_files" => config.max_files = Some(int_value()? as usize),
+                "retention_days" => config = config.retention_days(int_value()?),
+                "json" => config.json = value.as_bool().ok_or("Invalid logging config: json must be true or false")?,
+                _ => return Err(format!("Invalid logging config: unknown key {key}")),
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn load_file(path: &Path) -> Result<LogConfig, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("Can't read logging config file {}: {e}", path.display()))?;
+        LogConfig::from_toml_str(&content)
+    }
+
+    /// Default config file location, logging/logging.toml in user config folder
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|d| d.join("logging").join("logging.toml"))
+    }
+
+    /// Loads config file from default location if it exists, then LOGGING_DIR environment variable overrides log folder.
+    /// An invalid config file is reported on stderr, and default config is used
+    pub fn load_default() -> LogConfig {
+        let mut config = match LogConfig::default_path().filter(|p| p.is_file()) {
+            Some(path) => LogConfig::load_file(&path).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                LogConfig::default()
+            }),
+            None => LogConfig::default(),
+        };
+        if let Ok(dir) = env::var(LOG_DIR_ENV_VAR)
+            && !dir.is_empty()
+        {
+            config.dir = Some(expand_tilde(&dir));
+        }
+        config
+    }
+
+    /// Log folder, configured one or OS default
+    pub fn log_dir(&self) -> Option<PathBuf> {
+        if let Some(dir) = &self.dir {
+            Some(dir.clone())
+        } else if cfg!(target_os = "windows") {
+            Some(PathBuf::from("C:\\Temp"))
+        } else if cfg!(target_os = "linux") {
+            Some(expand_tilde(r"~/temp"))
+        } else if cfg!(target_os = "macos") {
+            Some(expand_tilde(r"~/Temp"))
+        } else {
+            None
+        }
+    }
+}
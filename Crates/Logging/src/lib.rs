@@ -6,6 +6,14 @@
 // 2025-05-05   PV      Moved to a crate and added support for MacOS and Linux
 // 2025-09-15   PV      1.1: Debugging lines with prefix dbg: are shown in cyan; LogWriter now a struct with path field; logwriter_none()
 // 2025-10-22   PV      Clippy review
+// 2026-10-19   PV      1.2.0 Levels error/warn/info/debug/trace with separate console and file thresholds, and per-target thresholds; log crate facade
+// 2026-10-19   PV      1.3.0 Log folder configurable through API, LOGGING_DIR and config file, created if missing; size/count rotation and retention
+// 2026-10-19   PV      1.4.0 Optional JSON-lines sink with timestamp, app, version, level, message and structured fields; logln_fields
+// 2026-10-19   PV      2.0.0 LogWriter is a cloneable Send+Sync handle, logging functions take &LogWriter; get_path takes &self
+// 2026-10-19   PV      2.1.0 Counters, timed spans, error totals and end-of-run summary; s() plural helper
+// 2026-10-19   PV      2.1.1 No retention by default; retention limit computed without overflow
+// 2026-10-19   PV      2.2.0 json_string is public, shared with apps writing JSON
+// 2026-10-19   PV      2.2.1 Pending text of log() calls kept per thread, JSON records of different threads don't mix
 
 //#[allow(unused)]
 
@@ -14,16 +22,27 @@
 // -----------------------------------
 // Submodules
 
+mod config;
+pub use config::{LOG_DIR_ENV_VAR, LogConfig};
+
+mod summary;
+pub use summary::{Span, s};
+
+mod tests;
+
 // Standard library imports
-use std::fs::File;
-use std::io::{BufWriter, Write};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant, SystemTime};
 
 // External crates imports
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, SecondsFormat};
 use colored::*;
-
-mod tests;
+pub use log::{Level, LevelFilter, SetLoggerError};
 
 // -----------------------------------
 // Globals
@@ -36,90 +55,459 @@ pub fn version() -> &'static str {
 
 // -----------------------------------
 
+/// Handle on a log. Clones share the same files and can be sent to other threads; each line is written under a lock,
+/// so lines of different threads don't interleave and lines of a thread keep their order. Files are flushed when a
+/// handle is dropped
+#[derive(Clone)]
 pub struct LogWriter {
+    state: Arc<Mutex<LogState>>,
+}
+
+// Shared between LogWriter and log facade
+struct LogState {
     writer_opt: Option<BufWriter<File>>,
     path: Option<PathBuf>,
+    json_opt: Option<BufWriter<File>>,
+    json_path: Option<PathBuf>,
+    pending: HashMap<ThreadId, String>, // Text of log() calls without newline per thread, JSON record is written when line is complete
+    app_name: String,
+    app_version: String,
+    rotation: Option<Rotation>,
+    start: Instant,
+    counters: Vec<(String, u64)>,    // In order of creation
+    phases: Vec<(String, Duration)>, // Total time of spans, per name
+    errors: u64,
+    console_level: LevelFilter,
+    file_level: LevelFilter,
+    targets: Vec<(String, LevelFilter)>,
+}
+
+// Size-based rotation: when current file reaches max_size, writing continues in {stem}.2.log, {stem}.3.log...
+struct Rotation {
+    max_size: u64,
+    written: u64,
+    index: usize,
+    stem: String,
+    app_name: String,
+    config: LogConfig,
+}
+
+// Forwards records of log crate macros (error!, debug!, ...) emitted by other crates to a LogWriter
+struct FacadeLogger {
+    state: Arc<Mutex<LogState>>,
 }
 
 pub fn logwriter_none() -> LogWriter {
-    LogWriter {
-        writer_opt: None,
-        path: None,
-    }
+    LogWriter::with_writer(None, None, None, "", "")
 }
 
 impl LogWriter {
-    pub fn get_path(self) -> Option<PathBuf> {
-        self.path.clone()
+    fn with_writer(
+        writer_opt: Option<BufWriter<File>>,
+        path: Option<PathBuf>,
+        rotation: Option<Rotation>,
+        app_name: &str,
+        app_version: &str,
+    ) -> LogWriter {
+        LogWriter {
+            state: Arc::new(Mutex::new(LogState {
+                writer_opt,
+                path,
+                json_opt: None,
+                json_path: None,
+                pending: HashMap::new(),
+                app_name: app_name.to_string(),
+                app_version: app_version.to_string(),
+                rotation,
+                start: Instant::now(),
+                counters: Vec::new(),
+                phases: Vec::new(),
+                errors: 0,
+                console_level: LevelFilter::Trace,
+                file_level: LevelFilter::Trace,
+                targets: Vec::new(),
+            })),
+        }
+    }
+
+    /// Path of current log file, None if there is no log file
+    pub fn get_path(&self) -> Option<PathBuf> {
+        self.lock().path.clone()
+    }
+
+    /// Writes buffered lines to log files
+    pub fn flush(&self) {
+        self.lock().flush();
+    }
+
+    /// Path of current JSON-lines log file, None if there is no JSON log
+    pub fn get_json_path(&self) -> Option<PathBuf> {
+        self.lock().json_path.clone()
+    }
+
+    /// Also writes each message as a JSON record on a line of file path, with timestamp, app, version, level, target,
+    /// message and fields. Records follow file and target thresholds. JSON file is not rotated
+    pub fn set_json_file(&self, path: &Path) -> io::Result<()> {
+        let file = File::create(path)?;
+        let mut state = self.lock();
+        if let Some(bw) = state.json_opt.as_mut() {
+            let _ = bw.flush();
+        }
+        state.json_opt = Some(BufWriter::new(file));
+        state.json_path = Some(path.to_path_buf());
+        Ok(())
+    }
+
+    /// Messages less severe than level are not printed on console. Default is Trace, all messages are printed
+    pub fn set_console_level(&self, level: LevelFilter) {
+        self.lock().console_level = level;
+    }
+
+    /// Messages less severe than level are not written to log file. Default is Trace, all messages are written
+    pub fn set_file_level(&self, level: LevelFilter) {
+        self.lock().file_level = level;
+    }
+
+    /// Additional threshold for messages of a target and its submodules, such as "myglob"; most specific target wins
+    pub fn set_target_level(&self, target: &str, level: LevelFilter) {
+        let mut state = self.lock();
+        state.targets.retain(|(t, _)| t != target);
+        state.targets.push((target.to_string(), level));
+    }
+
+    /// Sends messages of log crate macros to this LogWriter, up to level. Records are formatted as "LEVEL target: message".
+    /// Can only be called once per process, since log crate accepts a single logger
+    pub fn install_log_facade(&self, level: LevelFilter) -> Result<(), SetLoggerError> {
+        log::set_boxed_logger(Box::new(FacadeLogger {
+            state: Arc::clone(&self.state),
+        }))?;
+        log::set_max_level(level);
+        Ok(())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LogState> {
+        // A panic while logging doesn't prevent further logging
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// Files are shared with clones and log facade, if installed, so they must be flushed explicitly
+impl Drop for LogWriter {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+impl LogState {
+    fn flush(&mut self) {
+        if let Some(bw) = self.writer_opt.as_mut() {
+            let _ = bw.flush();
+        }
+        if let Some(bw) = self.json_opt.as_mut() {
+            let _ = bw.flush();
+        }
+    }
+
+    fn target_allows(&self, level: Level, target: &str) -> bool {
+        let filter = self
+            .targets
+            .iter()
+            .filter(|(t, _)| target == t || target.strip_prefix(t.as_str()).is_some_and(|rest| rest.starts_with("::")))
+            .max_by_key(|(t, _)| t.len());
+        filter.is_none_or(|(_, f)| level <= *f)
+    }
+
+    // Writes msg to console and text file, and to JSON file with fields once line is complete
+    fn write(&mut self, level: Level, target: &str, msg: &str, newline: bool, fields: &[(&str, &str)]) {
+        if level == Level::Error && newline {
+            self.errors += 1;
+        }
+        if !self.target_allows(level, target) {
+            return;
+        }
+        self.write_text(level, msg, newline);
+        if self.json_opt.is_some() {
+            if newline {
+                let message = self.pending.remove(&thread::current().id()).unwrap_or_default() + msg;
+                self.write_json(level, target, &message, fields);
+            } else {
+                self.pending.entry(thread::current().id()).or_default().push_str(msg);
+            }
+        }
+    }
+
+    fn write_text(&mut self, level: Level, msg: &str, newline: bool) {
+        if level <= self.console_level {
+            let colored_msg = match level {
+                Level::Error => msg.red().bold(),
+                Level::Warn => msg.yellow(),
+                Level::Info => msg.normal(),
+                Level::Debug => msg.cyan(),
+                Level::Trace => msg.bright_black(),
+            };
+            if newline {
+                println!("{colored_msg}");
+            } else {
+                print!("{colored_msg}");
+            }
+        }
+
+        if level <= self.file_level
+            && let Some(bw) = self.writer_opt.as_mut()
+        {
+            let _ = if newline { writeln!(bw, "{msg}") } else { write!(bw, "{msg}") };
+            if let Some(rotation) = self.rotation.as_mut() {
+                rotation.written += msg.len() as u64 + newline as u64;
+                // Only rotate at the end of a line
+                if newline && rotation.written >= rotation.max_size {
+                    self.rotate();
+                }
+            }
+        }
+    }
+
+    fn write_json(&mut self, level: Level, target: &str, message: &str, fields: &[(&str, &str)]) {
+        if level > self.file_level {
+            return;
+        }
+        let Some(bw) = self.json_opt.as_mut() else { return };
+
+        let timestamp = Local::now().to_rfc3339_opts(SecondsFormat::Millis, false);
+        let mut record = format!(
+            "{{\"timestamp\":{},\"app\":{},\"version\":{},\"level\":{}",
+            json_string(&timestamp),
+            json_string(&self.app_name),
+            json_string(&self.app_version),
+            json_string(level.as_str())
+        );
+        if !target.is_empty() {
+            record += &format!(",\"target\":{}", json_string(target));
+        }
+        record += &format!(",\"message\":{}", json_string(message));
+        if !fields.is_empty() {
+            let fields: Vec<String> = fields.iter().map(|(k, v)| format!("{}:{}", json_string(k), json_string(v))).collect();
+            record += &format!(",\"fields\":{{{}}}", fields.join(","));
+        }
+        record.push('}');
+        let _ = writeln!(bw, "{record}");
+    }
+
+    // Closes current file and continues in next one, on error logging continues in current file
+    fn rotate(&mut self) {
+        let Some(rotation) = self.rotation.as_mut() else { return };
+        let Some(dir) = self.path.as_ref().and_then(|p| p.parent()).map(|p| p.to_path_buf()) else { return };
+
+        let next_path = dir.join(format!("{}.{}.log", rotation.stem, rotation.index + 1));
+        match File::create(&next_path) {
+            Ok(file) => {
+                if let Some(bw) = self.writer_opt.as_mut() {
+                    let _ = bw.flush();
+                }
+                self.writer_opt = Some(BufWriter::new(file));
+                rotation.index += 1;
+                rotation.written = 0;
+                cleanup_logs(&dir, &rotation.app_name, &rotation.config, &next_path);
+                self.path = Some(next_path);
+            }
+            Err(e) => {
+                eprintln!("{}: Error creating log file {}, log continues in current file: {e}", rotation.app_name, next_path.display());
+                rotation.max_size = u64::MAX;
+            }
+        }
     }
 }
 
+impl log::Log for FacadeLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        // Errors are always processed so they are counted
+        let level = metadata.level();
+        level == Level::Error
+            || ((level <= state.console_level || level <= state.file_level) && state.target_allows(level, metadata.target()))
+    }
+
+    fn log(&self, record: &log::Record) {
+        let level = record.level();
+        let target = record.target();
+        let message = record.args().to_string();
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if level == Level::Error {
+            state.errors += 1;
+        }
+        if state.target_allows(level, target) {
+            // Text log identifies level and target on the line, JSON record has them as separate members
+            state.write_text(level, &format!("{level} {target}: {message}"), true);
+            state.write_json(level, target, &message, &[]);
+        }
+    }
+
+    fn flush(&self) {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).flush();
+    }
+}
+
+/// Quoted JSON string with escaped special characters
+pub fn json_string(s: &str) -> String {
+    let mut res = String::with_capacity(s.len() + 2);
+    res.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if (c as u32) < 0x20 => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
 // -----------------------------------
 
-pub fn logln(lw: &mut LogWriter, msg: &str) {
+// Level of a message logged with logln: *** prefix is an error, dbg: prefix is debug info, others are info
+fn level_from_prefix(msg: &str) -> Level {
     if msg.starts_with("***") {
-        println!("{}", msg.red().bold());
+        Level::Error
     } else if msg.starts_with("dbg:") {
-        println!("{}", msg.cyan());
+        Level::Debug
     } else {
-        println!("{}", msg);
-    }
-    if let Some(bw) = lw.writer_opt.as_mut() {
-        let _ = writeln!(bw, "{}", msg);
+        Level::Info
     }
 }
 
+pub fn logln(lw: &LogWriter, msg: &str) {
+    logln_level(lw, level_from_prefix(msg), msg);
+}
+
+// Writes msg without newline; in text file and console, a line built with several log calls may be interleaved with
+// lines of other threads sharing the log, JSON record is built per thread and written when the line is complete
 #[allow(unused)]
-pub fn log(lw: &mut LogWriter, msg: &str) {
-    print!("{}", msg);
-    if let Some(bw) = lw.writer_opt.as_mut() {
-        let _ = write!(bw, "{}", msg);
-    }
+pub fn log(lw: &LogWriter, msg: &str) {
+    lw.lock().write(Level::Info, "", msg, false, &[]);
+}
+
+/// Logs a message with an explicit level, message is written as is
+pub fn logln_level(lw: &LogWriter, level: Level, msg: &str) {
+    lw.lock().write(level, "", msg, true, &[]);
+}
+
+/// Logs a message with an explicit level for a target, subject to target threshold set with set_target_level
+pub fn logln_target(lw: &LogWriter, target: &str, level: Level, msg: &str) {
+    lw.lock().write(level, target, msg, true, &[]);
+}
+
+/// Logs a message with key/value fields, such as ("operation", "rename"), ("path", ...). Console and text log only
+/// show message, fields are included in JSON record if a JSON log is active
+pub fn logln_fields(lw: &LogWriter, level: Level, msg: &str, fields: &[(&str, &str)]) {
+    lw.lock().write(level, "", msg, true, fields);
 }
 
 // Create a new logging file, and if verbose is true, write app name+version on first line
+// Log folder, rotation and retention come from config file and environment, see LogConfig::load_default
 pub fn new(app_name: &str, app_version: &str, verbose: bool) -> LogWriter {
+    new_with_config(app_name, app_version, verbose, &LogConfig::load_default())
+}
+
+/// Same as new, with an explicit configuration. Log folder is created if needed, and old log files of the app are
+/// deleted according to config retention_days and max_files
+pub fn new_with_config(app_name: &str, app_version: &str, verbose: bool, config: &LogConfig) -> LogWriter {
     let now: DateTime<Local> = Local::now();
     let formatted_now = now.format("%Y-%m-%d-%H.%M.%S");
 
-    let tmp_folder = if cfg!(target_os = "windows") {
-        PathBuf::from("C:\\Temp")
-    } else if cfg!(target_os = "linux") {
-        expand_tilde(r"~/temp")
-    } else if cfg!(target_os = "macos") {
-        expand_tilde(r"~/Temp")
-    } else {
+    let Some(log_folder) = config.log_dir() else {
         eprintln!("{app_name}: OS not recognized when creating LogWriter, no log created.");
         return logwriter_none();
     };
-    if !tmp_folder.is_dir() {
-        eprintln!("{app_name}: Can't find temp folder {}, no log created.", tmp_folder.display());
+    if let Err(e) = fs::create_dir_all(&log_folder) {
+        eprintln!("{app_name}: Can't create log folder {}, no log created: {e}", log_folder.display());
         return logwriter_none();
     }
-    let dir_sep = if cfg!(target_os = "windows") { '\\' } else { '/' };
-    let logpath = format!("{}{dir_sep}{app_name}-{formatted_now}.log", tmp_folder.display());
-    let file = File::create(logpath.clone());
+    let stem = format!("{app_name}-{formatted_now}");
+    let logpath = log_folder.join(format!("{stem}.log"));
+    let file = File::create(&logpath);
     if file.is_err() {
         logln(
-            &mut logwriter_none(),
-            format!("{app_name}: Error creating log file {logpath}, no log created: {:?}", file.err()).as_str(),
+            &logwriter_none(),
+            format!("{app_name}: Error creating log file {}, no log created: {:?}", logpath.display(), file.err()).as_str(),
         );
         return logwriter_none();
     }
     let writer = Some(BufWriter::new(file.unwrap()));
+    cleanup_logs(&log_folder, app_name, config, &logpath);
 
-    let mut lw = LogWriter {
-        writer_opt: writer,
-        path: Some(PathBuf::from(logpath)),
-    };
+    let rotation = config.max_size.map(|max_size| Rotation {
+        max_size,
+        written: 0,
+        index: 1,
+        stem: stem.clone(),
+        app_name: app_name.to_string(),
+        config: config.clone(),
+    });
+    let json_path = log_folder.join(format!("{stem}.jsonl"));
+    let lw = LogWriter::with_writer(writer, Some(logpath), rotation, app_name, app_version);
+    if config.json {
+        match lw.set_json_file(&json_path) {
+            Ok(()) => cleanup_logs(&log_folder, app_name, config, &json_path),
+            Err(e) => eprintln!("{app_name}: Error creating JSON log file {}, no JSON log created: {e}", json_path.display()),
+        }
+    }
 
     if verbose {
-        logln(&mut lw, &format!("{app_name} {app_version}"));
+        logln(&lw, &format!("{app_name} {app_version}"));
     }
 
     lw
 }
 
+// Deletes log files of app older than retention, then oldest ones beyond max_files; current file is always kept.
+// Only files with the same extension as current file (log or jsonl) are considered
+fn cleanup_logs(dir: &Path, app_name: &str, config: &LogConfig, current: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    let extension = format!(".{}", current.extension().unwrap_or_default().to_string_lossy());
+
+    // Only files named {app_name}-{timestamp}...log are considered, so other apps logs are left alone
+    let prefix = format!("{app_name}-");
+    let mut logs: Vec<(SystemTime, PathBuf)> = entries
+        .flatten()
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            name.ends_with(&extension) && name.strip_prefix(&prefix).is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+        })
+        .filter(|e| e.path() != current)
+        .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+        .collect();
+    logs.sort();
+
+    let remove = |path: &Path| {
+        if let Err(e) = fs::remove_file(path) {
+            eprintln!("{app_name}: Can't delete old log file {}: {e}", path.display());
+        }
+    };
+
+    // A retention too long to be represented as a time is the same as no retention
+    if let Some(days) = config.retention_days
+        && let Some(limit) = days
+            .checked_mul(86_400)
+            .and_then(|secs| SystemTime::now().checked_sub(Duration::from_secs(secs)))
+    {
+        let expired = logs.iter().take_while(|(t, _)| *t < limit).count();
+        for (_, path) in logs.drain(..expired) {
+            remove(&path);
+        }
+    }
+
+    if let Some(max_files) = config.max_files {
+        // Current file counts as one
+        let excess = (logs.len() + 1).saturating_sub(max_files.max(1));
+        for (_, path) in logs.drain(..excess) {
+            remove(&path);
+        }
+    }
+}
+
 fn expand_tilde(path: &str) -> PathBuf {
     if let Some(mut stripped) = path.strip_prefix("~")
         && let Some(home) = dirs::home_dir()
@@ -12,15 +12,20 @@ use std::{fs, io};
 use dirs as _;
 use chrono as _;
 use colored as _;
+use log as _;
+use toml as _;
+#[cfg(test)]
+use tempfile as _;
 
 fn main() -> io::Result<()> {
     println!("Crate version: {}\n", logging::version());
 
-    let mut lw = logging::new("test", "1.1.0", true);
-    logln(&mut lw, "Hello");
-    logln(&mut lw, "*** Error: message");
-    logln(&mut lw, "dbg: Debugging info");
+    let lw = logging::new("test", "1.1.0", true);
+    logln(&lw, "Hello");
+    logln(&lw, "*** Error: message");
+    logln(&lw, "dbg: Debugging info");
 
+    lw.flush();
     let file_path = lw.get_path().unwrap();
     let content = fs::read_to_string(file_path.as_path())?;
 
@@ -0,0 +1,122 @@
+// summary.rs - Logging session summary
+// Named counters, timed spans and error totals, reported at the end of a run in a consistent format
+//
+// 2026-10-19   PV      First version
+
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::{Level, LogState, LogWriter};
+
+/// Plural suffix, "s" if n > 1
+pub fn s<T: PartialOrd + From<u8>>(n: T) -> &'static str {
+    if n > T::from(1) { "s" } else { "" }
+}
+
+/// Measures time spent in a phase until dropped, durations of spans with the same name are added
+#[must_use = "span measures time until it's dropped"]
+pub struct Span {
+    state: Arc<Mutex<LogState>>,
+    name: String,
+    start: Instant,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        match state.phases.iter_mut().find(|(n, _)| *n == self.name) {
+            Some((_, d)) => *d += elapsed,
+            None => state.phases.push((self.name.clone(), elapsed)),
+        }
+    }
+}
+
+impl LogWriter {
+    /// Adds 1 to counter name, created if needed
+    pub fn count(&self, name: &str) {
+        self.add_count(name, 1);
+    }
+
+    /// Adds n to counter name, created if needed. Counters are listed in summary in order of creation, so add_count(name, 0)
+    /// can be used to report a counter even if it's never incremented
+    pub fn add_count(&self, name: &str, n: u64) {
+        let mut state = self.lock();
+        match state.counters.iter_mut().find(|(c, _)| c == name) {
+            Some((_, v)) => *v += n,
+            None => state.counters.push((name.to_string(), n)),
+        }
+    }
+
+    /// Current value of counter name, 0 if it doesn't exist
+    pub fn counter(&self, name: &str) -> u64 {
+        self.lock().counters.iter().find(|(c, _)| c == name).map_or(0, |(_, v)| *v)
+    }
+
+    /// Counts an error that is not logged, such as an error only reported in verbose mode
+    pub fn count_error(&self) {
+        self.lock().errors += 1;
+    }
+
+    /// Number of error messages logged so far, including the ones filtered out by level thresholds
+    pub fn errors(&self) -> u64 {
+        self.lock().errors
+    }
+
+    /// Starts timing a phase, such as let _span = lw.span("hashing"); time is recorded when span is dropped
+    pub fn span(&self, name: &str) -> Span {
+        Span {
+            state: self.state.clone(),
+            name: name.to_string(),
+            start: Instant::now(),
+        }
+    }
+
+    /// Summary text, for instance:
+    /// Summary: 12 files, 3 dirs, 1 error
+    /// Phases: search 1.234s, actions 0.012s
+    /// Total duration: 1.250s
+    /// Counters are named in singular, first word gets an s when value is more than 1, such as "file renamed".
+    /// Summary line is omitted if there are no counters and no errors, Phases line if there are no spans.
+    /// Total duration is measured since LogWriter creation
+    pub fn summary(&self) -> String {
+        let state = self.lock();
+        let mut res = String::new();
+
+        let mut items: Vec<String> = state.counters.iter().map(|(name, v)| counter_text(name, *v)).collect();
+        if state.errors > 0 {
+            items.push(counter_text("error", state.errors));
+        }
+        if !items.is_empty() {
+            res += &format!("Summary: {}\n", items.join(", "));
+        }
+
+        if !state.phases.is_empty() {
+            let phases: Vec<String> = state.phases.iter().map(|(name, d)| format!("{name} {}", seconds(*d))).collect();
+            res += &format!("Phases: {}\n", phases.join(", "));
+        }
+
+        res += &format!("Total duration: {}", seconds(state.start.elapsed()));
+        res
+    }
+
+    /// Writes summary to console and log file
+    pub fn log_summary(&self) {
+        let summary = self.summary();
+        let mut state = self.lock();
+        for line in summary.lines() {
+            state.write(Level::Info, "", line, true, &[]);
+        }
+    }
+}
+
+fn counter_text(name: &str, v: u64) -> String {
+    match name.split_once(' ') {
+        Some((first, rest)) => format!("{v} {first}{} {rest}", s(v)),
+        None => format!("{v} {name}{}", s(v)),
+    }
+}
+
+fn seconds(d: Duration) -> String {
+    format!("{:.3}s", d.as_secs_f64())
+}
@@ -1,21 +1,31 @@
 // tests.rs - MyMarkup tests
 //
 // 2025-09-15   PV      First actual test
+// 2026-10-19   PV      Levels and log facade tests
+// 2026-10-19   PV      Config, rotation and retention tests
+// 2026-10-19   PV      JSON-lines log test
+// 2026-10-19   PV      Shared LogWriter test
+// 2026-10-19   PV      Summary test
+// 2026-10-19   PV      JSON-lines log shared by two threads mixing log and logln
 
 #![cfg(test)]
 
-use std::{fs, io};
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
 
 use crate::*;
 
 #[test]
 fn test_logwriter() -> io::Result<()> {
-    let mut lw = new("test", "1.2.3", true);
-    logln(&mut lw, "Hello");
-    logln(&mut lw, "*** Error: message");
-    logln(&mut lw, "dbg: Debugging info");
+    let lw = new("test", "1.2.3", true);
+    logln(&lw, "Hello");
+    logln(&lw, "*** Error: message");
+    logln(&lw, "dbg: Debugging info");
 
     let pb = lw.get_path().unwrap();
+    drop(lw);
     let file_path = pb.as_path();
     let content = fs::read_to_string(file_path)?;
     assert_eq!(content,"test 1.2.3\nHello\n*** Error: message\ndbg: Debugging info\n");
@@ -23,3 +33,254 @@ fn test_logwriter() -> io::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_levels() -> io::Result<()> {
+    let lw = new("test_levels", "1.2.3", false);
+    lw.set_file_level(LevelFilter::Warn);
+    lw.set_target_level("myglob", LevelFilter::Error);
+    lw.set_target_level("myglob::iterator", LevelFilter::Trace);
+
+    logln(&lw, "Info, not written");
+    logln(&lw, "*** Error written");
+    logln_level(&lw, Level::Warn, "Warning written");
+    logln_level(&lw, Level::Debug, "Debug not written");
+    logln_target(&lw, "myglob", Level::Warn, "myglob warning not written");
+    logln_target(&lw, "myglob::iterator", Level::Warn, "myglob::iterator warning written");
+    logln_target(&lw, "myglobx", Level::Warn, "myglobx warning written");
+
+    let pb = lw.get_path().unwrap();
+    drop(lw);
+    let content = fs::read_to_string(&pb)?;
+    assert_eq!(content, "*** Error written\nWarning written\nmyglob::iterator warning written\nmyglobx warning written\n");
+    fs::remove_file(&pb).unwrap();
+
+    Ok(())
+}
+
+#[test]
+fn test_log_facade() -> io::Result<()> {
+    let lw = new("test_facade", "1.2.3", false);
+    lw.set_console_level(LevelFilter::Off);
+    lw.install_log_facade(LevelFilter::Debug).unwrap();
+
+    log::warn!(target: "myglob", "Warning from {}", "myglob");
+    log::trace!(target: "myglob", "Trace not written");
+
+    let pb = lw.get_path().unwrap();
+    drop(lw);
+    let content = fs::read_to_string(&pb)?;
+    assert_eq!(content, "WARN myglob: Warning from myglob\n");
+    fs::remove_file(&pb).unwrap();
+
+    Ok(())
+}
+
+#[test]
+fn test_config_file() {
+    let config = LogConfig::from_toml_str("dir = \"/var/log/rutils\"\nmax_size = 1000\nmax_files = 5\nretention_days = 0").unwrap();
+    assert_eq!(config, LogConfig::default().dir(Path::new("/var/log/rutils")).max_size(1000).max_files(5).retention_days(0));
+    assert_eq!(config.retention_days, None);
+    assert_eq!(LogConfig::default().retention_days, None);
+
+    assert!(LogConfig::from_toml_str("json = true").unwrap().json);
+    assert!(LogConfig::from_toml_str("json = 1").is_err());
+    assert!(LogConfig::from_toml_str("max_files = -1").is_err());
+    assert!(LogConfig::from_toml_str("folder = \"x\"").is_err());
+}
+
+#[test]
+fn test_rotation_and_retention() -> io::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let dir = tmp.path().join("sub");
+
+    // Old log of same app is deleted by retention, other app log and non-log files are kept
+    fs::create_dir_all(&dir)?;
+    let old = dir.join("test_rotation-2020-01-01-00.00.00.log");
+    File::create(&old)?.set_modified(SystemTime::now() - Duration::from_secs(40 * 24 * 3600))?;
+    let other = dir.join("test_rotation_other-2020-01-01-00.00.00.log");
+    File::create(&other)?.set_modified(SystemTime::now() - Duration::from_secs(40 * 24 * 3600))?;
+
+    let config = LogConfig::default().dir(&dir).max_size(10).max_files(2).retention_days(30);
+    let lw = new_with_config("test_rotation", "1.0", false, &config);
+    lw.set_console_level(LevelFilter::Off);
+    logln(&lw, "Line 1 is longer than 10 bytes");
+    logln(&lw, "Line 2 also");
+    logln(&lw, "Line 3");
+    let last = lw.get_path().unwrap();
+    drop(lw);
+
+    assert!(!old.exists());
+    assert!(other.exists());
+    assert!(last.to_string_lossy().ends_with(".3.log"));
+    assert_eq!(fs::read_to_string(&last)?, "Line 3\n");
+
+    // max_files = 2: first file has been deleted when third one was created
+    let mut logs = fs::read_dir(&dir)?
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|n| n.starts_with("test_rotation-"))
+        .collect::<Vec<_>>();
+    logs.sort();
+    assert_eq!(logs.len(), 2);
+    assert!(logs[0].ends_with(".2.log"));
+
+    // Retention too long to be represented as a time is ignored
+    File::create(&old)?.set_modified(SystemTime::now() - Duration::from_secs(40 * 24 * 3600))?;
+    let config = LogConfig::default().dir(&dir).retention_days(u64::MAX);
+    new_with_config("test_rotation", "1.0", false, &config).set_console_level(LevelFilter::Off);
+    assert!(old.exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_json_log() -> io::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let config = LogConfig::default().dir(tmp.path()).json(true);
+    let lw = new_with_config("test_json", "1.2.3", false, &config);
+    lw.set_console_level(LevelFilter::Off);
+    lw.set_file_level(LevelFilter::Info);
+    log(&lw, "Partial ");
+    logln(&lw, "line");
+    logln_level(&lw, Level::Debug, "Debug not written");
+    logln_fields(&lw, Level::Info, "Renamed \"a\tb\"", &[("operation", "rename"), ("path", r"C:\Temp\a\tb")]);
+    logln_target(&lw, "myglob", Level::Warn, "Line1\nLine2");
+
+    let json_path = lw.get_json_path().unwrap();
+    assert!(json_path.to_string_lossy().ends_with(".jsonl"));
+    drop(lw);
+
+    let content = fs::read_to_string(&json_path)?;
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(lines.len(), 3);
+    // Timestamp varies, check the rest of the record
+    let strip_timestamp = |l: &str| l.split_once(",\"app\"").map(|(_, rest)| rest.to_string()).unwrap();
+    assert!(lines[0].starts_with("{\"timestamp\":\"20"));
+    assert_eq!(strip_timestamp(lines[0]), r#":"test_json","version":"1.2.3","level":"INFO","message":"Partial line"}"#);
+    assert_eq!(
+        strip_timestamp(lines[1]),
+        r#":"test_json","version":"1.2.3","level":"INFO","message":"Renamed \"a\tb\"","fields":{"operation":"rename","path":"C:\\Temp\\a\\tb"}}"#
+    );
+    assert_eq!(strip_timestamp(lines[2]), r#":"test_json","version":"1.2.3","level":"WARN","target":"myglob","message":"Line1\nLine2"}"#);
+    assert_eq!(json_string("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+
+    Ok(())
+}
+
+#[test]
+fn test_json_log_threads() -> io::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let config = LogConfig::default().dir(tmp.path()).json(true);
+    let lw = new_with_config("test_json_threads", "1.2.3", false, &config);
+    lw.set_console_level(LevelFilter::Off);
+
+    // Each JSON line is built from a log and a logln of the same thread
+    let handles: Vec<_> = (0..2)
+        .map(|t| {
+            let lw = lw.clone();
+            std::thread::spawn(move || {
+                for i in 0..200 {
+                    log(&lw, &format!("thread {t} "));
+                    std::thread::yield_now();
+                    logln(&lw, &format!("line {i} of thread {t}"));
+                }
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    let json_path = lw.get_json_path().unwrap();
+    drop(lw);
+    let content = fs::read_to_string(&json_path)?;
+
+    let mut next = [0; 2];
+    for line in content.lines() {
+        let (_, message) = line.split_once(r#""message":""#).unwrap();
+        let parts: Vec<&str> = message.trim_end_matches("\"}").split(' ').collect();
+        assert_eq!(parts.len(), 7, "Mixed record: {line}");
+        let t: usize = parts[1].parse().unwrap();
+        assert_eq!(parts[6], parts[1], "Mixed record: {line}");
+        assert_eq!(parts[3].parse::<i32>().unwrap(), next[t]);
+        next[t] += 1;
+    }
+    assert_eq!(next, [200; 2]);
+
+    Ok(())
+}
+
+#[test]
+fn test_shared_logwriter() -> io::Result<()> {
+    let lw = new("test_shared", "1.2.3", false);
+    lw.set_console_level(LevelFilter::Off);
+
+    let handles: Vec<_> = (0..4)
+        .map(|t| {
+            let lw = lw.clone();
+            std::thread::spawn(move || {
+                for i in 0..250 {
+                    logln(&lw, &format!("thread {t} line {i} {}", "x".repeat(100)));
+                }
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    let pb = lw.get_path().unwrap();
+    drop(lw);
+    let content = fs::read_to_string(&pb)?;
+
+    // All lines are complete, and lines of each thread are in order
+    let mut next = [0; 4];
+    for line in content.lines() {
+        let parts: Vec<&str> = line.split(' ').collect();
+        assert_eq!(parts.len(), 5, "Interleaved line: {line}");
+        let t: usize = parts[1].parse().unwrap();
+        assert_eq!(parts[3].parse::<i32>().unwrap(), next[t]);
+        next[t] += 1;
+    }
+    assert_eq!(next, [250; 4]);
+    fs::remove_file(&pb)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_summary() {
+    let lw = logwriter_none();
+    lw.set_console_level(LevelFilter::Off);
+    assert!(lw.summary().starts_with("Total duration: "));
+
+    lw.add_count("dir", 0);
+    lw.count("file");
+    lw.count("file renamed");
+    lw.add_count("file", 2);
+    lw.count("file renamed");
+    lw.set_target_level("myglob", LevelFilter::Off);
+    logln(&lw, "*** Error counted");
+    logln_target(&lw, "myglob", Level::Error, "Error counted even if filtered out");
+    lw.count_error();
+    {
+        let _span = lw.span("search");
+    }
+    {
+        let _span = lw.span("search");
+    }
+
+    assert_eq!(lw.counter("file"), 3);
+    assert_eq!(lw.counter("unknown"), 0);
+    assert_eq!(lw.errors(), 3);
+    let summary = lw.summary();
+    let lines: Vec<&str> = summary.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], "Summary: 0 dir, 3 files, 2 files renamed, 3 errors");
+    assert!(lines[1].starts_with("Phases: search 0.0"));
+    assert!(lines[2].starts_with("Total duration: "));
+
+    assert_eq!(s(1), "");
+    assert_eq!(s(2usize), "s");
+}
@@ -33,10 +33,14 @@
 // 2025-10-24   PV      2.1.1 Fixed bug C:\**\thumbs.db stopping search at first file not found
 // 2025-10-29   PV      2.2.0 MyGlobSearch::root()
 // 2025-11-16   PV      3.0.0 Added MyGlobCLOptions; MyGlobBuilder::apply_command_line_options helper
+// 2026-10-19   PV      3.0.1 glob_syntax uses MyMarkup lists, and a code block for examples
+// 2026-10-19   PV      3.1.0 Tracing uses log facade (trace! and debug!) instead of TRACE constant and println!
+// 2026-10-19   PV      3.2.0 MyGlobCLOptions::VALUES for shell completion
 
 //#![allow(unused_variables, dead_code, unused_imports)]
 
 // Standard library imports
+use log::{debug, trace};
 use regex::Regex;
 use std::error::Error;
 use std::fmt::Display;
@@ -58,8 +62,6 @@ mod tests;
 
 const LIB_VERSION: &str = env!("CARGO_PKG_VERSION");
 
-const TRACE: bool = false;
-
 // -----------------------------------
 // Structures
 
@@ -128,25 +130,33 @@ impl MyGlobSearch {
 
     pub fn glob_syntax() -> &'static str {
         "⌊Glob pattern rules⌋:
-- ¬⟦?⟧ matches any single character.
-- ¬⟦*⟧ matches any (possibly empty) sequence of characters.
-- ¬⟦**⟧ matches the current directory and arbitrary subdirectories. To match files in arbitrary subdirectories, use ⟦**/*⟧. This sequence must form a single path component, so both ⟦**a⟧ and ⟦b**⟧ are invalid and will result in an error.
-- ¬⟦[...]⟧ matches any character inside the brackets. Character sequences can also specify ranges of characters (Unicode order), so ⟦[0-9]⟧ specifies any character between 0 and 9 inclusive. Special cases: ⟦[[]⟧ represents an opening bracket, ⟦[]]⟧ represents a closing bracket. 
-- ¬⟦[!...]⟧ is the negation of ⟦[...]⟧, it matches any characters not in the brackets.
-- ¬The metacharacters ⟦?⟧, ⟦*⟧, ⟦[⟧, ⟦]⟧ can be matched by escaping them between brackets such as ⟦[\\?]⟧, ⟦[\\]]⟧ or ⟦[\\[]⟧. The ⟦-⟧ character can be specified inside a character sequence pattern by placing it at the start or the end, e.g. ⟦[abc-]⟧.
-- ¬⟦{choice1,choice2...}⟧  match any of the comma-separated choices between braces. Can be nested, and include ⟦?⟧, ⟦*⟧ and character classes. Special macro ⟦!SOURCES⟧ is replaced by common sources extensions (.c,.cs,.cpp...) and typically used in expressions such as ⟦*.{!SOURCES}⟧ to find source files.
-- ¬Character classes ⟦[ ]⟧ accept regex syntax such as ⟦[\\d]⟧ to match a single digit, see https://docs.rs/regex/latest/regex/#character-classes for character classes and escape sequences supported.
+- ⟦?⟧ matches any single character.
+- ⟦*⟧ matches any (possibly empty) sequence of characters.
+- ⟦**⟧ matches the current directory and arbitrary subdirectories. To match files in arbitrary subdirectories, use ⟦**/*⟧. This sequence must form a single path component, so both ⟦**a⟧ and ⟦b**⟧ are invalid and will result in an error.
+- ⟦[...]⟧ matches any character inside the brackets. Character sequences can also specify ranges of characters (Unicode order), so ⟦[0-9]⟧ specifies any character between 0 and 9 inclusive. Special cases: ⟦[[]⟧ represents an opening bracket, ⟦[]]⟧ represents a closing bracket. 
+- ⟦[!...]⟧ is the negation of ⟦[...]⟧, it matches any characters not in the brackets.
+- The metacharacters ⟦?⟧, ⟦*⟧, ⟦[⟧, ⟦]⟧ can be matched by escaping them between brackets such as ⟦[\\?]⟧, ⟦[\\]]⟧ or ⟦[\\[]⟧. The ⟦-⟧ character can be specified inside a character sequence pattern by placing it at the start or the end, e.g. ⟦[abc-]⟧.
+- ⟦{choice1,choice2...}⟧  match any of the comma-separated choices between braces. Can be nested, and include ⟦?⟧, ⟦*⟧ and character classes. Special macro ⟦!SOURCES⟧ is replaced by common sources extensions (.c,.cs,.cpp...) and typically used in expressions such as ⟦*.{!SOURCES}⟧ to find source files.
+- Character classes ⟦[ ]⟧ accept regex syntax such as ⟦[\\d]⟧ to match a single digit, see https://docs.rs/regex/latest/regex/#character-classes for character classes and escape sequences supported.
 
 ⌊Autorecurse glob pattern transformation⌋:
-- ¬⟪Constant pattern⟫ (no filter, no ⟦**⟧) pointing to a directory: ⟦/**/*⟧ is appended at the end to search all files of all subdirectories.
-- ¬⟪Patterns without ⟦**⟧ and ending with a filter⟫: ⟦/**⟧ is inserted before the final filter to find all matching files of all subdirectories.
-- ¬⟪Patterns without ⟦**⟧ and ending with a constant⟫: no transformation, ⟦W:\\thumbs.db⟧ matches onky this file.
+- ⟪Constant pattern⟫ (no filter, no ⟦**⟧) pointing to a directory: ⟦/**/*⟧ is appended at the end to search all files of all subdirectories.
+- ⟪Patterns without ⟦**⟧ and ending with a filter⟫: ⟦/**⟧ is inserted before the final filter to find all matching files of all subdirectories.
+- ⟪Patterns without ⟦**⟧ and ending with a constant⟫: no transformation, ⟦W:\\thumbs.db⟧ matches onky this file.
 
 ⌊Case sensitive option⌋:
 Case-sensitive option only apply to filters such as ⟦*.JPG⟧ or ⟦*Eric*⟧, ⟦**⟧ ignore folders case, and constant parts such as ⟦C:\\Development⟧ depend on the OS and the filesystem: typically case-insensitive on Windows, and case-sensitive on Linux, MacOS or case-sensitive volumes on Windows (Cryptomator, WSL volummes, ...).
 
 ⌊Note⌋:
 Pattern ⟦*.*⟧ explicitly refers to segments containing a dot. This is different than the Windows/Dos traditional command line pattern ⟦*.*⟧ that matches any file, with or without a dot.
+
+⌊Examples⌋:
+```
+C:\\Development\\**\\*.rs    Rust files of C:\\Development and all its subdirectories
+*.{jpg,png}               JPG and PNG files of current directory
+**\\thumbs.db              thumbs.db files of current directory and all its subdirectories
+*.{!SOURCES}              Source files of current directory
+```
 "
     }
 
@@ -212,9 +222,7 @@ Pattern ⟦*.*⟧ explicitly refers to segments containing a dot. This is differ
             };
         }
 
-        if TRACE {
-            println!("$0: Start iteration, initial push DirToExplore {}", self.root);
-        }
+        trace!("$0: Start iteration, initial push DirToExplore {}", self.root);
         // Normal case, start iterator at root
         MyGlobIteratorState {
             queue: vec![SearchPendingData::DirToExplore(Path::new(&self.root).to_path_buf(), 0, false, 0)],
@@ -360,6 +368,7 @@ impl MyGlobBuilder {
             }
         }
 
+        debug!("Glob {} compiled: root {root}, {} segment(s)", self.glob_pattern, segments.len());
         Ok(MyGlobSearch {
             root,
             segments,
@@ -537,21 +546,17 @@ impl Iterator for MyGlobIteratorState<'_> {
                 SearchPendingData::Dir(pb, _is_link) => return Some(MyGlobMatch::Dir(pb)),
 
                 SearchPendingData::DirToExplore(root, depth, recurse, recurse_depth) => {
-                    if TRACE {
-                        println!(
-                            "\n$1: Main loop, DirToExplore {}  max_depth={} depth={} recurse_depth={}",
-                            root.display(),
-                            self.max_depth,
-                            depth,
-                            recurse_depth
-                        );
-                    }
+                    trace!(
+                        "$1: Main loop, DirToExplore {}  max_depth={} depth={} recurse_depth={}",
+                        root.display(),
+                        self.max_depth,
+                        depth,
+                        recurse_depth
+                    );
 
                     match &self.segments[depth] {
                         Segment::Constant(name) => {
-                            if TRACE {
-                                println!("$1b Constant segment: {}", name);
-                            }
+                            trace!("$1b Constant segment: {}", name);
 
                             let pb = root.join(name);
                             let fto: Option<fs::FileType> = if !pb.exists() {
@@ -587,15 +592,13 @@ impl Iterator for MyGlobIteratorState<'_> {
 
                             // Then if recurse mode, we also search in all subdirectories
                             if recurse && (self.max_depth == 0 || recurse_depth < self.max_depth) {
-                                if TRACE {
-                                    println!(
-                                        "$2: Search subdirectories of {}  max_depth={} depth={} recurse_depth={}",
-                                        root.display(),
-                                        self.max_depth,
-                                        depth,
-                                        recurse_depth
-                                    );
-                                }
+                                trace!(
+                                    "$2: Search subdirectories of {}  max_depth={} depth={} recurse_depth={}",
+                                    root.display(),
+                                    self.max_depth,
+                                    depth,
+                                    recurse_depth
+                                );
 
                                 match fs::read_dir(&root) {
                                     Ok(contents) => {
@@ -607,15 +610,13 @@ impl Iterator for MyGlobIteratorState<'_> {
                                                         let p = entry.path();
                                                         let fnlc = p.file_name().unwrap().to_string_lossy().to_lowercase();
                                                         if !self.ignore_dirs.iter().any(|ie| *ie == fnlc.to_lowercase()) {
-                                                            if TRACE {
-                                                                println!(
-                                                                    "$3: Push DirToExplore {}  max_depth={} depth={} recurse_depth={}",
-                                                                    p.display(),
-                                                                    self.max_depth,
-                                                                    depth,
-                                                                    recurse_depth
-                                                                );
-                                                            }
+                                                            trace!(
+                                                                "$3: Push DirToExplore {}  max_depth={} depth={} recurse_depth={}",
+                                                                p.display(),
+                                                                self.max_depth,
+                                                                depth,
+                                                                recurse_depth
+                                                            );
                                                             self.queue.insert(0, SearchPendingData::DirToExplore(p, depth, true, recurse_depth + 1));
                                                         }
                                                     }
@@ -639,26 +640,21 @@ impl Iterator for MyGlobIteratorState<'_> {
                         }
 
                         Segment::Recurse => {
-                            if TRACE {
-                                println!("$1c Recurse segment");
-
-                                println!(
-                                    "$4: Push DirToExplore {}  max_depth={}  current: depth={} recurse_depth={}  pushed: depth={} recurse_depth={}",
-                                    root.display(),
-                                    self.max_depth,
-                                    depth,
-                                    recurse_depth,
-                                    depth + 1,
-                                    0
-                                );
-                            }
+                            trace!("$1c Recurse segment");
+                            trace!(
+                                "$4: Push DirToExplore {}  max_depth={}  current: depth={} recurse_depth={}  pushed: depth={} recurse_depth={}",
+                                root.display(),
+                                self.max_depth,
+                                depth,
+                                recurse_depth,
+                                depth + 1,
+                                0
+                            );
                             self.queue.insert(0, SearchPendingData::DirToExplore(root, depth + 1, true, 0));
                         }
 
                         Segment::Filter(re) => {
-                            if TRACE {
-                                println!("$1d Filter segment");
-                            }
+                            trace!("$1d Filter segment");
 
                             // Search all files, return the ones that match
                             let mut dirs: Vec<PathBuf> = Vec::new();
@@ -674,15 +670,13 @@ impl Iterator for MyGlobIteratorState<'_> {
 
                                                 if (ft.is_file() || ft.is_symlink_file()) && (ft.is_file() || self.link_mode > 0) {
                                                     if depth == self.segments.len() - 1 && re.is_match(&fname) {
-                                                        if TRACE {
-                                                            println!(
-                                                                "$5a: Push File {}  max_depth={} depth={} recurse_depth={}",
-                                                                pb.display(),
-                                                                self.max_depth,
-                                                                depth,
-                                                                recurse_depth
-                                                            );
-                                                        }
+                                                        trace!(
+                                                            "$5a: Push File {}  max_depth={} depth={} recurse_depth={}",
+                                                            pb.display(),
+                                                            self.max_depth,
+                                                            depth,
+                                                            recurse_depth
+                                                        );
 
                                                         self.queue.insert(0, SearchPendingData::File(pb, ft.is_symlink()));
                                                     }
@@ -695,27 +689,23 @@ impl Iterator for MyGlobIteratorState<'_> {
                                                                 // If it's the last segment, we just return the directory
                                                                 // Otherwise, we continue exploration in next loop
                                                                 if depth == self.segments.len() - 1 {
-                                                                    if TRACE {
-                                                                        println!(
-                                                                            "$5b: Push Dir {}  max_depth={} depth={} recurse_depth={}",
-                                                                            pb.display(),
-                                                                            self.max_depth,
-                                                                            depth,
-                                                                            recurse_depth
-                                                                        );
-                                                                    }
+                                                                    trace!(
+                                                                        "$5b: Push Dir {}  max_depth={} depth={} recurse_depth={}",
+                                                                        pb.display(),
+                                                                        self.max_depth,
+                                                                        depth,
+                                                                        recurse_depth
+                                                                    );
 
                                                                     self.queue.insert(0, SearchPendingData::Dir(pb.clone(), ft.is_symlink()));
                                                                 } else if ft.is_dir() || self.link_mode > 1 {
-                                                                    if TRACE {
-                                                                        println!(
-                                                                            "$5c: Push DirToExplore {}  max_depth={} depth={} recurse_depth={}",
-                                                                            pb.display(),
-                                                                            self.max_depth,
-                                                                            depth,
-                                                                            recurse_depth
-                                                                        );
-                                                                    }
+                                                                    trace!(
+                                                                        "$5c: Push DirToExplore {}  max_depth={} depth={} recurse_depth={}",
+                                                                        pb.display(),
+                                                                        self.max_depth,
+                                                                        depth,
+                                                                        recurse_depth
+                                                                    );
                                                                     self.queue
                                                                         .insert(0, SearchPendingData::DirToExplore(pb.clone(), depth + 1, false, 0));
                                                                 }
@@ -724,15 +714,13 @@ impl Iterator for MyGlobIteratorState<'_> {
                                                             if (self.max_depth == 0 || recurse_depth < self.max_depth - 1)
                                                                 && (ft.is_dir() || self.link_mode > 1)
                                                             {
-                                                                if TRACE {
-                                                                    println!(
-                                                                        "$6: Add to dirs {}  max_depth={} depth={} recurse_depth={}",
-                                                                        pb.display(),
-                                                                        self.max_depth,
-                                                                        depth,
-                                                                        recurse_depth
-                                                                    );
-                                                                }
+                                                                trace!(
+                                                                    "$6: Add to dirs {}  max_depth={} depth={} recurse_depth={}",
+                                                                    pb.display(),
+                                                                    self.max_depth,
+                                                                    depth,
+                                                                    recurse_depth
+                                                                );
                                                                 dirs.push(pb);
                                                             }
                                                         }
@@ -766,15 +754,13 @@ impl Iterator for MyGlobIteratorState<'_> {
 
                             if recurse && (self.max_depth == 0 || recurse_depth < self.max_depth) {
                                 for dir in dirs {
-                                    if TRACE {
-                                        println!(
-                                            "$7:Pushing from dirs SearchPendingData {} max_depth={} depth={} recurse_depth={}",
-                                            dir.display(),
-                                            self.max_depth,
-                                            depth,
-                                            recurse_depth
-                                        );
-                                    }
+                                    trace!(
+                                        "$7:Pushing from dirs SearchPendingData {} max_depth={} depth={} recurse_depth={}",
+                                        dir.display(),
+                                        self.max_depth,
+                                        depth,
+                                        recurse_depth
+                                    );
 
                                     self.queue.insert(0, SearchPendingData::DirToExplore(dir, depth, true, recurse_depth + 1));
                                 }
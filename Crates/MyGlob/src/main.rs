@@ -9,6 +9,7 @@
 #![allow(unused)]
 
 use myglob::{MyGlobBuilder, MyGlobError, MyGlobMatch, MyGlobSearch};
+use log as _;
 use regex as _;
 use std::env;
 use std::path::Path;
@@ -2,6 +2,7 @@
 // Standard MyGlob Command Line Options processing so it's consistent across various applications
 //
 // 2025-11-16   PV
+// 2026-10-19   PV      VALUES for shell completion
 
 #[derive(Debug, Default)]
 pub struct MyGlobCLOptions {
@@ -14,6 +15,8 @@ pub struct MyGlobCLOptions {
 }
 
 impl MyGlobCLOptions {
+    /// Option names, used by shell completion (md and f expect an argument, l0 is also accepted as l,0)
+    pub const VALUES: [&'static str; 10] = ["ci", "cs", "a+", "a-", "l0", "l1", "l2", "md", "ngf", "f"];
 
     /// Provide a new instance of MyGlobCLOptions with predefined options autorecurse:true, link_mode:1
     /// If this default is not pertinent for an app, create and initialize a nex instance directly in app
@@ -0,0 +1,333 @@
+// backends.rs - MyMarkup rendering backends
+// A backend translates markup styles into output-specific sequences (ANSI escapes, HTML tags, ...)
+//
+// 2026-10-19   PV      First version, ANSI, plain text, Markdown and HTML backends
+// 2026-10-19   PV      push_table for backends that don't wrap
+// 2026-10-19   PV      ANSI backend uses a Theme and terminal color support; Color3 style
+// 2026-10-19   PV      Headings, list markers and code blocks
+
+use crate::*;
+
+/// Styles that can be expressed with MyMarkup brackets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkupStyle {
+    Bold,      // ⟪⟫
+    Italic,    // ⟨⟩
+    Underline, // ⌊⌋
+    Color1,    // ⟦⟧
+    Color2,    // ⦃⦄
+    Color3,    // ⟮⟯
+}
+
+/// Rendering backend used by MyMarkup::build_markup_backend.
+/// Text chars and style changes are fed in order; a backend only has to provide the strings to emit.
+pub trait MarkupBackend {
+    /// String emitted when a style starts
+    fn style_on(&mut self, style: MarkupStyle) -> String;
+
+    /// String emitted when a style ends
+    fn style_off(&mut self, style: MarkupStyle) -> String;
+
+    /// Appends a visible text char to out, escaping it if needed. Escaping doesn't change the visible width of the char
+    fn push_char(&mut self, c: char, out: &mut String) {
+        out.push(c);
+    }
+
+    /// Appends a line break
+    fn push_newline(&mut self, out: &mut String) {
+        out.push('\n');
+    }
+
+    /// Prefix of the whole document
+    fn begin(&mut self) -> &'static str {
+        ""
+    }
+
+    /// Suffix of the whole document
+    fn end(&mut self) -> &'static str {
+        ""
+    }
+
+    /// false if output should not be wrapped at width nor indented at ¬ margins (target does its own layout)
+    fn wraps(&self) -> bool {
+        true
+    }
+
+    /// Styles applied to a heading text, level 1 to 3. Default follows help texts conventions: bold underlined title,
+    /// underlined section, bold subsection
+    fn heading_styles(&self, level: usize) -> Vec<MarkupStyle> {
+        match level {
+            1 => vec![MarkupStyle::Bold, MarkupStyle::Underline],
+            2 => vec![MarkupStyle::Underline],
+            _ => vec![MarkupStyle::Bold],
+        }
+    }
+
+    /// String emitted before a heading text
+    fn heading_prefix(&mut self, _level: usize) -> String {
+        String::new()
+    }
+
+    /// String emitted for a list item marker, should have the same width as marker text
+    fn list_marker(&mut self, marker: ListMarker) -> String {
+        marker.text()
+    }
+
+    /// Only called for backends that don't wrap, to emit code block lines, tabs are already expanded
+    fn push_code_block(&mut self, lines: &[String], out: &mut String) {
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                self.push_newline(out);
+            }
+            for c in line.chars() {
+                self.push_char(c, out);
+            }
+        }
+    }
+
+    /// Only called for backends that don't wrap, to format a table whose cells are already rendered
+    fn push_table(&mut self, header: Option<&[String]>, rows: &[Vec<String>], _aligns: &[ColumnAlign], out: &mut String) {
+        for (i, row) in header.into_iter().chain(rows.iter().map(|r| r.as_slice())).enumerate() {
+            if i > 0 {
+                self.push_newline(out);
+            }
+            out.push_str(&row.join("  "));
+        }
+    }
+}
+
+// -----------------------------------
+// ANSI terminal (default)
+
+/// Styles come from a theme, colors are adapted to terminal color support
+#[derive(Debug)]
+pub struct AnsiBackend {
+    theme: Theme,
+    support: ColorSupport,
+    open_styles: Vec<MarkupStyle>,
+}
+
+impl Default for AnsiBackend {
+    /// Default theme, no colors downgrade; MyMarkup::build_markup uses theme and color support of current terminal
+    fn default() -> Self {
+        AnsiBackend::new(Theme::default(), ColorSupport::TrueColor)
+    }
+}
+
+impl AnsiBackend {
+    pub fn new(theme: Theme, support: ColorSupport) -> AnsiBackend {
+        AnsiBackend {
+            theme,
+            support,
+            open_styles: Vec::new(),
+        }
+    }
+}
+
+impl MarkupBackend for AnsiBackend {
+    fn style_on(&mut self, style: MarkupStyle) -> String {
+        self.open_styles.push(style);
+        self.theme.style(style).on_sequence(self.support)
+    }
+
+    fn style_off(&mut self, style: MarkupStyle) -> String {
+        if let Some(pos) = self.open_styles.iter().rposition(|&s| s == style) {
+            self.open_styles.remove(pos);
+        }
+        // Reset sequences are not specific (22 ends both bold and dim, 39 resets any color), so outer styles still open
+        // are restored
+        let mut res = self.theme.style(style).off_sequence();
+        for &s in self.open_styles.iter() {
+            res.push_str(&self.theme.style(s).on_sequence(self.support));
+        }
+        res
+    }
+}
+
+// -----------------------------------
+// Plain text, no escape sequence at all
+
+#[derive(Debug, Default)]
+pub struct TextBackend {}
+
+impl MarkupBackend for TextBackend {
+    fn style_on(&mut self, _style: MarkupStyle) -> String {
+        String::new()
+    }
+
+    fn style_off(&mut self, _style: MarkupStyle) -> String {
+        String::new()
+    }
+}
+
+// -----------------------------------
+// Markdown (GitHub flavor), for README sections
+// Text is not wrapped, lines end with a hard break, colors are rendered as inline code
+
+#[derive(Debug, Default)]
+pub struct MarkdownBackend {
+    code_depth: usize,
+    line_empty: bool,
+}
+
+impl MarkupBackend for MarkdownBackend {
+    fn style_on(&mut self, style: MarkupStyle) -> String {
+        self.line_empty = false;
+        let s = match style {
+            MarkupStyle::Bold => "**",
+            MarkupStyle::Italic => "_",
+            MarkupStyle::Underline => "<u>",
+            MarkupStyle::Color1 | MarkupStyle::Color2 | MarkupStyle::Color3 => {
+                self.code_depth += 1;
+                if self.code_depth == 1 { "`" } else { "" }
+            }
+        };
+        s.to_string()
+    }
+
+    fn style_off(&mut self, style: MarkupStyle) -> String {
+        let s = match style {
+            MarkupStyle::Bold => "**",
+            MarkupStyle::Italic => "_",
+            MarkupStyle::Underline => "</u>",
+            MarkupStyle::Color1 | MarkupStyle::Color2 | MarkupStyle::Color3 => {
+                self.code_depth = self.code_depth.saturating_sub(1);
+                if self.code_depth == 0 { "`" } else { "" }
+            }
+        };
+        s.to_string()
+    }
+
+    fn push_char(&mut self, c: char, out: &mut String) {
+        self.line_empty = false;
+        // No escaping inside code spans, backslash is literal there
+        if self.code_depth == 0 {
+            match c {
+                '\\' | '*' | '_' | '`' | '[' | ']' | '|' | '#' => out.push('\\'),
+                '<' => return out.push_str("&lt;"),
+                '>' => return out.push_str("&gt;"),
+                '&' => return out.push_str("&amp;"),
+                _ => {}
+            }
+        }
+        out.push(c);
+    }
+
+    fn begin(&mut self) -> &'static str {
+        self.line_empty = true;
+        ""
+    }
+
+    fn push_newline(&mut self, out: &mut String) {
+        // Two trailing spaces force a line break, an empty line separates paragraphs
+        if !self.line_empty {
+            out.push_str("  ");
+        }
+        out.push('\n');
+        self.line_empty = true;
+    }
+
+    fn wraps(&self) -> bool {
+        false
+    }
+
+    fn heading_styles(&self, _level: usize) -> Vec<MarkupStyle> {
+        vec![]
+    }
+
+    fn heading_prefix(&mut self, level: usize) -> String {
+        self.line_empty = false;
+        "#".repeat(level) + " "
+    }
+
+    fn list_marker(&mut self, marker: ListMarker) -> String {
+        self.line_empty = false;
+        match marker {
+            ListMarker::Bullet(_) => "-".to_string(),
+            ListMarker::Number(_) => marker.text(),
+        }
+    }
+
+    fn push_code_block(&mut self, lines: &[String], out: &mut String) {
+        // Fenced block, content is literal
+        out.push_str("```\n");
+        for line in lines {
+            out.push_str(line);
+            out.push('\n');
+        }
+        out.push_str("```");
+        self.line_empty = false;
+    }
+
+    fn push_table(&mut self, header: Option<&[String]>, rows: &[Vec<String>], aligns: &[ColumnAlign], out: &mut String) {
+        // A Markdown table always has a header, it's left empty if table has none
+        let empty_header = vec![String::new(); aligns.len()];
+        let header = header.unwrap_or(&empty_header);
+        let separator = aligns
+            .iter()
+            .map(|a| match a {
+                ColumnAlign::Left => "---".to_string(),
+                ColumnAlign::Center => ":---:".to_string(),
+                ColumnAlign::Right => "---:".to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        let table_lines = std::iter::once(header).chain(std::iter::once(separator.as_slice())).chain(rows.iter().map(|r| r.as_slice()));
+        for (i, row) in table_lines.enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str("| ");
+            out.push_str(&row.join(" | "));
+            out.push_str(" |");
+        }
+        self.line_empty = false;
+    }
+}
+
+// -----------------------------------
+// HTML, layout is preserved in a <pre> block, styles use tags and classes
+
+#[derive(Debug, Default)]
+pub struct HtmlBackend {}
+
+impl MarkupBackend for HtmlBackend {
+    fn style_on(&mut self, style: MarkupStyle) -> String {
+        let s = match style {
+            MarkupStyle::Bold => "<b>",
+            MarkupStyle::Italic => "<i>",
+            MarkupStyle::Underline => "<u>",
+            MarkupStyle::Color1 => "<span class=\"mm-color1\">",
+            MarkupStyle::Color2 => "<span class=\"mm-color2\">",
+            MarkupStyle::Color3 => "<span class=\"mm-color3\">",
+        };
+        s.to_string()
+    }
+
+    fn style_off(&mut self, style: MarkupStyle) -> String {
+        let s = match style {
+            MarkupStyle::Bold => "</b>",
+            MarkupStyle::Italic => "</i>",
+            MarkupStyle::Underline => "</u>",
+            MarkupStyle::Color1 | MarkupStyle::Color2 | MarkupStyle::Color3 => "</span>",
+        };
+        s.to_string()
+    }
+
+    fn push_char(&mut self, c: char, out: &mut String) {
+        match c {
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '&' => out.push_str("&amp;"),
+            _ => out.push(c),
+        }
+    }
+
+    fn begin(&mut self) -> &'static str {
+        "<pre class=\"mymarkup\">"
+    }
+
+    fn end(&mut self) -> &'static str {
+        "</pre>"
+    }
+}
@@ -6,6 +6,14 @@
 // 2025-07-04   PV      1.0.1 Fixed add \n if text doesn't ends with \n
 // 2025-07-05   PV      1.1.0 Main generation is now build_markup_core, which returns a string rather than printing directly
 // 2025-10-22   PV      Clippy review
+// 2026-10-19   PV      1.2.0 Pluggable rendering backends: ANSI (default), plain text, Markdown and HTML
+// 2026-10-19   PV      1.3.0 Markup is parsed into a Document tree, validated, then rendered in a separate pass; no more £ end marker
+// 2026-10-19   PV      1.4.0 Tables with header row, columns alignment and cells wrapping; build_table for programs output
+// 2026-10-19   PV      1.5.0 Wrapping counts grapheme clusters and East Asian display width instead of chars
+// 2026-10-19   PV      1.6.0 Themes loadable from a file; NO_COLOR, CLICOLOR_FORCE and non-TTY output respected; 256/truecolor palettes; ⟮⟯ style
+// 2026-10-19   PV      1.7.0 page_markup, built-in pager for long texts on a terminal, with scrolling, search and re-wrap on resize
+// 2026-10-19   PV      1.8.0 Headings, bullet and numbered lists with hanging indent, code blocks, tab stops
+// 2026-10-19   PV      1.9.0 is_markup_char, so apps measuring or stripping markup use the same set of chars
 
 // MyMarkup use pecialized brackets for formatting text:
 // ⟪Bold⟫           ~W  ~X
@@ -14,10 +22,18 @@
 // ⌈Striketrough⌉   ~Q  ~S
 // ⟦Color1⟧         ~c  ~v  Cyan
 // ⦃Color2⦄         ~C  ~V  Yellow
-// ⟮⟯               ~à  ~)  (Unused for now)
+// ⟮Color3⟯         ~à  ~)  Green
+// Styles and colors actually used come from a Theme, see theme.rs
 // ¬ (AltGr+7) sets left margin
+// │ at the beginning of a line starts a table row, and separates cells: │cell 1│cell 2│
+//   An optional second line │:──│──:│ makes first line a header, colons define alignment (left, right, or center for :──:)
+// § Title, §§ Section and §§§ Subsection at the beginning of a line are headings
+// • item, - item or 1. item at the beginning of a line, after optional indentation, are list items. Continuation lines
+//   are aligned on item text, and an item more indented than previous one starts a nested list
+// Lines between two ``` lines are a code block, printed as is: no markup, no wrapping
+// Tab chars move to next tab stop, every 8 columns
 //
-// Note: Tab are not managed yet, nor tab expansions
+// Styles are closed at the end of each line, brackets nesting can be checked with MyMarkup::check_markup
 
 //#![allow(unused)]
 
@@ -27,6 +43,20 @@ use terminal_size::{Width, terminal_size};
 // -----------------------------------
 // Submodules
 
+mod backends;
+pub use backends::{AnsiBackend, HtmlBackend, MarkdownBackend, MarkupBackend, MarkupStyle, TextBackend};
+
+mod parser;
+pub use parser::{Block, CodeBlock, ColumnAlign, Document, Heading, ListItem, ListMarker, MarkupError, MarkupErrorKind, Paragraph, Span, Table};
+
+mod render;
+use render::render_document;
+
+mod pager;
+
+mod theme;
+pub use theme::{Color, ColorSupport, THEME_ENV_VAR, Theme, ThemeStyle};
+
 mod tests;
 
 // -----------------------------------
@@ -90,7 +120,8 @@ pub const BG_BRIGHT_MAGENTA: &str = "\x1b[105m";
 pub const BG_BRIGHT_CYAN: &str = "\x1b[106m";
 pub const BG_BRIGHT_WHITE: &str = "\x1b[107m";
 
-const END_OF_STRING: char = '£';
+// Width used for non-terminal formats
+pub const DEFAULT_WIDTH: usize = 80;
 
 // -----------------------------------
 // Structures
@@ -98,6 +129,29 @@ const END_OF_STRING: char = '£';
 #[derive(Debug)]
 pub struct MyMarkup {}
 
+/// Predefined output formats, each one backed by a MarkupBackend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarkupFormat {
+    #[default]
+    Ansi,
+    Text,
+    Markdown,
+    Html,
+}
+
+impl MarkupFormat {
+    /// Converts a format name (ansi, text/txt, markdown/md, html) into a MarkupFormat, case-insensitive
+    pub fn from_name(name: &str) -> Option<MarkupFormat> {
+        match name.to_lowercase().as_str() {
+            "ansi" => Some(MarkupFormat::Ansi),
+            "text" | "txt" => Some(MarkupFormat::Text),
+            "markdown" | "md" => Some(MarkupFormat::Markdown),
+            "html" => Some(MarkupFormat::Html),
+            _ => None,
+        }
+    }
+}
+
 impl MyMarkup {
     pub fn version() -> &'static str {
         LIB_VERSION
@@ -107,237 +161,88 @@ impl MyMarkup {
         println!("{}", MyMarkup::build_markup(txt_string))
     }
 
-    pub fn build_markup(txt_str: &str) -> String {
-        let width = if let Some((Width(w), _)) = terminal_size() {
-            w as usize
-        } else {
-            80usize
-        };
+    /// Prints markup through a pager when stdout and stdin are a terminal and text doesn't fit on screen, or prints it
+    /// like render_markup. Text is rendered again at new width when terminal is resized
+    pub fn page_markup(txt_string: &str) {
+        let doc = Document::parse_lenient(txt_string);
+        let support = ColorSupport::detect();
+        let theme = if support == ColorSupport::None { Theme::default() } else { Theme::load_default() };
+        pager::page(&|width| {
+            let mut backend: Box<dyn MarkupBackend> = match support {
+                ColorSupport::None => Box::new(TextBackend::default()),
+                support => Box::new(AnsiBackend::new(theme.clone(), support)),
+            };
+            render_document(&doc, backend.as_mut(), false, width)
+        });
+    }
 
-        MyMarkup::build_markup_core(txt_str, false, width)
+    /// Renders markup for stdout: at terminal width, with user theme, and without escape sequences if colors are
+    /// disabled (NO_COLOR, output redirected, ...)
+    pub fn build_markup(txt_str: &str) -> String {
+        MyMarkup::build_markup_backend(txt_str, terminal_backend().as_mut(), false, terminal_width())
     }
 
     pub fn build_markup_core(txt_str: &str, show_limits: bool, width: usize) -> String {
-        // Add END_OF_STRING special char
-        let mut txt_string = String::from(txt_str);
-        txt_string.push(END_OF_STRING);
-
-        let mut res = String::new();
+        MyMarkup::build_markup_backend(txt_str, &mut AnsiBackend::default(), show_limits, width)
+    }
 
-        if show_limits {
-            for _ in 0..width {
-                res.push('-');
-            }
-            res.push('\n');
+    /// Renders markup using a predefined format, at terminal width for Ansi, at DEFAULT_WIDTH for other formats
+    pub fn build_markup_format(txt_str: &str, format: MarkupFormat) -> String {
+        match format {
+            MarkupFormat::Ansi => MyMarkup::build_markup(txt_str),
+            MarkupFormat::Text => MyMarkup::build_markup_backend(txt_str, &mut TextBackend::default(), false, DEFAULT_WIDTH),
+            MarkupFormat::Markdown => MyMarkup::build_markup_backend(txt_str, &mut MarkdownBackend::default(), false, DEFAULT_WIDTH),
+            MarkupFormat::Html => MyMarkup::build_markup_backend(txt_str, &mut HtmlBackend::default(), false, DEFAULT_WIDTH),
         }
+    }
 
-        let mut word = String::new();
-        let mut len = 0;
-
-        let mut col = 0;
-        let mut tab = 0;
-        for c in txt_string.chars() {
-            match c {
-                '⟪' => {
-                    word.push_str(STYLE_BOLD_ON);
-                    continue;
-                }
-                '⟫' => {
-                    word.push_str(STYLE_BOLD_OFF);
-                    continue;
-                }
-                '⟨' => {
-                    word.push_str(STYLE_ITALIC_ON);
-                    continue;
-                }
-                '⟩' => {
-                    word.push_str(STYLE_ITALIC_OFF);
-                    continue;
-                }
-                '⌊' => {
-                    word.push_str(STYLE_UNDERLINE_ON);
-                    continue;
-                }
-                '⌋' => {
-                    word.push_str(STYLE_UNDERLINE_OFF);
-                    continue;
-                }
-                '⟦' => {
-                    word.push_str(FG_CYAN);
-                    continue;
-                }
-                '⟧' => {
-                    word.push_str(FG_DEFAULT);
-                    continue;
-                }
-                '⦃' => {
-                    word.push_str(FG_YELLOW);
-                    continue;
-                }
-                '⦄' => {
-                    word.push_str(FG_DEFAULT);
-                    continue;
-                }
-                '\r' => continue,
-                '\n' | END_OF_STRING => {
-                    if !word.is_empty() && !is_only_spaces(&word) {
-                        if col + len <= width {
-                            res.push_str(&word);
-                            if show_limits {
-                                col += len;
-                                while col < width {
-                                    col += 1;
-                                    res.push(' ');
-                                }
-                                res.push('|');
-                            }
-                            if c == '\n' {
-                                res.push('\n');
-                            }
-                        } else {
-                            if show_limits {
-                                while col < width {
-                                    col += 1;
-                                    res.push(' ');
-                                }
-                                res.push('|');
-                            }
-                            res.push('\n');
-                            for _ in 0..tab {
-                                res.push(' ');
-                            }
-                            while word.starts_with(' ') {
-                                word.remove(0);
-                                len -= 1;
-                            }
-                            res.push_str(&word);
-                            col = tab + len;
-                            if show_limits {
-                                while col < width {
-                                    col += 1;
-                                    res.push(' ');
-                                }
-                                res.push('|');
-                            }
-                            if c == '\n' {
-                                res.push('\n');
-                            }
-                        }
-                    } else {
-                        while col < width {
-                            col += 1;
-                            res.push(' ');
-                        }
-                        if show_limits {
-                            res.push('|');
-                        }
-                        if c == '\n' {
-                            res.push('\n');
-                        }
-                    }
-                    word.clear();
-                    len = 0;
-                    col = 0;
-                    tab = 0;
-                }
-                '¬' => {
-                    res.push_str(&word);
-                    col += len;
-                    tab = col;
-                    word.clear();
-                    len = 0;
-                }
-                ' ' => {
-                    if !word.is_empty() {
-                        if is_only_spaces(&word) {
-                            word.push(c);
-                            len += 1;
-                            continue;
-                        }
-
-                        if col + len <= width {
-                            res.push_str(&word);
-                            col += len;
-                            word.clear();
-                            len = 0;
-                        } else {
-                            if show_limits {
-                                while col < width {
-                                    col += 1;
-                                    res.push(' ');
-                                }
-                                res.push('|');
-                            }
-                            res.push('\n');
-                            for _ in 0..tab {
-                                res.push(' ');
-                            }
-                            col = tab;
-                            while word.starts_with(' ') {
-                                word.remove(0);
-                                len -= 1;
-                            }
-
-                            res.push_str(&word);
-                            col += len;
-                            word.clear();
-                            len = 0;
-                        }
-                    }
-                    word.push(' ');
-                    len += 1;
-                }
-                _ => {
-                    if tab + len >= width - 1 {
-                        // We can't accumulate char, it would be longer than width
-                        if col > tab {
-                            // if we have already printed some chars, we need to flush and start a new line
-                            if show_limits {
-                                while col < width {
-                                    col += 1;
-                                    res.push(' ');
-                                }
-                                res.push('|');
-                            }
-                            res.push('\n');
-
-                            for _ in 0..tab {
-                                res.push(' ');
-                            }
-                            col = tab;
-                        }
-
-                        while word.starts_with(' ') {
-                            word.remove(0);
-                            len -= 1;
-                        }
-
-                        if col + len >= width {
-                            res.push_str(&word);
-                            if show_limits {
-                                res.push('|');
-                            }
-                            res.push('\n');
-
-                            word.clear();
-                            len = 0;
-                            for _ in 0..tab {
-                                res.push(' ');
-                            }
-                            col = tab;
-                        }
-                    }
-
-                    word.push(c);
-                    len += 1;
-                }
-            }
-        }
+    /// Renders markup with any backend. show_limits draws the width limits, for tests and debugging.
+    /// Markup is parsed leniently, use MyMarkup::check_markup to validate brackets nesting
+    pub fn build_markup_backend(txt_str: &str, backend: &mut dyn MarkupBackend, show_limits: bool, width: usize) -> String {
+        let doc = Document::parse_lenient(txt_str);
+        render_document(&doc, backend, show_limits, width)
+    }
+
+    /// Renders an already parsed document
+    pub fn render_document(doc: &Document, backend: &mut dyn MarkupBackend, show_limits: bool, width: usize) -> String {
+        render_document(doc, backend, show_limits, width)
+    }
+
+    /// Prints a table, such as programs results, at terminal width
+    pub fn render_table(table: &Table) {
+        println!("{}", MyMarkup::build_table(table))
+    }
+
+    /// Renders a table for stdout, like build_markup
+    pub fn build_table(table: &Table) -> String {
+        let doc = Document {
+            blocks: vec![Block::Table(table.clone())],
+        };
+        render_document(&doc, terminal_backend().as_mut(), false, terminal_width())
+    }
+
+    /// True for style brackets and margin char ¬, chars of markup that are not printed
+    pub fn is_markup_char(c: char) -> bool {
+        parser::is_markup_char(c)
+    }
 
-        res
+    /// Returns all brackets nesting errors of a markup text, with their position; empty if markup is valid
+    pub fn check_markup(txt_str: &str) -> Vec<MarkupError> {
+        Document::check(txt_str)
     }
 }
 
-fn is_only_spaces(word: &str) -> bool {
-    word.chars().all(|c| c == ' ')
+fn terminal_width() -> usize {
+    if let Some((Width(w), _)) = terminal_size() {
+        w as usize
+    } else {
+        DEFAULT_WIDTH
+    }
+}
+
+fn terminal_backend() -> Box<dyn MarkupBackend> {
+    match ColorSupport::detect() {
+        ColorSupport::None => Box::new(TextBackend::default()),
+        support => Box::new(AnsiBackend::new(Theme::load_default(), support)),
+    }
 }
@@ -6,7 +6,12 @@
 
 #![allow(unused)]
 
+use crossterm as _;
+use dirs as _;
 use terminal_size as _;
+use toml as _;
+use unicode_segmentation as _;
+use unicode_width as _;
 
 use mymarkup::*;
 
@@ -58,7 +63,7 @@ Files without BOM must be more than 10 characters for auto-detection of UTF-8 or
 • ¬Character classes ⟦[ ]⟧ accept regex syntax such as ⟦[\\d]⟧ to match a single digit, see https://docs.rs/regex/latest/regex/#character-classes for character classes and escape sequences supported.
 
 ⌊Autorecurse glob pattern transformation⌋
-• ¬⟪Constant pattern (no filter, no **⟧) pointing to a directory⟫: ⟦\\**\\*⟧ is appended at the end to search all files of all subdirectories.
+• ¬⟪Constant pattern⟫ (no filter, no ⟦**⟧) pointing to a directory: ⟦\\**\\*⟧ is appended at the end to search all files of all subdirectories.
 • ¬⟪Patterns without ⟦**⟧ and ending with a filter⟫: ⟦\\**⟧ is inserted before final filter to find all matching files of all subdirectories.";
 
     MyMarkup::render_markup(text);
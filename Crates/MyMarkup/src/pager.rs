@@ -0,0 +1,240 @@
+// pager.rs - MyMarkup built-in pager
+// Shows long rendered markup one screen at a time, with scrolling, search and re-wrap when terminal is resized
+//
+// 2026-10-19   PV      First version
+
+use std::io::{self, IsTerminal, Write};
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{cursor, execute, queue};
+use terminal_size::{Height, Width, terminal_size};
+
+const HELP: &str = "↑↓ PgUp PgDn Home End  / search  n/N next/previous  q quit";
+
+struct Pager<'a> {
+    render: &'a dyn Fn(usize) -> String,
+    lines: Vec<String>,
+    plain_lines: Vec<String>, // Lowercase lines without escape sequences, for search
+    top: usize,
+    width: usize,
+    height: usize,
+    search: String,
+    message: String,
+}
+
+// Restores terminal state when pager ends, even after an error
+struct TerminalGuard {}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Prints rendered text through the pager if stdin and stdout are terminals and text is longer than terminal height,
+/// or prints it directly. render(width) returns text rendered at a given width, it's called again after a resize
+pub fn page(render: &dyn Fn(usize) -> String) {
+    let interactive = io::stdout().is_terminal() && io::stdin().is_terminal();
+    let (width, height) = match terminal_size() {
+        Some((Width(w), Height(h))) if interactive => (w as usize, h as usize),
+        _ => {
+            println!("{}", render(crate::terminal_width()));
+            return;
+        }
+    };
+
+    let text = render(width);
+    if text.lines().count() < height {
+        println!("{text}");
+        return;
+    }
+
+    let mut pager = Pager {
+        render,
+        lines: Vec::new(),
+        plain_lines: Vec::new(),
+        top: 0,
+        width,
+        height,
+        search: String::new(),
+        message: String::new(),
+    };
+    pager.set_text(&text);
+
+    if let Err(e) = pager.run() {
+        // Terminal is restored at this point, fall back to plain printing
+        eprintln!("MyMarkup: pager error {e}");
+        println!("{text}");
+    }
+}
+
+impl Pager<'_> {
+    fn set_text(&mut self, text: &str) {
+        self.lines = text.lines().map(|l| l.to_string()).collect();
+        self.plain_lines = self.lines.iter().map(|l| strip_escapes(l).to_lowercase()).collect();
+    }
+
+    // Text lines visible at once, last terminal line is used for status
+    fn page_height(&self) -> usize {
+        self.height.saturating_sub(1).max(1)
+    }
+
+    fn max_top(&self) -> usize {
+        self.lines.len().saturating_sub(self.page_height())
+    }
+
+    fn run(&mut self) -> io::Result<()> {
+        terminal::enable_raw_mode()?;
+        let _guard = TerminalGuard {};
+        execute!(io::stdout(), EnterAlternateScreen, cursor::Hide)?;
+
+        loop {
+            self.draw(None)?;
+            let quit = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => self.process_key(key)?,
+                Event::Resize(w, h) => {
+                    self.resize(w, h);
+                    false
+                }
+                _ => false,
+            };
+            if quit {
+                return Ok(());
+            }
+        }
+    }
+
+    // Returns true to quit
+    fn process_key(&mut self, key: KeyEvent) -> io::Result<bool> {
+        self.message.clear();
+        let page = self.page_height();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => return Ok(true),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(true),
+            KeyCode::Down | KeyCode::Enter | KeyCode::Char('j') => self.top += 1,
+            KeyCode::Up | KeyCode::Char('k') => self.top = self.top.saturating_sub(1),
+            KeyCode::PageDown | KeyCode::Char(' ') | KeyCode::Char('f') => self.top += page,
+            KeyCode::PageUp | KeyCode::Char('b') => self.top = self.top.saturating_sub(page),
+            KeyCode::Home | KeyCode::Char('g') => self.top = 0,
+            KeyCode::End | KeyCode::Char('G') => self.top = self.max_top(),
+            KeyCode::Char('/') => {
+                if let Some(s) = self.read_search()? {
+                    self.search = s.to_lowercase();
+                    self.find(true, true);
+                }
+            }
+            KeyCode::Char('n') => self.find(true, false),
+            KeyCode::Char('N') => self.find(false, false),
+            _ => {}
+        }
+        self.top = self.top.min(self.max_top());
+        Ok(false)
+    }
+
+    // Reads search string on status line, None if cancelled with Esc
+    fn read_search(&mut self) -> io::Result<Option<String>> {
+        let mut s = String::new();
+        loop {
+            self.draw(Some(&s))?;
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                    KeyCode::Enter => return Ok(Some(s)),
+                    KeyCode::Esc => return Ok(None),
+                    KeyCode::Backspace => {
+                        s.pop();
+                    }
+                    KeyCode::Char(c) => s.push(c),
+                    _ => {}
+                },
+                Event::Resize(w, h) => self.resize(w, h),
+                _ => {}
+            }
+        }
+    }
+
+    // Moves first line containing search string at top of screen. New search includes current top line
+    fn find(&mut self, forward: bool, include_top: bool) {
+        if self.search.is_empty() {
+            return;
+        }
+        let matches = |i: &usize| self.plain_lines[*i].contains(&self.search);
+        let found = if forward {
+            let start = if include_top { self.top } else { self.top + 1 };
+            (start..self.lines.len()).find(matches)
+        } else {
+            (0..self.top).rev().find(matches)
+        };
+        match found {
+            Some(i) => self.top = i,
+            None => self.message = format!("Pattern not found: {}", self.search),
+        }
+    }
+
+    // Renders text again at new width, keeping relative position. Size is read again since resize events can be queued
+    // while user drags window border, event size is only used if terminal_size fails
+    fn resize(&mut self, event_width: u16, event_height: u16) {
+        let (width, height) = match terminal_size() {
+            Some((Width(w), Height(h))) => (w as usize, h as usize),
+            None => (event_width as usize, event_height as usize),
+        };
+        if width != self.width {
+            let previous_len = self.lines.len().max(1);
+            let text = (self.render)(width);
+            self.set_text(&text);
+            self.top = self.top * self.lines.len() / previous_len;
+        }
+        self.width = width;
+        self.height = height;
+        self.top = self.top.min(self.max_top());
+    }
+
+    fn draw(&self, search_prompt: Option<&str>) -> io::Result<()> {
+        let mut stdout = io::stdout();
+        queue!(stdout, Clear(ClearType::All))?;
+        for (row, line) in self.lines.iter().skip(self.top).take(self.page_height()).enumerate() {
+            queue!(stdout, cursor::MoveTo(0, row as u16), Print(line))?;
+        }
+
+        let status = match search_prompt {
+            Some(s) => format!("/{s}"),
+            None if !self.message.is_empty() => self.message.clone(),
+            None => {
+                let last = (self.top + self.page_height()).min(self.lines.len());
+                let percent = 100 * last / self.lines.len().max(1);
+                format!("Lines {}-{last}/{} ({percent}%)  {HELP}", self.top + 1, self.lines.len())
+            }
+        };
+        let status: String = status.chars().take(self.width.saturating_sub(1)).collect();
+        queue!(
+            stdout,
+            cursor::MoveTo(0, self.page_height() as u16),
+            SetAttribute(Attribute::Reverse),
+            Print(status),
+            SetAttribute(Attribute::Reset)
+        )?;
+        stdout.flush()
+    }
+}
+
+// Removes ANSI escape sequences (ESC [ ... letter)
+pub(crate) fn strip_escapes(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+        } else {
+            res.push(c);
+        }
+    }
+    res
+}
@@ -0,0 +1,473 @@
+// parser.rs - MyMarkup parser
+// Converts markup text into a Document tree of paragraphs, spans, margins and styles, validating brackets nesting
+//
+// 2026-10-19   PV      First version
+// 2026-10-19   PV      Blocks, tables
+// 2026-10-19   PV      ⟮⟯ Color3 style
+// 2026-10-19   PV      Headings, lists and code blocks
+// 2026-10-19   PV      is_markup_char
+
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::MarkupStyle;
+
+// Opening and closing brackets of each style
+const BRACKETS: [(char, char, MarkupStyle); 6] = [
+    ('⟪', '⟫', MarkupStyle::Bold),
+    ('⟨', '⟩', MarkupStyle::Italic),
+    ('⌊', '⌋', MarkupStyle::Underline),
+    ('⟦', '⟧', MarkupStyle::Color1),
+    ('⦃', '⦄', MarkupStyle::Color2),
+    ('⟮', '⟯', MarkupStyle::Color3),
+];
+
+const MARGIN: char = '¬';
+
+// Table rows start with this char, which also separates cells
+const TABLE_SEPARATOR: char = '│';
+
+// Headings start with 1 to 3 of these chars followed by a space
+const HEADING: char = '§';
+const MAX_HEADING_LEVEL: usize = 3;
+
+// Chars starting a bullet list item when followed by a space
+const BULLETS: [char; 2] = ['•', '-'];
+
+// Lines starting with this delimit a code block
+const CODE_FENCE: &str = "```";
+
+/// True for style brackets and margin char, which are not printed
+pub fn is_markup_char(c: char) -> bool {
+    c == MARGIN || BRACKETS.iter().any(|&(open, close, _)| c == open || c == close)
+}
+
+// -----------------------------------
+// Structures
+
+/// Parsed markup, a sequence of blocks
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Document {
+    pub blocks: Vec<Block>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Paragraph(Paragraph),
+    Heading(Heading),
+    ListItem(ListItem),
+    Code(CodeBlock),
+    Table(Table),
+}
+
+/// A source line, wrapped at render time. An empty paragraph is an empty line
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Paragraph {
+    pub spans: Vec<Span>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Span {
+    Text(String),
+    Margin,                         // ¬, continuation lines are indented at this position
+    Styled(MarkupStyle, Vec<Span>), // Content between a pair of brackets
+}
+
+/// § Title, §§ Section or §§§ Subsection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    pub level: usize,
+    pub content: Paragraph,
+}
+
+/// A line starting with a bullet (• or -) or a number followed by a dot, and a space, after optional indentation.
+/// Continuation lines are aligned on item text (hanging indent)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListItem {
+    pub indent: usize, // Columns before marker: 0 for a top level item, aligned on parent item text for a nested item
+    pub marker: ListMarker,
+    pub content: Paragraph,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListMarker {
+    Bullet(char),
+    Number(usize),
+}
+
+/// Lines between ``` fences, rendered as is: no markup, no wrapping, tabs expanded
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CodeBlock {
+    pub lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColumnAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Consecutive lines starting with │, cells separated by │. If second line only contains │, ─ (or -) and :, first
+/// row is a header, and colons define alignment, :── left, ──: right, :──: center
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Table {
+    pub header: Option<Vec<Paragraph>>,
+    pub rows: Vec<Vec<Paragraph>>,
+    pub aligns: Vec<ColumnAlign>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkupErrorKind {
+    UnexpectedClosing(char),
+    MismatchedClosing { expected: char, found: char },
+    Unclosed(char),
+    UnclosedCodeBlock,
+}
+
+/// Markup error, line and column are 1-based, column is counted in chars
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarkupError {
+    pub kind: MarkupErrorKind,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for MarkupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match self.kind {
+            MarkupErrorKind::UnexpectedClosing(c) => write!(f, "closing {c} without opening bracket"),
+            MarkupErrorKind::MismatchedClosing { expected, found } => write!(f, "closing {found} found where {expected} was expected"),
+            MarkupErrorKind::Unclosed(c) => write!(f, "{c} not closed at end of line"),
+            MarkupErrorKind::UnclosedCodeBlock => write!(f, "{CODE_FENCE} code block not closed at end of text"),
+        }
+    }
+}
+
+impl Error for MarkupError {}
+
+// -----------------------------------
+
+impl ListMarker {
+    /// Marker as written in markup, • - or 1.
+    pub fn text(&self) -> String {
+        match self {
+            ListMarker::Bullet(c) => c.to_string(),
+            ListMarker::Number(n) => format!("{n}."),
+        }
+    }
+}
+
+impl Table {
+    /// Builds a table from plain text cells, not interpreted as markup, to format programs results.
+    /// Missing aligns default to Left
+    pub fn from_text(header: Option<&[&str]>, aligns: &[ColumnAlign], rows: &[Vec<String>]) -> Table {
+        Table {
+            header: header.map(text_cells),
+            rows: rows.iter().map(|r| text_cells(r)).collect(),
+            aligns: aligns.to_vec(),
+        }
+    }
+
+    /// Number of columns, the largest number of cells of a row
+    pub fn columns(&self) -> usize {
+        self.header.iter().chain(self.rows.iter()).map(|r| r.len()).max().unwrap_or(0)
+    }
+}
+
+// A style opened but not closed yet during parsing
+struct OpenStyle {
+    style: MarkupStyle,
+    open: char,
+    close: char,
+    column: usize,
+    parent_spans: Vec<Span>,
+}
+
+impl Document {
+    /// Strict parsing, returns the first nesting error found
+    pub fn parse(txt: &str) -> Result<Document, MarkupError> {
+        let (doc, errors) = Document::parse_core(txt);
+        match errors.first() {
+            Some(e) => Err(*e),
+            None => Ok(doc),
+        }
+    }
+
+    /// Lenient parsing, never fails: closing brackets without matching opening are ignored, and styles still open
+    /// at the end of a line are closed, so a style never leaks into following text
+    pub fn parse_lenient(txt: &str) -> Document {
+        Document::parse_core(txt).0
+    }
+
+    /// Returns all nesting errors of a markup text, empty if markup is valid
+    pub fn check(txt: &str) -> Vec<MarkupError> {
+        Document::parse_core(txt).1
+    }
+
+    fn parse_core(txt: &str) -> (Document, Vec<MarkupError>) {
+        let mut doc = Document::default();
+        let mut errors = Vec::new();
+
+        let lines: Vec<&str> = txt.split('\n').map(|l| l.strip_suffix('\r').unwrap_or(l)).collect();
+        // (indentation in source, indent of item text in output) of list items enclosing current line
+        let mut list_levels: Vec<(usize, usize)> = Vec::new();
+        let mut i = 0;
+        while i < lines.len() {
+            let list_item = parse_list_marker(lines[i]);
+            if list_item.is_none() {
+                list_levels.clear();
+            }
+
+            if lines[i].starts_with(CODE_FENCE) {
+                let first = i;
+                let mut code = CodeBlock::default();
+                i += 1;
+                while i < lines.len() && !lines[i].starts_with(CODE_FENCE) {
+                    code.lines.push(lines[i].to_string());
+                    i += 1;
+                }
+                if i == lines.len() {
+                    errors.push(MarkupError {
+                        kind: MarkupErrorKind::UnclosedCodeBlock,
+                        line: first + 1,
+                        column: 1,
+                    });
+                }
+                doc.blocks.push(Block::Code(code));
+                i += 1;
+            } else if let Some((source_indent, marker, text_column)) = list_item {
+                // Deeper indentation than previous item starts a nested list, same or lower indentation goes back to
+                // the matching level
+                while list_levels.last().is_some_and(|&(si, _)| source_indent < si) {
+                    list_levels.pop();
+                }
+                if list_levels.last().is_some_and(|&(si, _)| source_indent == si) {
+                    list_levels.pop();
+                }
+                let indent = list_levels.last().map(|&(_, text_indent)| text_indent).unwrap_or(0);
+                list_levels.push((source_indent, indent + marker.text().chars().count() + 1));
+
+                let text: String = lines[i].chars().skip(text_column - 1).collect();
+                doc.blocks.push(Block::ListItem(ListItem {
+                    indent,
+                    marker,
+                    content: Paragraph {
+                        spans: parse_inline(&text, i + 1, text_column, &mut errors),
+                    },
+                }));
+                i += 1;
+            } else if let Some(level) = heading_level(lines[i]) {
+                doc.blocks.push(Block::Heading(Heading {
+                    level,
+                    content: Paragraph {
+                        spans: parse_inline(&lines[i][level * HEADING.len_utf8() + 1..], i + 1, level + 2, &mut errors),
+                    },
+                }));
+                i += 1;
+            } else if lines[i].starts_with(TABLE_SEPARATOR) {
+                let first = i;
+                while i < lines.len() && lines[i].starts_with(TABLE_SEPARATOR) {
+                    i += 1;
+                }
+                doc.blocks.push(Block::Table(parse_table(&lines[first..i], first + 1, &mut errors)));
+            } else {
+                doc.blocks.push(Block::Paragraph(Paragraph {
+                    spans: parse_inline(lines[i], i + 1, 1, &mut errors),
+                }));
+                i += 1;
+            }
+        }
+
+        (doc, errors)
+    }
+}
+
+// Returns heading level if line starts with 1 to 3 § followed by a space
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|&c| c == HEADING).count();
+    let rest = &line[level * HEADING.len_utf8()..];
+    if (1..=MAX_HEADING_LEVEL).contains(&level) && rest.starts_with(' ') { Some(level) } else { None }
+}
+
+// Returns (number of leading spaces, marker, 1-based column of item text) if line is a list item
+fn parse_list_marker(line: &str) -> Option<(usize, ListMarker, usize)> {
+    let source_indent = line.chars().take_while(|&c| c == ' ').count();
+    let rest = &line[source_indent..];
+
+    let first = rest.chars().next()?;
+    let (marker, marker_len) = if BULLETS.contains(&first) {
+        (ListMarker::Bullet(first), 1)
+    } else {
+        let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 || digits > 9 || !rest[digits..].starts_with('.') {
+            return None;
+        }
+        (ListMarker::Number(rest[..digits].parse().ok()?), digits + 1)
+    };
+
+    // Marker must be followed by a space, extra spaces are part of the indentation of item text
+    let after: String = rest.chars().skip(marker_len).collect();
+    let spaces = after.chars().take_while(|&c| c == ' ').count();
+    if spaces == 0 {
+        return None;
+    }
+    Some((source_indent, marker, source_indent + marker_len + spaces + 1))
+}
+
+// Parses table lines, first_line is the 1-based line number of first table line
+fn parse_table(lines: &[&str], first_line: usize, errors: &mut Vec<MarkupError>) -> Table {
+    let mut table = Table::default();
+
+    let mut rows_start = 0;
+    if lines.len() > 1
+        && let Some(aligns) = parse_table_separator(lines[1])
+    {
+        table.header = Some(parse_table_row(lines[0], first_line, errors));
+        table.aligns = aligns;
+        rows_start = 2;
+    }
+
+    for (i, line) in lines.iter().enumerate().skip(rows_start) {
+        table.rows.push(parse_table_row(line, first_line + i, errors));
+    }
+
+    table
+}
+
+fn parse_table_row(line: &str, line_number: usize, errors: &mut Vec<MarkupError>) -> Vec<Paragraph> {
+    // Skip leading │, and ignore optional final │
+    let content = &line[TABLE_SEPARATOR.len_utf8()..];
+    let content = content.strip_suffix(TABLE_SEPARATOR).unwrap_or(content);
+
+    let mut cells = Vec::new();
+    let mut column = 2;
+    for cell in content.split(TABLE_SEPARATOR) {
+        let trimmed = cell.trim_start_matches(' ');
+        let cell_column = column + (cell.chars().count() - trimmed.chars().count());
+        cells.push(Paragraph {
+            spans: parse_inline(trimmed.trim_end_matches(' '), line_number, cell_column, errors),
+        });
+        column += cell.chars().count() + 1;
+    }
+    cells
+}
+
+// Returns columns alignment if line is a header separator, such as │:──│──:│
+fn parse_table_separator(line: &str) -> Option<Vec<ColumnAlign>> {
+    let content = &line[TABLE_SEPARATOR.len_utf8()..];
+    let content = content.strip_suffix(TABLE_SEPARATOR).unwrap_or(content);
+
+    let mut aligns = Vec::new();
+    for cell in content.split(TABLE_SEPARATOR) {
+        let cell = cell.trim();
+        let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+        if dashes.is_empty() || !dashes.chars().all(|c| c == '─' || c == '-') {
+            return None;
+        }
+        aligns.push(match (cell.starts_with(':'), cell.ends_with(':')) {
+            (true, true) => ColumnAlign::Center,
+            (false, true) => ColumnAlign::Right,
+            _ => ColumnAlign::Left,
+        });
+    }
+    Some(aligns)
+}
+
+// Parses styles and margins of a single line (or table cell); first_column is the 1-based column of text start
+fn parse_inline(text: &str, line_number: usize, first_column: usize, errors: &mut Vec<MarkupError>) -> Vec<Span> {
+    let mut stack: Vec<OpenStyle> = Vec::new();
+    let mut spans: Vec<Span> = Vec::new();
+    let mut buffer = String::new();
+
+    for (col_index, c) in text.chars().enumerate() {
+        let column = first_column + col_index;
+
+        if let Some(&(open, close, style)) = BRACKETS.iter().find(|b| b.0 == c) {
+            flush_text(&mut buffer, &mut spans);
+            stack.push(OpenStyle {
+                style,
+                open,
+                close,
+                column,
+                parent_spans: std::mem::take(&mut spans),
+            });
+        } else if let Some(&(_, close, style)) = BRACKETS.iter().find(|b| b.1 == c) {
+            flush_text(&mut buffer, &mut spans);
+            match stack.last() {
+                Some(top) if top.style == style => close_style(&mut stack, &mut spans),
+                Some(top) => {
+                    errors.push(MarkupError {
+                        kind: MarkupErrorKind::MismatchedClosing {
+                            expected: top.close,
+                            found: close,
+                        },
+                        line: line_number,
+                        column,
+                    });
+                    // If style is open deeper, close everything up to it, otherwise ignore this bracket
+                    if stack.iter().any(|os| os.style == style) {
+                        while let Some(top) = stack.last() {
+                            let done = top.style == style;
+                            close_style(&mut stack, &mut spans);
+                            if done {
+                                break;
+                            }
+                        }
+                    }
+                }
+                None => errors.push(MarkupError {
+                    kind: MarkupErrorKind::UnexpectedClosing(close),
+                    line: line_number,
+                    column,
+                }),
+            }
+        } else if c == MARGIN {
+            flush_text(&mut buffer, &mut spans);
+            spans.push(Span::Margin);
+        } else if c != '\r' {
+            buffer.push(c);
+        }
+    }
+
+    flush_text(&mut buffer, &mut spans);
+    for os in stack.iter() {
+        errors.push(MarkupError {
+            kind: MarkupErrorKind::Unclosed(os.open),
+            line: line_number,
+            column: os.column,
+        });
+    }
+    while !stack.is_empty() {
+        close_style(&mut stack, &mut spans);
+    }
+
+    spans
+}
+
+fn text_cells<S: AsRef<str>>(row: &[S]) -> Vec<Paragraph> {
+    row.iter()
+        .map(|cell| {
+            let cell = cell.as_ref();
+            Paragraph {
+                spans: if cell.is_empty() { vec![] } else { vec![Span::Text(cell.replace(['\r', '\n'], " "))] },
+            }
+        })
+        .collect()
+}
+
+fn flush_text(text: &mut String, spans: &mut Vec<Span>) {
+    if !text.is_empty() {
+        spans.push(Span::Text(std::mem::take(text)));
+    }
+}
+
+// Pops top style of the stack, and appends it as a Styled span to its parent spans, that become current spans
+fn close_style(stack: &mut Vec<OpenStyle>, spans: &mut Vec<Span>) {
+    if let Some(os) = stack.pop() {
+        let children = std::mem::replace(spans, os.parent_spans);
+        spans.push(Span::Styled(os.style, children));
+    }
+}
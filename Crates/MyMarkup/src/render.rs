@@ -0,0 +1,452 @@
+// render.rs - MyMarkup rendering
+// Renders a parsed Document with a backend: word wrapping, margins, styles transitions and tables layout
+//
+// 2026-10-19   PV      First version, extracted from build_markup_core state machine
+// 2026-10-19   PV      Layout by lines, tables
+// 2026-10-19   PV      Wrapping based on grapheme clusters and East Asian display width
+// 2026-10-19   PV      Headings, lists, code blocks and tab stops
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{Block, CodeBlock, ColumnAlign, Document, MarkupBackend, MarkupStyle, Paragraph, Span, Table};
+
+// Spaces between table columns
+const COLUMN_GAP: usize = 2;
+
+// Distance between tab stops
+const TAB_WIDTH: usize = 8;
+
+// A user-perceived character (grapheme cluster), its display width in columns, and the styles stack active at its position
+struct Grapheme {
+    text: String,
+    width: usize,
+    styles: Vec<MarkupStyle>,
+}
+
+enum Token {
+    Word(Vec<Grapheme>),
+    Spaces(Vec<Grapheme>),
+    Tab(Vec<MarkupStyle>),
+    Margin,
+}
+
+// A rendered output line, and its visible width
+#[derive(Debug, Default)]
+struct Line {
+    text: String,
+    width: usize,
+}
+
+// Wraps a paragraph into lines of at most width columns
+struct ParagraphLayout<'a> {
+    backend: &'a mut dyn MarkupBackend,
+    width: usize,
+    lines: Vec<Line>,
+    current: Line,
+    tab: usize,
+    open_styles: Vec<MarkupStyle>,
+}
+
+pub fn render_document(doc: &Document, backend: &mut dyn MarkupBackend, show_limits: bool, width: usize) -> String {
+    // Backends doing their own layout get an unlimited width
+    let (show_limits, width) = if backend.wraps() { (show_limits, width.max(1)) } else { (false, usize::MAX) };
+
+    let mut out = String::from(backend.begin());
+    if show_limits {
+        out.push_str(&"-".repeat(width));
+        backend.push_newline(&mut out);
+    }
+
+    for (i, block) in doc.blocks.iter().enumerate() {
+        if i > 0 {
+            backend.push_newline(&mut out);
+        }
+
+        let lines = match block {
+            Block::Paragraph(paragraph) => layout_paragraph(paragraph, &[], "", backend, width),
+            Block::Heading(heading) => {
+                let styles = backend.heading_styles(heading.level);
+                let prefix = backend.heading_prefix(heading.level);
+                layout_paragraph(&heading.content, &styles, &prefix, backend, width)
+            }
+            Block::ListItem(item) => {
+                let prefix = format!("{}{} ", " ".repeat(item.indent), backend.list_marker(item.marker));
+                layout_paragraph(&item.content, &[], &prefix, backend, width)
+            }
+            Block::Code(code) if !backend.wraps() => {
+                let lines = code.lines.iter().map(|l| expand_tabs(l)).collect::<Vec<_>>();
+                backend.push_code_block(&lines, &mut out);
+                continue;
+            }
+            Block::Code(code) => layout_code(code, backend),
+            Block::Table(table) if !backend.wraps() => {
+                render_native_table(table, backend, &mut out);
+                continue;
+            }
+            Block::Table(table) => layout_table(table, backend, width),
+        };
+
+        for (j, line) in lines.iter().enumerate() {
+            if j > 0 {
+                backend.push_newline(&mut out);
+            }
+            out.push_str(&line.text);
+            if show_limits {
+                out.push_str(&" ".repeat(width.saturating_sub(line.width)));
+                out.push('|');
+            }
+        }
+    }
+
+    out.push_str(backend.end());
+    out
+}
+
+// Wraps paragraph at width, base_styles are applied to all text (used for tables and headings). prefix is emitted as is
+// at the beginning of first line (list marker), continuation lines are indented by its width
+fn layout_paragraph(
+    paragraph: &Paragraph,
+    base_styles: &[MarkupStyle],
+    prefix: &str,
+    backend: &mut dyn MarkupBackend,
+    width: usize,
+) -> Vec<Line> {
+    let prefix_width = prefix.width();
+    let mut pl = ParagraphLayout {
+        backend,
+        width,
+        lines: Vec::new(),
+        current: Line {
+            text: prefix.to_string(),
+            width: prefix_width,
+        },
+        tab: prefix_width,
+        open_styles: Vec::new(),
+    };
+
+    let mut pending_spaces: Vec<Grapheme> = Vec::new();
+    for token in tokenize(paragraph, base_styles) {
+        match token {
+            Token::Spaces(spaces) => pending_spaces.extend(spaces),
+            Token::Margin => {
+                for g in pending_spaces.drain(..) {
+                    pl.emit_grapheme(&g);
+                }
+                pl.tab = pl.current.width;
+            }
+            Token::Tab(styles) => {
+                // Spaces up to next tab stop, they are kept even at line end, but never exceed width
+                for g in pending_spaces.drain(..) {
+                    pl.emit_grapheme(&g);
+                }
+                let stop = (pl.current.width / TAB_WIDTH + 1) * TAB_WIDTH;
+                let space = Grapheme {
+                    text: " ".to_string(),
+                    width: 1,
+                    styles,
+                };
+                for _ in pl.current.width..stop.min(pl.width) {
+                    pl.emit_grapheme(&space);
+                }
+            }
+            Token::Word(word) => {
+                if pl.current.width + total_width(&pending_spaces) + total_width(&word) <= pl.width {
+                    for g in pending_spaces.drain(..) {
+                        pl.emit_grapheme(&g);
+                    }
+                } else if pl.current.width > pl.tab {
+                    // Something printed after margin, a line break gives more room; spaces are dropped at line break
+                    pending_spaces.clear();
+                    pl.new_line();
+                } else {
+                    // Leading spaces of a paragraph are kept if there is room left for at least one char of the word
+                    for g in pending_spaces.drain(..) {
+                        if pl.current.width + 1 < pl.width {
+                            pl.emit_grapheme(&g);
+                        }
+                    }
+                }
+                pl.emit_word(&word);
+            }
+        }
+    }
+
+    pl.transition(&[]);
+    let current = std::mem::take(&mut pl.current);
+    pl.lines.push(current);
+    pl.lines
+}
+
+impl ParagraphLayout<'_> {
+    // Emits a word at current position, breaking it if it's longer than available width
+    fn emit_word(&mut self, word: &[Grapheme]) {
+        for g in word {
+            // A line break is useless if continuation line doesn't offer more room, grapheme is emitted anyway
+            let more_room = self.current.width > self.tab || self.tab >= self.width;
+            if self.current.width + g.width > self.width && self.current.width > 0 && more_room {
+                self.new_line();
+            }
+            self.emit_grapheme(g);
+        }
+    }
+
+    fn emit_grapheme(&mut self, g: &Grapheme) {
+        self.transition(&g.styles);
+        for c in g.text.chars() {
+            self.backend.push_char(c, &mut self.current.text);
+        }
+        self.current.width += g.width;
+    }
+
+    // Ends current line and starts a continuation line, indented at margin
+    fn new_line(&mut self) {
+        self.transition(&[]);
+        let current = std::mem::take(&mut self.current);
+        self.lines.push(current);
+
+        // A margin leaving no room for text is ignored
+        if self.tab >= self.width {
+            self.tab = 0;
+        }
+        self.current.text.push_str(&" ".repeat(self.tab));
+        self.current.width = self.tab;
+    }
+
+    // Closes and opens styles to go from currently open styles to target styles
+    fn transition(&mut self, target: &[MarkupStyle]) {
+        let common = self.open_styles.iter().zip(target).take_while(|(a, b)| a == b).count();
+        while self.open_styles.len() > common {
+            let style = self.open_styles.pop().unwrap();
+            let s = self.backend.style_off(style);
+            self.current.text.push_str(&s);
+        }
+        for &style in &target[common..] {
+            let s = self.backend.style_on(style);
+            self.current.text.push_str(&s);
+            self.open_styles.push(style);
+        }
+    }
+}
+
+fn total_width(graphemes: &[Grapheme]) -> usize {
+    graphemes.iter().map(|g| g.width).sum()
+}
+
+// Flattens paragraph spans into words, spaces and margins, each grapheme carrying its styles
+fn tokenize(paragraph: &Paragraph, base_styles: &[MarkupStyle]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut styles = base_styles.to_vec();
+    flatten(&paragraph.spans, &mut styles, &mut tokens);
+    tokens
+}
+
+fn flatten(spans: &[Span], styles: &mut Vec<MarkupStyle>, tokens: &mut Vec<Token>) {
+    for span in spans {
+        match span {
+            Span::Text(text) => {
+                for text in text.graphemes(true) {
+                    let g = Grapheme {
+                        text: text.to_string(),
+                        width: text.width(),
+                        styles: styles.clone(),
+                    };
+                    if text == "\t" {
+                        tokens.push(Token::Tab(g.styles));
+                        continue;
+                    }
+                    // Wide chars (CJK ideographs, emoji, ...) are words by themselves, a line can break between them
+                    let wide = g.width > 1;
+                    match (text == " ", tokens.last_mut()) {
+                        (true, Some(Token::Spaces(spaces))) => spaces.push(g),
+                        (false, Some(Token::Word(word))) if !wide && word.last().is_some_and(|last| last.width <= 1) => word.push(g),
+                        (true, _) => tokens.push(Token::Spaces(vec![g])),
+                        (false, _) => tokens.push(Token::Word(vec![g])),
+                    }
+                }
+            }
+            Span::Margin => tokens.push(Token::Margin),
+            Span::Styled(style, children) => {
+                styles.push(*style);
+                flatten(children, styles, tokens);
+                styles.pop();
+            }
+        }
+    }
+}
+
+// -----------------------------------
+// Code blocks
+
+// Code lines are not wrapped, they may be wider than width
+fn layout_code(code: &CodeBlock, backend: &mut dyn MarkupBackend) -> Vec<Line> {
+    code.lines
+        .iter()
+        .map(|l| {
+            let expanded = expand_tabs(l);
+            let mut line = Line {
+                text: String::new(),
+                width: expanded.width(),
+            };
+            for c in expanded.chars() {
+                backend.push_char(c, &mut line.text);
+            }
+            line
+        })
+        .collect()
+}
+
+// Replaces tabs by spaces up to next tab stop
+fn expand_tabs(line: &str) -> String {
+    let mut res = String::new();
+    let mut width = 0;
+    for g in line.graphemes(true) {
+        if g == "\t" {
+            let stop = (width / TAB_WIDTH + 1) * TAB_WIDTH;
+            res.push_str(&" ".repeat(stop - width));
+            width = stop;
+        } else {
+            res.push_str(g);
+            width += g.width();
+        }
+    }
+    res
+}
+
+// -----------------------------------
+// Tables
+
+// Returns (natural width, longest word width) of a cell
+fn cell_widths(cell: &Paragraph) -> (usize, usize) {
+    let mut natural = 0;
+    let mut longest = 0;
+    for token in tokenize(cell, &[]) {
+        match token {
+            Token::Word(w) => {
+                natural += total_width(&w);
+                longest = longest.max(total_width(&w));
+            }
+            Token::Spaces(s) => natural += total_width(&s),
+            Token::Tab(_) => natural += TAB_WIDTH,
+            Token::Margin => {}
+        }
+    }
+    (natural, longest)
+}
+
+// Computes columns widths from content, shrinking widest columns first until table fits in width
+fn columns_widths(table: &Table, width: usize) -> Vec<usize> {
+    let columns = table.columns();
+    let mut natural = vec![0; columns];
+    let mut minimum = vec![1; columns];
+    for row in table.header.iter().chain(table.rows.iter()) {
+        for (c, cell) in row.iter().enumerate() {
+            let (n, m) = cell_widths(cell);
+            natural[c] = natural[c].max(n);
+            minimum[c] = minimum[c].max(m);
+        }
+    }
+
+    let available = width.saturating_sub(COLUMN_GAP * columns.saturating_sub(1));
+    let mut widths = natural.iter().map(|&n| n.max(1)).collect::<Vec<_>>();
+    while widths.iter().sum::<usize>() > available {
+        // Shrink widest column still above its longest word, or else widest column, words will be broken
+        let candidate = (0..columns)
+            .filter(|&c| widths[c] > minimum[c])
+            .max_by_key(|&c| widths[c])
+            .or_else(|| (0..columns).filter(|&c| widths[c] > 1).max_by_key(|&c| widths[c]));
+        match candidate {
+            Some(c) => widths[c] -= 1,
+            None => break,
+        }
+    }
+    widths
+}
+
+fn layout_table(table: &Table, backend: &mut dyn MarkupBackend, width: usize) -> Vec<Line> {
+    let widths = columns_widths(table, width);
+    let mut lines = Vec::new();
+
+    if let Some(header) = &table.header {
+        layout_table_row(header, table, &widths, &[MarkupStyle::Bold], backend, &mut lines);
+
+        let text = widths.iter().map(|&w| "─".repeat(w)).collect::<Vec<_>>().join(&" ".repeat(COLUMN_GAP));
+        lines.push(Line {
+            width: widths.iter().sum::<usize>() + COLUMN_GAP * widths.len().saturating_sub(1),
+            text,
+        });
+    }
+    for row in table.rows.iter() {
+        layout_table_row(row, table, &widths, &[], backend, &mut lines);
+    }
+
+    lines
+}
+
+fn layout_table_row(
+    row: &[Paragraph],
+    table: &Table,
+    widths: &[usize],
+    base_styles: &[MarkupStyle],
+    backend: &mut dyn MarkupBackend,
+    lines: &mut Vec<Line>,
+) {
+    let empty = Paragraph::default();
+    let cells = (0..widths.len())
+        .map(|c| layout_paragraph(row.get(c).unwrap_or(&empty), base_styles, "", backend, widths[c]))
+        .collect::<Vec<_>>();
+    let height = cells.iter().map(|cl| cl.len()).max().unwrap_or(0);
+
+    for i in 0..height {
+        let mut line = Line::default();
+        for (c, cell_lines) in cells.iter().enumerate() {
+            if c > 0 {
+                line.text.push_str(&" ".repeat(COLUMN_GAP));
+                line.width += COLUMN_GAP;
+            }
+            let (text, w) = match cell_lines.get(i) {
+                Some(cl) => (cl.text.as_str(), cl.width),
+                None => ("", 0),
+            };
+            let padding = widths[c].saturating_sub(w);
+            let (before, after) = match table.aligns.get(c).copied().unwrap_or_default() {
+                ColumnAlign::Left => (0, padding),
+                ColumnAlign::Right => (padding, 0),
+                ColumnAlign::Center => (padding / 2, padding - padding / 2),
+            };
+            line.text.push_str(&" ".repeat(before));
+            line.text.push_str(text);
+            line.text.push_str(&" ".repeat(after));
+            line.width += before + w + after;
+        }
+
+        // No trailing spaces
+        let trimmed = line.text.trim_end_matches(' ').len();
+        line.width -= line.text.len() - trimmed;
+        line.text.truncate(trimmed);
+        lines.push(line);
+    }
+}
+
+// Backends that don't wrap get cells rendered on a single line, and format the table themselves
+fn render_native_table(table: &Table, backend: &mut dyn MarkupBackend, out: &mut String) {
+    let columns = table.columns();
+    let render_row = |row: &[Paragraph], backend: &mut dyn MarkupBackend| -> Vec<String> {
+        let empty = Paragraph::default();
+        (0..columns)
+            .map(|c| {
+                layout_paragraph(row.get(c).unwrap_or(&empty), &[], "", backend, usize::MAX)
+                    .into_iter()
+                    .map(|l| l.text)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect()
+    };
+
+    let header = table.header.as_ref().map(|h| render_row(h, backend));
+    let rows = table.rows.iter().map(|r| render_row(r, backend)).collect::<Vec<_>>();
+    let mut aligns = table.aligns.clone();
+    aligns.resize(columns, ColumnAlign::Left);
+    backend.push_table(header.as_deref(), &rows, &aligns, out);
+}
@@ -1,6 +1,14 @@
 // tests.rs - MyMarkup tests
 //
 // 2025-07-05   PV      First version with MyMarkup 1.1 that can generate string output
+// 2026-10-19   PV      Backends tests
+// 2026-10-19   PV      Parser tests
+// 2026-10-19   PV      Tables tests
+// 2026-10-19   PV      Unicode width tests
+// 2026-10-19   PV      Themes tests
+// 2026-10-19   PV      Pager tests
+// 2026-10-19   PV      Headings, lists, code blocks and tabs tests
+// 2026-10-19   PV      is_markup_char test
 
 #![cfg(test)]
 
@@ -110,3 +118,367 @@ petite.   |";
     let s = MyMarkup::build_markup_core(text, true, 10);
     assert_eq!(s, expected);
 }
+
+#[test]
+fn test_text_backend() {
+    let text = "⌊Usage⌋: ⟪rgrep⟫ [⦃-i⦄] ⟨pattern⟩";
+    let s = MyMarkup::build_markup_backend(text, &mut TextBackend::default(), false, 80);
+    assert_eq!(s, "Usage: rgrep [-i] pattern");
+}
+
+#[test]
+fn test_text_backend_margin() {
+    let text = "⦃-F⦄  ¬Fixed string search, also for patterns starting with -";
+    let expected = "-F  Fixed string search,
+    also for patterns
+    starting with -";
+
+    let s = MyMarkup::build_markup_backend(text, &mut TextBackend::default(), false, 25);
+    assert_eq!(s, expected);
+}
+
+#[test]
+fn test_markdown_backend() {
+    let text = "⌊Options⌋:\n⦃-i⦄ ¬Ignore ⟪case⟫ for a_b\n\n⟦[*]⟧ matches a star";
+    let expected = "<u>Options</u>:  \n`-i` Ignore **case** for a\\_b  \n\n`[*]` matches a star";
+
+    let s = MyMarkup::build_markup_backend(text, &mut MarkdownBackend::default(), false, 10);
+    assert_eq!(s, expected);
+}
+
+#[test]
+fn test_html_backend() {
+    let text = "⟪a<b⟫ & ⦃c⦄";
+    let s = MyMarkup::build_markup_backend(text, &mut HtmlBackend::default(), false, 80);
+    assert_eq!(s, "<pre class=\"mymarkup\"><b>a&lt;b</b> &amp; <span class=\"mm-color2\">c</span></pre>");
+}
+
+#[test]
+fn test_format_names() {
+    assert_eq!(MarkupFormat::from_name("MD"), Some(MarkupFormat::Markdown));
+    assert_eq!(MarkupFormat::from_name("txt"), Some(MarkupFormat::Text));
+    assert_eq!(MarkupFormat::from_name("pdf"), None);
+}
+
+#[test]
+fn test_parse_tree() {
+    let doc = Document::parse("⦃-i⦄  ¬Ignore ⟪⟨case⟩⟫\n").unwrap();
+    let expected = Document {
+        blocks: vec![
+            Block::Paragraph(Paragraph {
+                spans: vec![
+                    Span::Styled(MarkupStyle::Color2, vec![Span::Text("-i".to_string())]),
+                    Span::Text("  ".to_string()),
+                    Span::Margin,
+                    Span::Text("Ignore ".to_string()),
+                    Span::Styled(MarkupStyle::Bold, vec![Span::Styled(MarkupStyle::Italic, vec![Span::Text("case".to_string())])]),
+                ],
+            }),
+            Block::Paragraph(Paragraph::default()),
+        ],
+    };
+    assert_eq!(doc, expected);
+}
+
+#[test]
+fn test_parse_errors() {
+    let errors = MyMarkup::check_markup("Ok ⟪bold⟫\n⦃-glob opt⟩ x⦄\n⌊Types⌋⟫:\nOpen ⟦here");
+    assert_eq!(
+        errors,
+        vec![
+            MarkupError {
+                kind: MarkupErrorKind::MismatchedClosing { expected: '⦄', found: '⟩' },
+                line: 2,
+                column: 11
+            },
+            MarkupError {
+                kind: MarkupErrorKind::UnexpectedClosing('⟫'),
+                line: 3,
+                column: 8
+            },
+            MarkupError {
+                kind: MarkupErrorKind::Unclosed('⟦'),
+                line: 4,
+                column: 6
+            },
+        ]
+    );
+    assert_eq!(errors[2].to_string(), "line 4, column 6: ⟦ not closed at end of line");
+    assert!(Document::parse("⟪a⟫ ⟨b⟩").is_ok());
+}
+
+#[test]
+fn test_no_style_leak() {
+    // Unclosed bold is closed at end of line, stray closing bracket is ignored
+    let s = MyMarkup::build_markup_core("⟪Bold\nPlain⟫", false, 80);
+    assert_eq!(s, format!("{STYLE_BOLD_ON}Bold{STYLE_BOLD_OFF}\nPlain"));
+}
+
+#[test]
+fn test_style_closed_at_wrap() {
+    // Margin indentation of continuation line is not underlined
+    let s = MyMarkup::build_markup_core("x ¬⌊aaa bbb⌋", false, 6);
+    assert_eq!(s, format!("x {STYLE_UNDERLINE_ON}aaa{STYLE_UNDERLINE_OFF}\n  {STYLE_UNDERLINE_ON}bbb{STYLE_UNDERLINE_OFF}"));
+}
+
+#[test]
+fn test_table_layout() {
+    let text = "Options:
+│Option│Size│Description
+│:──│──:│──
+│-glob│12│Globbing specific options
+│-n│1234│No action
+End";
+    let expected = "Options:
+Option  Size  Description
+──────  ────  ─────────────────────────
+-glob     12  Globbing specific options
+-n      1234  No action
+End";
+
+    let s = MyMarkup::build_markup_backend(text, &mut TextBackend::default(), false, 80);
+    assert_eq!(s, expected);
+}
+
+#[test]
+fn test_table_wrap() {
+    // Description column shrinks to fit in 24 columns, and cells wrap inside their column
+    let text = "│-glob│Globbing specific options
+│-v│Verbose output";
+    let expected = "------------------------
+-glob  Globbing specific|
+       options          |
+-v     Verbose output   |";
+
+    let s = MyMarkup::build_markup_backend(text, &mut TextBackend::default(), true, 24);
+    assert_eq!(s, expected);
+}
+
+#[test]
+fn test_table_header_style() {
+    let text = "│Name│─\n│:─:│─:\n│a│1";
+    let doc = Document::parse(text).unwrap();
+    let Block::Table(table) = &doc.blocks[0] else { panic!("Table expected") };
+    assert_eq!(table.aligns, vec![ColumnAlign::Center, ColumnAlign::Right]);
+    assert_eq!(table.rows.len(), 1);
+
+    let s = MyMarkup::build_markup_core("│a│b\n│─│─\n│c│d", false, 80);
+    assert_eq!(s, format!("{STYLE_BOLD_ON}a{STYLE_BOLD_OFF}  {STYLE_BOLD_ON}b{STYLE_BOLD_OFF}\n─  ─\nc  d"));
+}
+
+#[test]
+fn test_table_markdown() {
+    let text = "│Option│Description\n│──│──:\n│⦃-n⦄│No action";
+    let s = MyMarkup::build_markup_backend(text, &mut MarkdownBackend::default(), false, 80);
+    assert_eq!(s, "| Option | Description |\n| --- | ---: |\n| `-n` | No action |");
+}
+
+#[test]
+fn test_table_from_text() {
+    let rows = vec![vec!["C:\\Temp\\a.txt".to_string(), "1 024".to_string()], vec!["⟪b⟫".to_string(), "7".to_string()]];
+    let table = Table::from_text(Some(&["File", "Size"]), &[ColumnAlign::Left, ColumnAlign::Right], &rows);
+    let doc = Document {
+        blocks: vec![Block::Table(table)],
+    };
+    let s = MyMarkup::render_document(&doc, &mut TextBackend::default(), false, 80);
+    assert_eq!(s, "File            Size\n─────────────  ─────\nC:\\Temp\\a.txt  1 024\n⟪b⟫                7");
+}
+
+// Checks that each line between limits is exactly width columns wide on screen
+fn check_display_width(s: &str, width: usize) {
+    use unicode_width::UnicodeWidthStr;
+    for line in s.lines().skip(1) {
+        assert_eq!(line.width(), width + 1, "Bad width for line «{line}»");
+    }
+}
+
+#[test]
+fn test_cjk_wrap() {
+    let text = "Le japonais 日本語のテキスト s'écrit sans espaces.";
+    let expected = "--------------
+Le japonais 日|
+本語のテキスト|
+s'écrit sans  |
+espaces.      |";
+
+    let s = MyMarkup::build_markup_core(text, true, 14);
+    assert_eq!(s, expected);
+    check_display_width(&s, 14);
+}
+
+#[test]
+fn test_combining_marks() {
+    // e + U+0301 combining acute accent is a single column
+    let text = "Cafe\u{301} cre\u{300}me bru\u{302}le\u{301}e";
+    let expected = "------------
+Cafe\u{301} cre\u{300}me  |
+bru\u{302}le\u{301}e      |";
+
+    let s = MyMarkup::build_markup_core(text, true, 12);
+    assert_eq!(s, expected);
+    check_display_width(&s, 12);
+}
+
+#[test]
+fn test_emoji_zwj() {
+    // Family emoji is a single grapheme made of 3 emoji joined by ZWJ, 2 columns wide
+    let text = "Famille 👨‍👩‍👧 et drapeau 🇫🇷 ok";
+    let expected = "----------
+Famille 👨‍👩‍👧|
+et drapeau|
+🇫🇷 ok     |";
+
+    let s = MyMarkup::build_markup_core(text, true, 10);
+    assert_eq!(s, expected);
+    check_display_width(&s, 10);
+}
+
+#[test]
+fn test_pound_sign() {
+    // £ used to be an internal end marker
+    let s = MyMarkup::build_markup_backend("Prix: 12£\n£", &mut TextBackend::default(), false, 80);
+    assert_eq!(s, "Prix: 12£\n£");
+}
+
+#[test]
+fn test_theme_style_parse() {
+    let style = ThemeStyle::parse("bold #ff8000 on bright_blue").unwrap();
+    assert!(style.bold && !style.italic);
+    assert_eq!(style.fg, Some(Color::Rgb(255, 128, 0)));
+    assert_eq!(style.bg, Some(Color::Ansi(12)));
+    assert_eq!(style.on_sequence(ColorSupport::TrueColor), "\x1b[1;38;2;255;128;0;104m");
+    assert_eq!(style.off_sequence(), "\x1b[22;39;49m");
+
+    assert!(ThemeStyle::parse("bold purple").is_err());
+}
+
+#[test]
+fn test_color_downgrade() {
+    let style = ThemeStyle::parse("#ff8000").unwrap();
+    assert_eq!(style.on_sequence(ColorSupport::Ansi256), "\x1b[38;5;208m");
+    assert_eq!(style.on_sequence(ColorSupport::Ansi16), "\x1b[33m");
+    // Attributes are kept without colors
+    assert_eq!(ThemeStyle::parse("underline 196").unwrap().on_sequence(ColorSupport::None), "\x1b[4m");
+    assert_eq!(ThemeStyle::parse("bold").unwrap().paint("x", ColorSupport::None), "x");
+}
+
+#[test]
+fn test_color_support_detection() {
+    let env = |vars: &'static [(&'static str, &'static str)]| {
+        move |name: &str| vars.iter().find(|v| v.0 == name).map(|v| v.1.to_string()).unwrap_or_default()
+    };
+    assert_eq!(ColorSupport::detect_with(env(&[("TERM", "xterm")]), true), ColorSupport::Ansi16);
+    assert_eq!(ColorSupport::detect_with(env(&[("TERM", "xterm")]), false), ColorSupport::None);
+    assert_eq!(ColorSupport::detect_with(env(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")]), true), ColorSupport::None);
+    assert_eq!(ColorSupport::detect_with(env(&[("CLICOLOR_FORCE", "1"), ("TERM", "xterm-256color")]), false), ColorSupport::Ansi256);
+    assert_eq!(ColorSupport::detect_with(env(&[("CLICOLOR_FORCE", "0")]), false), ColorSupport::None);
+    assert_eq!(ColorSupport::detect_with(env(&[("COLORTERM", "truecolor")]), true), ColorSupport::TrueColor);
+}
+
+#[test]
+fn test_theme_file() {
+    let theme = Theme::from_toml_str("color1 = \"bold blue\"\n\"rgrep.match\" = \"red on white\"").unwrap();
+    let s = MyMarkup::build_markup_backend("⟦a⟧ ⟮b⟯", &mut AnsiBackend::new(theme.clone(), ColorSupport::Ansi16), false, 80);
+    assert_eq!(s, "\x1b[1;34ma\x1b[22;39m \x1b[32mb\x1b[39m");
+    assert_eq!(theme.named("rgrep.match"), Some(ThemeStyle::parse("red on white").unwrap()));
+
+    assert!(Theme::from_toml_str("color1 = 12").is_err());
+}
+
+#[test]
+fn test_nested_colors_restored() {
+    // Closing inner color restores outer color
+    let s = MyMarkup::build_markup_core("⟦a⦃b⦄c⟧", false, 80);
+    assert_eq!(s, format!("{FG_CYAN}a{FG_YELLOW}b{FG_DEFAULT}{FG_CYAN}c{FG_DEFAULT}"));
+}
+
+#[test]
+fn test_pager_strip_escapes() {
+    let s = MyMarkup::build_markup_core("⟪Bold⟫ and ⟦color⟧", false, 80);
+    assert_eq!(pager::strip_escapes(&s), "Bold and color");
+}
+
+#[test]
+fn test_headings() {
+    let s = MyMarkup::build_markup_backend("§ Title\n§§ Section\n§§§ Sub\n§§§§ Not a heading", &mut TextBackend::default(), false, 80);
+    assert_eq!(s, "Title\nSection\nSub\n§§§§ Not a heading");
+
+    let s = MyMarkup::build_markup_core("§ Title\n§§ Section", false, 80);
+    assert_eq!(s, format!("{STYLE_BOLD_ON}{STYLE_UNDERLINE_ON}Title{STYLE_UNDERLINE_OFF}{STYLE_BOLD_ON}{STYLE_BOLD_OFF}\n{STYLE_UNDERLINE_ON}Section{STYLE_UNDERLINE_OFF}"));
+}
+
+#[test]
+fn test_lists_parse() {
+    let doc = Document::parse("- one\n  1. two\n  2. three\n     • four\n- five\n-six").unwrap();
+    let items = doc
+        .blocks
+        .iter()
+        .filter_map(|b| match b {
+            Block::ListItem(item) => Some((item.indent, item.marker)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        items,
+        vec![
+            (0, ListMarker::Bullet('-')),
+            (2, ListMarker::Number(1)),
+            (2, ListMarker::Number(2)),
+            (5, ListMarker::Bullet('•')),
+            (0, ListMarker::Bullet('-')),
+        ]
+    );
+    assert!(matches!(doc.blocks[5], Block::Paragraph(_)));
+}
+
+#[test]
+fn test_lists_hanging_indent() {
+    let text = "• First item, long enough to wrap\n  1. Nested numbered item that wraps too\n• ¬Old style item";
+    let s = MyMarkup::build_markup_backend(text, &mut TextBackend::default(), true, 20);
+    let expected = "--------------------
+• First item, long  |
+  enough to wrap    |
+  1. Nested numbered|
+     item that wraps|
+     too            |
+• Old style item    |";
+    assert_eq!(s, expected);
+}
+
+#[test]
+fn test_code_block() {
+    let text = "Before\n```\nfn main() {\n\tprintln!(\"<⟪not bold⟫>\");\n}\n```\nAfter";
+    let s = MyMarkup::build_markup_backend(text, &mut TextBackend::default(), false, 10);
+    assert_eq!(s, "Before\nfn main() {\n        println!(\"<⟪not bold⟫>\");\n}\nAfter");
+
+    let s = MyMarkup::build_markup_format(text, MarkupFormat::Html);
+    assert!(s.contains("        println!(\"&lt;⟪not bold⟫&gt;\");"));
+
+    let errors = MyMarkup::check_markup("```\nnot closed");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, MarkupErrorKind::UnclosedCodeBlock);
+    assert_eq!(errors[0].to_string(), "line 1, column 1: ``` code block not closed at end of text");
+}
+
+#[test]
+fn test_markdown_blocks() {
+    let text = "§§ Usage\n• ⟪Bold⟫ item\n  2. sub_item\n```\na_b *c*\n```";
+    let s = MyMarkup::build_markup_format(text, MarkupFormat::Markdown);
+    assert_eq!(s, "## Usage  \n- **Bold** item  \n  2. sub\\_item  \n```\na_b *c*\n```");
+}
+
+#[test]
+fn test_tab_stops() {
+    let s = MyMarkup::build_markup_backend("a\tb\tc\nabcdefghij\tk", &mut TextBackend::default(), false, 80);
+    assert_eq!(s, "a       b       c\nabcdefghij      k");
+
+    // A tab never goes beyond width
+    let s = MyMarkup::build_markup_backend("abcdef\tgh", &mut TextBackend::default(), false, 7);
+    assert_eq!(s, "abcdef \ngh");
+}
+
+#[test]
+fn test_is_markup_char() {
+    let plain: String = "⟪a⟫⟨b⟩⌊c⌋⟦d⟧⦃e⦄⟮f⟯ ¬g│§".chars().filter(|&c| !MyMarkup::is_markup_char(c)).collect();
+    assert_eq!(plain, "abcdef g│§");
+}
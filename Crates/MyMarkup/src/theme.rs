@@ -0,0 +1,334 @@
+// theme.rs - MyMarkup themes
+// Maps markup elements (and programs named elements) to terminal styles, with colors adapted to terminal capabilities
+//
+// 2026-10-19   PV      First version
+
+use std::collections::HashMap;
+use std::env;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+use crate::MarkupStyle;
+
+// Environment variable containing the path of a theme file, overriding default theme file location
+pub const THEME_ENV_VAR: &str = "MYMARKUP_THEME";
+
+// Standard xterm values of the 16 basic colors, used to downgrade colors on limited terminals
+const BASIC_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+const COLOR_NAMES: [&str; 8] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+// -----------------------------------
+// Structures
+
+/// Colors a terminal can display, from none (redirected output, NO_COLOR) to 24-bit colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum ColorSupport {
+    #[default]
+    None,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Ansi(u8),    // 0..15, 8 basic colors and their bright variants
+    Palette(u8), // 256 colors palette
+    Rgb(u8, u8, u8),
+}
+
+/// Attributes and colors of a theme element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ThemeStyle {
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+    pub reverse: bool,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+}
+
+/// Styles of markup elements (bold, italic, underline, color1, color2, color3), and of any other named element a
+/// program wants to make configurable, such as rgrep.match
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    styles: HashMap<String, ThemeStyle>,
+}
+
+// -----------------------------------
+
+impl ColorSupport {
+    /// Detects color support of stdout. NO_COLOR (non-empty) disables colors, CLICOLOR_FORCE (non-empty and not 0)
+    /// forces colors even if stdout is not a terminal, CLICOLOR=0 disables colors. Palette depends on COLORTERM and TERM
+    pub fn detect() -> ColorSupport {
+        ColorSupport::detect_with(|name| env::var(name).unwrap_or_default(), std::io::stdout().is_terminal())
+    }
+
+    pub(crate) fn detect_with(var: impl Fn(&str) -> String, is_terminal: bool) -> ColorSupport {
+        if !var("NO_COLOR").is_empty() {
+            return ColorSupport::None;
+        }
+        let forced = !var("CLICOLOR_FORCE").is_empty() && var("CLICOLOR_FORCE") != "0";
+        if !forced && (var("CLICOLOR") == "0" || var("TERM") == "dumb" || !is_terminal) {
+            return ColorSupport::None;
+        }
+
+        let colorterm = var("COLORTERM").to_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" || !var("WT_SESSION").is_empty() {
+            ColorSupport::TrueColor
+        } else if var("TERM").contains("256color") {
+            ColorSupport::Ansi256
+        } else if cfg!(target_os = "windows") {
+            // Windows 10+ console supports 24-bit colors
+            ColorSupport::TrueColor
+        } else {
+            ColorSupport::Ansi16
+        }
+    }
+}
+
+impl Color {
+    /// Color name (black, red, ... white, bright_black ... bright_white), #rrggbb, or palette index 0..255
+    pub fn parse(s: &str) -> Result<Color, String> {
+        let s = s.to_lowercase();
+        if let Some(n) = COLOR_NAMES.iter().position(|&c| c == s) {
+            return Ok(Color::Ansi(n as u8));
+        }
+        if let Some(bright) = s.strip_prefix("bright_")
+            && let Some(n) = COLOR_NAMES.iter().position(|&c| c == bright)
+        {
+            return Ok(Color::Ansi(n as u8 + 8));
+        }
+        if let Some(hex) = s.strip_prefix('#')
+            && hex.len() == 6
+            && let Ok(v) = u32::from_str_radix(hex, 16)
+        {
+            return Ok(Color::Rgb((v >> 16) as u8, (v >> 8) as u8, v as u8));
+        }
+        if let Ok(n) = s.parse::<u8>() {
+            return Ok(Color::Palette(n));
+        }
+        Err(format!("Invalid color {s}"))
+    }
+
+    fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            Color::Ansi(n) => BASIC_COLORS[(n & 15) as usize],
+            Color::Palette(n) if n < 16 => BASIC_COLORS[n as usize],
+            Color::Palette(n) if n < 232 => {
+                let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+                let n = n - 16;
+                (level(n / 36), level(n / 6 % 6), level(n % 6))
+            }
+            Color::Palette(n) => {
+                let g = 8 + (n - 232) * 10;
+                (g, g, g)
+            }
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    // Converts color to what terminal supports
+    fn downgrade(self, support: ColorSupport) -> Color {
+        match (self, support) {
+            (Color::Rgb(r, g, b), ColorSupport::Ansi256) => {
+                let level = |v: u8| if v < 48 { 0 } else if v < 115 { 1 } else { (v - 35) / 40 };
+                Color::Palette(16 + 36 * level(r) + 6 * level(g) + level(b))
+            }
+            (Color::Rgb(..) | Color::Palette(16..), ColorSupport::Ansi16 | ColorSupport::None) => {
+                let (r, g, b) = self.to_rgb();
+                let distance = |&(cr, cg, cb): &(u8, u8, u8)| {
+                    let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+                    d(r, cr) + d(g, cg) + d(b, cb)
+                };
+                let n = (0..16).min_by_key(|&i| distance(&BASIC_COLORS[i])).unwrap();
+                Color::Ansi(n as u8)
+            }
+            (Color::Palette(n), _) if n < 16 => Color::Ansi(n),
+            _ => self,
+        }
+    }
+
+    // SGR parameters, base is 30 for foreground, 40 for background
+    fn sgr(self, base: u8) -> String {
+        match self {
+            Color::Ansi(n) if n < 8 => format!("{}", base + n),
+            Color::Ansi(n) => format!("{}", base + 60 + (n & 7)),
+            Color::Palette(n) => format!("{};5;{n}", base + 8),
+            Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+        }
+    }
+}
+
+impl ThemeStyle {
+    /// Parses a style specification: space-separated attributes (bold, dim, italic, underline, strikethrough, reverse),
+    /// a foreground color, and optionally "on" followed by a background color, such as "bold #ff8000 on blue"
+    pub fn parse(spec: &str) -> Result<ThemeStyle, String> {
+        let mut style = ThemeStyle::default();
+        let mut background = false;
+        for word in spec.split_whitespace() {
+            match word.to_lowercase().as_str() {
+                "bold" => style.bold = true,
+                "dim" => style.dim = true,
+                "italic" => style.italic = true,
+                "underline" => style.underline = true,
+                "strikethrough" => style.strikethrough = true,
+                "reverse" => style.reverse = true,
+                "none" | "default" => {}
+                "on" => background = true,
+                _ => {
+                    let color = Color::parse(word).map_err(|e| format!("{e} in style \"{spec}\""))?;
+                    if background {
+                        style.bg = Some(color);
+                    } else {
+                        style.fg = Some(color);
+                    }
+                }
+            }
+        }
+        Ok(style)
+    }
+
+    /// Escape sequence starting this style, empty if it has no attribute
+    pub fn on_sequence(&self, support: ColorSupport) -> String {
+        let mut params = Vec::new();
+        let attributes = [(self.bold, "1"), (self.dim, "2"), (self.italic, "3"), (self.underline, "4"), (self.reverse, "7"), (self.strikethrough, "9")];
+        params.extend(attributes.iter().filter(|a| a.0).map(|a| a.1.to_string()));
+        if support != ColorSupport::None {
+            params.extend(self.fg.map(|c| c.downgrade(support).sgr(30)));
+            params.extend(self.bg.map(|c| c.downgrade(support).sgr(40)));
+        }
+        sequence(&params)
+    }
+
+    /// Escape sequence ending this style, resetting only what this style has set
+    pub fn off_sequence(&self) -> String {
+        let attributes = [
+            (self.bold || self.dim, "22"),
+            (self.italic, "23"),
+            (self.underline, "24"),
+            (self.reverse, "27"),
+            (self.strikethrough, "29"),
+            (self.fg.is_some(), "39"),
+            (self.bg.is_some(), "49"),
+        ];
+        let params = attributes.iter().filter(|a| a.0).map(|a| a.1.to_string()).collect::<Vec<_>>();
+        sequence(&params)
+    }
+
+    /// Returns text surrounded by style escape sequences, or text unchanged if support is None
+    pub fn paint(&self, text: &str, support: ColorSupport) -> String {
+        if support == ColorSupport::None {
+            text.to_string()
+        } else {
+            format!("{}{text}{}", self.on_sequence(support), self.off_sequence())
+        }
+    }
+}
+
+fn sequence(params: &[String]) -> String {
+    if params.is_empty() { String::new() } else { format!("\x1b[{}m", params.join(";")) }
+}
+
+impl Default for Theme {
+    /// Default theme: ⟦⟧ cyan, ⦃⦄ yellow, ⟮⟯ green
+    fn default() -> Self {
+        let mut theme = Theme { styles: HashMap::new() };
+        for (name, spec) in [
+            ("bold", "bold"),
+            ("italic", "italic"),
+            ("underline", "underline"),
+            ("color1", "cyan"),
+            ("color2", "yellow"),
+            ("color3", "green"),
+        ] {
+            theme.set(name, ThemeStyle::parse(spec).unwrap());
+        }
+        theme
+    }
+}
+
+impl Theme {
+    /// Name of a markup element in theme files
+    pub fn element_name(style: MarkupStyle) -> &'static str {
+        match style {
+            MarkupStyle::Bold => "bold",
+            MarkupStyle::Italic => "italic",
+            MarkupStyle::Underline => "underline",
+            MarkupStyle::Color1 => "color1",
+            MarkupStyle::Color2 => "color2",
+            MarkupStyle::Color3 => "color3",
+        }
+    }
+
+    pub fn style(&self, style: MarkupStyle) -> ThemeStyle {
+        self.named(Theme::element_name(style)).unwrap_or_default()
+    }
+
+    /// Style of any named element, None if the theme doesn't define it
+    pub fn named(&self, name: &str) -> Option<ThemeStyle> {
+        self.styles.get(&name.to_lowercase()).copied()
+    }
+
+    pub fn set(&mut self, name: &str, style: ThemeStyle) {
+        self.styles.insert(name.to_lowercase(), style);
+    }
+
+    /// Theme file is a TOML file of element = "style" lines, such as color1 = "bold #00afff" or rgrep.match = "red on white",
+    /// quote names containing a dot: "rgrep.match" = "red". Elements not defined keep default theme style
+    pub fn from_toml_str(content: &str) -> Result<Theme, String> {
+        let table = content.parse::<toml::Table>().map_err(|e| format!("Invalid theme: {e}"))?;
+        let mut theme = Theme::default();
+        for (name, value) in table.iter() {
+            let spec = value.as_str().ok_or(format!("Invalid theme: style of {name} must be a string"))?;
+            theme.set(name, ThemeStyle::parse(spec)?);
+        }
+        Ok(theme)
+    }
+
+    pub fn load_file(path: &Path) -> Result<Theme, String> {
+        let content = std::fs::read_to_string(path).map_err(|e| format!("Can't read theme file {}: {e}", path.display()))?;
+        Theme::from_toml_str(&content)
+    }
+
+    /// Default theme file location, mymarkup/theme.toml in user config folder
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|d| d.join("mymarkup").join("theme.toml"))
+    }
+
+    /// Loads theme file from MYMARKUP_THEME environment variable, or from default location if it exists.
+    /// Falls back to default theme, with a warning on stderr if a theme file is invalid
+    pub fn load_default() -> Theme {
+        let path = match env::var(THEME_ENV_VAR) {
+            Ok(p) if !p.is_empty() => Some(PathBuf::from(p)),
+            _ => Theme::default_path().filter(|p| p.is_file()),
+        };
+        match path {
+            Some(p) => Theme::load_file(&p).unwrap_or_else(|e| {
+                eprintln!("MyMarkup: {e}, default theme used");
+                Theme::default()
+            }),
+            None => Theme::default(),
+        }
+    }
+}
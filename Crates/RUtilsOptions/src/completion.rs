@@ -0,0 +1,290 @@
+// completion.rs - Shell completion scripts of RUtils tools
+// Generated for bash, zsh, fish and PowerShell from the options description of a ToolSpec
+//
+// 2026-10-19   PV      First version
+
+use mymarkup::MyMarkup;
+
+use crate::ToolSpec;
+
+/// Shells supported by completion scripts generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    PowerShell,
+}
+
+impl Shell {
+    pub const NAMES: [&'static str; 4] = ["bash", "zsh", "fish", "powershell"];
+
+    /// Shell from its name, case-insensitive, pwsh is accepted for PowerShell
+    pub fn from_name(name: &str) -> Option<Shell> {
+        match name.to_lowercase().as_str() {
+            "bash" => Some(Shell::Bash),
+            "zsh" => Some(Shell::Zsh),
+            "fish" => Some(Shell::Fish),
+            "powershell" | "pwsh" => Some(Shell::PowerShell),
+            _ => None,
+        }
+    }
+}
+
+/// An option as seen by completion: one flag, with values of its argument if they're known
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionItem {
+    pub flag: String,
+    pub help: String,
+    pub values: Vec<&'static str>,
+    pub list: bool, // Argument is a comma-separated list of values
+}
+
+impl ToolSpec {
+    /// Flags to complete, derived from options names: names that don't start with a dash (help, ?) or that contain ? are
+    /// not completed, optional suffix is expanded (-nop[rint] gives -nop and -noprint), and a name with a space such as
+    /// "-type f" gives value f of flag -type
+    pub fn completion_items(&self) -> Vec<CompletionItem> {
+        let mut items: Vec<CompletionItem> = Vec::new();
+
+        for option in self.all_options().filter(|o| !o.positional) {
+            let help = short_help(option.help);
+            for name in option.names.iter().filter(|n| n.starts_with('-') && !n.contains('?')) {
+                let (flag, value) = match name.split_once(' ') {
+                    Some((f, v)) => (f, Some(v)),
+                    None => (*name, None),
+                };
+
+                for flag in expand_optional(flag) {
+                    let item = match items.iter_mut().position(|i| i.flag == flag) {
+                        Some(ix) => &mut items[ix],
+                        None => {
+                            items.push(CompletionItem {
+                                flag,
+                                help: help.clone(),
+                                values: Vec::new(),
+                                list: false,
+                            });
+                            items.last_mut().unwrap()
+                        }
+                    };
+                    if let Some(v) = value
+                        && !item.values.contains(&v)
+                    {
+                        item.values.push(v);
+                    }
+                    for v in option.values {
+                        if !item.values.contains(v) {
+                            item.values.push(v);
+                        }
+                    }
+                    item.list |= option.list;
+                }
+            }
+        }
+
+        // Help of a flag with values coming from names such as -type f and -type d is the list of values
+        for item in items.iter_mut() {
+            if item.values.len() > 1
+                && self
+                    .all_options()
+                    .any(|o| o.names.iter().any(|n| n.starts_with(&format!("{} ", item.flag))))
+            {
+                item.help = format!("Value {}", item.values.join("|"));
+            }
+        }
+
+        items
+    }
+
+    /// Completion script for a shell
+    pub fn completion_script(&self, shell: Shell) -> String {
+        let items = self.completion_items();
+        match shell {
+            Shell::Bash => self.bash_script(&items),
+            Shell::Zsh => self.zsh_script(&items),
+            Shell::Fish => self.fish_script(&items),
+            Shell::PowerShell => self.powershell_script(&items),
+        }
+    }
+
+    fn function_name(&self) -> String {
+        let name: String = self.name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+        format!("_{name}")
+    }
+
+    fn bash_script(&self, items: &[CompletionItem]) -> String {
+        let fname = self.function_name();
+        let mut res = format!(
+            "# bash completion for {0} {1}, generated by {0} --completions bash\n",
+            self.name, self.version
+        );
+        res += &format!("{fname}() {{\n");
+        res += "    local cur=\"${COMP_WORDS[COMP_CWORD]}\" prev=\"\"\n";
+        res += "    [[ $COMP_CWORD -gt 0 ]] && prev=\"${COMP_WORDS[COMP_CWORD-1]}\"\n";
+        res += "    case \"$prev\" in\n";
+        for item in items.iter().filter(|i| !i.values.is_empty()) {
+            res += &format!("        {})\n", sh_quote(&item.flag));
+            let values = sh_quote(&item.values.join(" "));
+            if item.list {
+                res += "            compopt -o nospace\n";
+                res += "            local prefix=\"\"\n";
+                res += "            [[ \"$cur\" == *,* ]] && prefix=\"${cur%,*},\"\n";
+                res += &format!("            COMPREPLY=($(compgen -P \"$prefix\" -W {values} -- \"${{cur##*,}}\"))\n");
+            } else {
+                res += &format!("            COMPREPLY=($(compgen -W {values} -- \"$cur\"))\n");
+            }
+            res += "            return ;;\n";
+        }
+        res += "    esac\n";
+        res += "    if [[ \"$cur\" == -* ]]; then\n";
+        let flags: Vec<&str> = items.iter().map(|i| i.flag.as_str()).collect();
+        res += &format!("        COMPREPLY=($(compgen -W {} -- \"$cur\"))\n", sh_quote(&flags.join(" ")));
+        res += "    else\n";
+        res += "        COMPREPLY=($(compgen -f -- \"$cur\"))\n";
+        res += "    fi\n";
+        res += "}\n";
+        res += &format!("complete -o filenames -o bashdefault -F {fname} {}\n", self.name);
+        res
+    }
+
+    fn zsh_script(&self, items: &[CompletionItem]) -> String {
+        let fname = self.function_name();
+        let mut res = format!("#compdef {}\n", self.name);
+        res += &format!(
+            "# zsh completion for {0} {1}, generated by {0} --completions zsh\n",
+            self.name, self.version
+        );
+        res += &format!("{fname}() {{\n");
+        res += "    local cur=${words[CURRENT]} prev=${words[CURRENT-1]}\n";
+        res += "    case $prev in\n";
+        for item in items.iter().filter(|i| !i.values.is_empty()) {
+            res += &format!("        {})\n", sh_quote(&item.flag));
+            let values: Vec<String> = item.values.iter().map(|v| sh_quote(v)).collect();
+            if item.list {
+                res += "            compset -P '*,'\n";
+                res += &format!("            compadd -S '' -- {}\n", values.join(" "));
+            } else {
+                res += &format!("            compadd -- {}\n", values.join(" "));
+            }
+            res += "            return ;;\n";
+        }
+        res += "    esac\n";
+        res += "    if [[ $cur == -* ]]; then\n";
+        res += "        local -a opts=(\n";
+        for item in items {
+            res += &format!("            {}\n", sh_quote(&format!("{}:{}", item.flag.replace(':', "\\:"), item.help)));
+        }
+        res += "        )\n";
+        res += "        _describe -t options option opts\n";
+        res += "    else\n";
+        res += "        _files\n";
+        res += "    fi\n";
+        res += "}\n";
+        res += &format!("compdef {fname} {}\n", self.name);
+        res
+    }
+
+    fn fish_script(&self, items: &[CompletionItem]) -> String {
+        let mut res = format!(
+            "# fish completion for {0} {1}, generated by {0} --completions fish\n",
+            self.name, self.version
+        );
+        for item in items {
+            let mut line = format!("complete -c {}", self.name);
+            line += &match item.flag.strip_prefix("--") {
+                Some(long) => format!(" -l {}", sh_quote(long)),
+                None => format!(" -o {}", sh_quote(&item.flag[1..])),
+            };
+            if item.list {
+                let helper = format!("_{}{}_values", self.function_name(), item.flag.replace('-', "_"));
+                res += &format!(
+                    "function {helper}\n    printf '%s\\n' {}\nend\n",
+                    item.values.iter().map(|v| sh_quote(v)).collect::<Vec<_>>().join(" ")
+                );
+                line += &format!(" -x -a '(__fish_complete_list , {helper})'");
+            } else if !item.values.is_empty() {
+                line += &format!(" -x -a {}", sh_quote(&item.values.join(" ")));
+            }
+            line += &format!(" -d {}", sh_quote(&item.help));
+            res += &line;
+            res.push('\n');
+        }
+        res
+    }
+
+    fn powershell_script(&self, items: &[CompletionItem]) -> String {
+        let mut res = format!(
+            "# PowerShell completion for {0} {1}, generated by {0} --completions powershell\n",
+            self.name, self.version
+        );
+        res += &format!(
+            "Register-ArgumentCompleter -Native -CommandName {0}, {0}.exe -ScriptBlock {{\n",
+            self.name
+        );
+        res += "    param($wordToComplete, $commandAst, $cursorPosition)\n";
+        res += "    $elements = @($commandAst.CommandElements | Where-Object { $_.Extent.EndOffset -lt $cursorPosition } | ForEach-Object { $_.Extent.Text })\n";
+        res += "    $prev = if ($elements.Count -gt 1) { $elements[-1] } else { '' }\n";
+        res += "    $values = @()\n";
+        res += "    $list = $false\n";
+        res += "    switch -CaseSensitive ($prev) {\n";
+        for item in items.iter().filter(|i| !i.values.is_empty()) {
+            let values: Vec<String> = item.values.iter().map(|v| ps_quote(v)).collect();
+            res += &format!(
+                "        {} {{ $values = @({}){} }}\n",
+                ps_quote(&item.flag),
+                values.join(", "),
+                if item.list { "; $list = $true" } else { "" }
+            );
+        }
+        res += "    }\n";
+        res += "    if ($values.Count -gt 0) {\n";
+        res += "        $prefix = ''\n";
+        res += "        if ($list -and $wordToComplete.Contains(',')) { $prefix = $wordToComplete.Substring(0, $wordToComplete.LastIndexOf(',') + 1) }\n";
+        res += "        $values | Where-Object { \"$prefix$_\".StartsWith($wordToComplete, [StringComparison]::OrdinalIgnoreCase) } | ForEach-Object {\n";
+        res += "            [System.Management.Automation.CompletionResult]::new(\"$prefix$_\", $_, 'ParameterValue', $_)\n";
+        res += "        }\n";
+        res += "        return\n";
+        res += "    }\n";
+        res += "    if ($wordToComplete.StartsWith('-')) {\n";
+        res += "        $options = @(\n";
+        for item in items {
+            res += &format!("            ,@({}, {})\n", ps_quote(&item.flag), ps_quote(&item.help));
+        }
+        res += "        )\n";
+        res += "        $options | Where-Object { $_[0].StartsWith($wordToComplete) } | ForEach-Object {\n";
+        res += "            [System.Management.Automation.CompletionResult]::new($_[0], $_[0], 'ParameterName', $_[1])\n";
+        res += "        }\n";
+        res += "    }\n";
+        res += "}\n";
+        res
+    }
+}
+
+// Expands optional suffix between brackets, -nop[rint] gives -nop and -noprint
+fn expand_optional(name: &str) -> Vec<String> {
+    if let Some((base, rest)) = name.split_once('[')
+        && let Some(opt) = rest.strip_suffix(']')
+    {
+        vec![base.to_string(), format!("{base}{opt}")]
+    } else {
+        vec![name.to_string()]
+    }
+}
+
+// Help without markup, limited to first sentence
+fn short_help(help: &str) -> String {
+    let plain: String = help.chars().filter(|&c| !MyMarkup::is_markup_char(c)).collect();
+    let end = [". ", "; "].iter().filter_map(|sep| plain.find(sep)).min().unwrap_or(plain.len());
+    plain[..end].to_string()
+}
+
+// Single-quoted string for bash, zsh and fish
+fn sh_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+// Single-quoted string for PowerShell
+fn ps_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
@@ -0,0 +1,108 @@
+// defaults.rs - Default options of RUtils tools
+// Read from environment variable {APP}_OPTIONS, or from a table of config file rutils.toml
+//
+// 2026-10-19   PV      First version
+// 2026-10-19   PV      string_list shared with profiles
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Environment variable overriding config file location
+pub const CONFIG_ENV_VAR: &str = "RUTILS_CONFIG";
+
+/// Config file, rutils/rutils.toml in user config folder unless RUTILS_CONFIG is defined
+pub fn config_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var(CONFIG_ENV_VAR)
+        && !path.is_empty()
+    {
+        return Some(PathBuf::from(path));
+    }
+    dirs::config_dir().map(|d| d.join("rutils").join("rutils.toml"))
+}
+
+/// Name of environment variable containing default options of an app, such as RFIND_OPTIONS
+pub fn env_var_name(app_name: &str) -> String {
+    format!("{}_OPTIONS", app_name.to_uppercase().replace('-', "_"))
+}
+
+/// Default options of an app, from environment variable if it's defined, or from config file if it exists
+pub fn default_args(app_name: &str) -> Result<Vec<String>, String> {
+    if let Ok(options) = env::var(env_var_name(app_name)) {
+        return Ok(split_args(&options));
+    }
+
+    match config_path().filter(|p| p.is_file()) {
+        Some(path) => {
+            let content = fs::read_to_string(&path).map_err(|e| format!("Can't read config file {}: {e}", path.display()))?;
+            defaults_from_toml(&content, app_name).map_err(|e| format!("Invalid config file {}: {e}", path.display()))
+        }
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Default options of an app from config file content. Each app has its own table, options is either a string or an
+/// array of strings:
+/// [rfind]
+/// options = "-v -glob l2"
+/// [rgrep]
+/// options = ["-i", "-a-"]
+pub fn defaults_from_toml(content: &str, app_name: &str) -> Result<Vec<String>, String> {
+    let table = content.parse::<toml::Table>().map_err(|e| e.to_string())?;
+    let Some(app_table) = table.get(app_name) else { return Ok(Vec::new()) };
+    let app_table = app_table.as_table().ok_or(format!("[{app_name}] must be a table"))?;
+
+    for key in app_table.keys() {
+        if key != "options" {
+            return Err(format!("Unknown key {key} in [{app_name}]"));
+        }
+    }
+
+    match app_table.get("options") {
+        None => Ok(Vec::new()),
+        Some(value) => string_list(value, &format!("[{app_name}] options")),
+    }
+}
+
+/// Arguments from a TOML value, either a string split on spaces or an array of strings. what names the value in errors
+pub(crate) fn string_list(value: &toml::Value, what: &str) -> Result<Vec<String>, String> {
+    match value {
+        toml::Value::String(s) => Ok(split_args(s)),
+        toml::Value::Array(a) => a
+            .iter()
+            .map(|v| v.as_str().map(|s| s.to_string()).ok_or(format!("{what} must only contain strings")))
+            .collect(),
+        _ => Err(format!("{what} must be a string or an array of strings")),
+    }
+}
+
+/// Splits a string of options on spaces, double quotes group words and are removed
+pub fn split_args(s: &str) -> Vec<String> {
+    let mut res = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut has_arg = false;
+
+    for c in s.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                has_arg = true;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if has_arg {
+                    res.push(std::mem::take(&mut current));
+                    has_arg = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_arg = true;
+            }
+        }
+    }
+    if has_arg {
+        res.push(current);
+    }
+    res
+}
@@ -0,0 +1,448 @@
+// RUtilsOptions library
+// Shared command line options support for RUtils tools: standard help and version options, usage text generated in
+// MyMarkup syntax from a declarative description of options, default options from config file or environment, shell
+// completion scripts generated from the same description, and named profiles
+//
+// 2026-10-19   PV      1.0.0 First version
+// 2026-10-19   PV      1.0.1 Markup chars come from MyMarkup, ⟮⟯ style was missing
+// 2026-10-19   PV      1.1.0 Shell completion scripts, option --completions
+// 2026-10-19   PV      1.2.0 Named profiles run with @name and saved with option -save
+// 2026-10-19   PV      1.2.1 Options groups are MyMarkup tables
+
+//#![allow(unused)]
+
+// -----------------------------------
+// Submodules
+
+mod completion;
+pub use completion::{CompletionItem, Shell};
+
+mod defaults;
+pub use defaults::{CONFIG_ENV_VAR, config_path, default_args, defaults_from_toml, env_var_name, split_args};
+
+mod profiles;
+pub use profiles::{
+    PROFILES_FILE, expand_profile, is_valid_profile_name, profile_to_toml, profiles_from_toml, profiles_path, read_profiles, save_profile,
+};
+
+mod tests;
+
+// Standard library imports
+use std::path::PathBuf;
+
+// External crates imports
+use mymarkup::MyMarkup;
+
+// -----------------------------------
+// Globals
+
+const LIB_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub fn version() -> &'static str {
+    LIB_VERSION
+}
+
+// -----------------------------------
+
+/// Description of an option, or of a positional parameter such as source, used to generate usage text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptionDef {
+    pub names: &'static [&'static str], // Alternative names such as ["-f", "-type f"], or parameter name such as ["source"]
+    pub arg: Option<&'static str>,      // Name of option argument
+    pub values: &'static [&'static str], // Possible values of argument when they're known
+    pub help: &'static str,             // In MyMarkup syntax
+    pub positional: bool,
+    pub list: bool, // Argument is a comma-separated list of values, such as -glob l2,md3
+}
+
+impl OptionDef {
+    pub const fn flag(names: &'static [&'static str], help: &'static str) -> Self {
+        OptionDef {
+            names,
+            arg: None,
+            values: &[],
+            help,
+            positional: false,
+            list: false,
+        }
+    }
+
+    pub const fn param(names: &'static [&'static str], help: &'static str) -> Self {
+        OptionDef {
+            names,
+            arg: None,
+            values: &[],
+            help,
+            positional: true,
+            list: false,
+        }
+    }
+
+    pub const fn arg(mut self, arg: &'static str) -> Self {
+        self.arg = Some(arg);
+        self
+    }
+
+    pub const fn values(mut self, values: &'static [&'static str]) -> Self {
+        self.values = values;
+        self
+    }
+
+    /// Possible values of an argument made of a comma-separated list of values
+    pub const fn list_values(mut self, values: &'static [&'static str]) -> Self {
+        self.values = values;
+        self.list = true;
+        self
+    }
+
+    // Left column of usage, such as ⦃-glob⦄ ⟨opt⟩
+    fn markup_names(&self) -> String {
+        let names: Vec<String> = self
+            .names
+            .iter()
+            .map(|n| if self.positional { format!("⟨{n}⟩") } else { format!("⦃{n}⦄") })
+            .collect();
+        let mut res = names.join("|");
+        if let Some(arg) = self.arg {
+            res += &format!(" ⟨{arg}⟩");
+        }
+        res
+    }
+}
+
+/// Special first argument, processed the same way by all tools
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpecialArg {
+    Usage,               // ? -? /? -h help -help /help
+    ExtendedUsage,       // ?? -?? /?? --help
+    Version,             // --version
+    Completions(String), // --completions shell, shell is empty if it's missing
+}
+
+/// Options description of a tool
+#[derive(Debug, Clone)]
+pub struct ToolSpec {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub description: &'static str,
+    pub copyright: Option<&'static str>,
+    pub synopsis: String,
+    pub groups: Vec<(&'static str, Vec<OptionDef>)>,
+    pub extended_groups: Vec<(&'static str, Vec<OptionDef>)>,
+    pub usage_notes: String,
+    pub profiles: bool, // First argument @name is replaced by arguments of profile name
+}
+
+/// Standard help options, shown at the beginning of first group
+pub const HELP_OPTIONS: [OptionDef; 3] = [
+    OptionDef::flag(&["?", "-?", "-h", "help"], "Show this message"),
+    OptionDef::flag(&["??", "-??", "--help"], "Show advanced usage notes"),
+    OptionDef::flag(&["--version"], "Show version"),
+];
+
+/// Option to save current invocation as a profile, to include in a group of options of tools supporting profiles
+pub const SAVE_OPTION: OptionDef = OptionDef::flag(
+    &["-save"],
+    "Save command line as profile ⟨name⟩ instead of running it, run it later with ⟦@⟨name⟩⟧",
+)
+.arg("name");
+
+/// Completion script option, shown in extended usage
+pub const COMPLETION_OPTION: OptionDef = OptionDef::flag(&["--completions"], "Write completion script for shell ⟨shell⟩ on stdout")
+    .arg("shell")
+    .values(&Shell::NAMES);
+
+impl ToolSpec {
+    pub fn new(name: &'static str, version: &'static str, description: &'static str) -> Self {
+        ToolSpec {
+            name,
+            version,
+            description,
+            copyright: None,
+            synopsis: "[⟨option⟩...]".to_string(),
+            groups: Vec::new(),
+            extended_groups: Vec::new(),
+            usage_notes: String::new(),
+            profiles: false,
+        }
+    }
+
+    pub fn copyright(mut self, copyright: &'static str) -> Self {
+        self.copyright = Some(copyright);
+        self
+    }
+
+    /// Text following app name in usage line, in MyMarkup syntax, such as [⟨option⟩...] ⟨source⟩...
+    pub fn synopsis(mut self, synopsis: &str) -> Self {
+        self.synopsis = synopsis.to_string();
+        self
+    }
+
+    /// Group of options shown in usage, such as Options, Filters or Actions
+    pub fn group(mut self, title: &'static str, options: &[OptionDef]) -> Self {
+        self.groups.push((title, options.to_vec()));
+        self
+    }
+
+    /// Group of options shown in extended usage only
+    pub fn extended_group(mut self, title: &'static str, options: &[OptionDef]) -> Self {
+        self.extended_groups.push((title, options.to_vec()));
+        self
+    }
+
+    /// Text shown at the end of usage, in MyMarkup syntax, such as a table of values of an option
+    pub fn usage_notes(mut self, notes: &str) -> Self {
+        self.usage_notes = notes.to_string();
+        self
+    }
+
+    /// Enables profiles: a first argument @name is replaced by the arguments of profile name
+    pub fn profiles(mut self) -> Self {
+        self.profiles = true;
+        self
+    }
+
+    /// All options, including help options and extended ones
+    pub fn all_options(&self) -> impl Iterator<Item = &OptionDef> {
+        HELP_OPTIONS.iter().chain([&COMPLETION_OPTION]).chain(
+            self.groups
+                .iter()
+                .chain(self.extended_groups.iter())
+                .flat_map(|(_, options)| options.iter()),
+        )
+    }
+
+    pub fn header(&self) {
+        println!("{} {}\n{}", self.name, self.version, self.description);
+    }
+
+    pub fn header_copyright(&self) {
+        self.header();
+        if let Some(copyright) = self.copyright {
+            println!("{copyright}");
+        }
+    }
+
+    /// Recognizes special first argument (index 1, index 0 is executable), other positions are not checked so that
+    /// for instance "rgrep -F help source" searches for word help
+    pub fn special_arg(args: &[String]) -> Option<SpecialArg> {
+        let arg = args.get(1)?.to_lowercase();
+        match arg.as_str() {
+            "?" | "-?" | "/?" | "-h" | "help" | "-help" | "/help" => Some(SpecialArg::Usage),
+            "??" | "-??" | "/??" | "--help" => Some(SpecialArg::ExtendedUsage),
+            "--version" => Some(SpecialArg::Version),
+            "--completions" => Some(SpecialArg::Completions(args.get(2).cloned().unwrap_or_default())),
+            _ => None,
+        }
+    }
+
+    /// Arguments of command line, with default options inserted after executable name.
+    /// Returns None if a special argument has been processed (usage or version shown), and app should stop
+    pub fn args(&self, extended_usage: impl FnOnce()) -> Result<Option<Vec<String>>, String> {
+        self.args_from(std::env::args().collect(), extended_usage)
+    }
+
+    /// Same as args, with explicit command line
+    pub fn args_from(&self, args: Vec<String>, extended_usage: impl FnOnce()) -> Result<Option<Vec<String>>, String> {
+        match Self::special_arg(&args) {
+            Some(SpecialArg::Usage) => {
+                self.usage();
+                return Ok(None);
+            }
+            Some(SpecialArg::ExtendedUsage) => {
+                extended_usage();
+                return Ok(None);
+            }
+            Some(SpecialArg::Version) => {
+                println!("{} {}", self.name, self.version);
+                return Ok(None);
+            }
+            Some(SpecialArg::Completions(shell)) => {
+                let shell = Shell::from_name(&shell).ok_or(format!("Option --completions requires a shell name: {}", Shell::NAMES.join(", ")))?;
+                print!("{}", self.completion_script(shell));
+                return Ok(None);
+            }
+            None => {}
+        }
+
+        let args = if self.profiles && args.get(1).is_some_and(|a| a.starts_with('@')) {
+            expand_profile(args, &read_profiles(self.name)?)?
+        } else {
+            args
+        };
+
+        let defaults = default_args(self.name)?;
+        let mut res = Vec::with_capacity(args.len() + defaults.len());
+        let mut it = args.into_iter();
+        res.extend(it.next());
+        res.extend(defaults);
+        res.extend(it);
+        Ok(Some(res))
+    }
+
+    /// Saves command line as profile name, see save_profile_from
+    pub fn save_profile(&self, name: &str) -> Result<PathBuf, String> {
+        self.save_profile_from(std::env::args().collect(), name)
+    }
+
+    /// Saves arguments of command line, without executable and option -save name, as profile name. A profile @name used as
+    /// first argument is expanded. Returns the path of profiles file
+    pub fn save_profile_from(&self, args: Vec<String>, name: &str) -> Result<PathBuf, String> {
+        let args = if args.get(1).is_some_and(|a| a.starts_with('@')) {
+            expand_profile(args, &read_profiles(self.name)?)?
+        } else {
+            args
+        };
+        save_profile(self.name, name, &invocation_args(&args, name))
+    }
+
+    /// Usage text in MyMarkup syntax
+    pub fn usage_markup(&self) -> String {
+        let mut res = format!("⌊Usage⌋: {} ¬{}", self.name, self.synopsis);
+        if self.groups.is_empty() {
+            res += "\n\n";
+            res += &options_markup("Options", &HELP_OPTIONS);
+        }
+        for (i, (title, options)) in self.groups.iter().enumerate() {
+            res += "\n\n";
+            if i == 0 {
+                let options: Vec<OptionDef> = HELP_OPTIONS.iter().chain(options.iter()).copied().collect();
+                res += &options_markup(title, &options);
+            } else {
+                res += &options_markup(title, options);
+            }
+        }
+        if !self.usage_notes.is_empty() {
+            res += "\n\n";
+            res += &self.usage_notes;
+        }
+        res
+    }
+
+    pub fn usage(&self) {
+        self.header();
+        println!();
+        MyMarkup::render_markup(&self.usage_markup());
+    }
+
+    /// Extended usage text in MyMarkup syntax: header, dependencies, extended options groups, text, and default options
+    pub fn extended_markup(&self, dependencies: &[(&str, &str)], text: &str) -> String {
+        let mut res = format!("{} {}\n{}\n", self.name, self.version, self.description);
+        if let Some(copyright) = self.copyright {
+            res += &format!("{copyright}\n");
+        }
+        res.push('\n');
+
+        if !dependencies.is_empty() {
+            res.push_str("⌊Dependencies⌋:\n");
+            for (name, version) in dependencies {
+                res += &format!("- {name}: {version}\n");
+            }
+            res.push('\n');
+        }
+
+        res.push_str("⟪⌊Advanced usage notes⌋⟫\n\n");
+        for (title, options) in self.extended_groups.iter() {
+            res += &options_markup(title, options);
+            res.push_str("\n\n");
+        }
+        res += text;
+        res.push_str("\n\n");
+        res += &self.completion_markup();
+        res.push_str("\n\n");
+        if self.profiles {
+            res += &self.profiles_markup();
+            res.push_str("\n\n");
+        }
+        res += &self.defaults_markup();
+        res
+    }
+
+    pub fn extended_usage(&self, dependencies: &[(&str, &str)], text: &str) {
+        MyMarkup::page_markup(&self.extended_markup(dependencies, text));
+    }
+
+    // How to install completion scripts
+    fn completion_markup(&self) -> String {
+        let name = self.name;
+        options_markup("Shell completion", &[COMPLETION_OPTION])
+            + &format!(
+                "
+Shells: {}, for instance:
+- bash: ¬⟦source <({name} --completions bash)⟧
+- zsh: ¬⟦source <({name} --completions zsh)⟧ (after compinit)
+- fish: ¬⟦{name} --completions fish > ~/.config/fish/completions/{name}.fish⟧
+- PowerShell: ¬⟦{name} --completions powershell | Out-String | Invoke-Expression⟧",
+                Shell::NAMES.join(", ")
+            )
+    }
+
+    // How to use profiles, and profiles currently defined
+    fn profiles_markup(&self) -> String {
+        let name = self.name;
+        let path = match profiles_path() {
+            Some(p) => format!("⟦{}⟧", p.display()),
+            None => "(no config folder)".to_string(),
+        };
+        let current = match read_profiles(name) {
+            Ok(profiles) if profiles.is_empty() => "(none)".to_string(),
+            Ok(profiles) => profiles
+                .iter()
+                .map(|(profile, args)| format!("\n- ⟦@{profile}⟧: ¬⟦{}⟧", args.join(" ")))
+                .collect(),
+            Err(e) => e,
+        };
+        options_markup("Profiles", &[SAVE_OPTION])
+            + &format!(
+                "
+A profile is a saved command line, run with ⟦{name} @⟨name⟩⟧ as first argument. Following arguments are added to the ones of the profile, for instance ⟦{name} @⟨name⟩ -n⟧. Profiles are stored in file {path}, in table ⟦[{name}]⟧, each profile being a string or an array of strings. This file is rewritten by option ⦃-save⦄, comments are not kept.
+Current profiles: {current}"
+            )
+    }
+
+    // Where default options come from, and current value
+    fn defaults_markup(&self) -> String {
+        let path = match config_path() {
+            Some(p) => format!("⟦{}⟧", p.display()),
+            None => "(no config folder)".to_string(),
+        };
+        let current = match default_args(self.name) {
+            Ok(args) if args.is_empty() => "(none)".to_string(),
+            Ok(args) => format!("⟦{}⟧", args.join(" ")),
+            Err(e) => e,
+        };
+        format!(
+            "⌊Default options⌋:
+Default options are inserted before command line options. They are read from environment variable ⟦{}⟧ if it's defined, otherwise from config file {path} (location can be changed with environment variable ⟦{CONFIG_ENV_VAR}⟧), for instance:
+⟦[{}]⟧
+⟦options = \"-v\"⟧
+Current default options: {current}",
+            env_var_name(self.name),
+            self.name
+        )
+    }
+}
+
+// Arguments of command line to save in a profile: without executable, and without option -save name
+fn invocation_args(args: &[String], name: &str) -> Vec<String> {
+    let is_save = |a: &String| (a.starts_with('-') || a.starts_with('/')) && a[1..].eq_ignore_ascii_case("save");
+    let mut res = Vec::new();
+    let mut it = args.iter().skip(1).peekable();
+    while let Some(arg) = it.next() {
+        if is_save(arg) && it.peek().is_some_and(|n| *n == name) {
+            it.next();
+            continue;
+        }
+        res.push(arg.clone());
+    }
+    res
+}
+
+/// Group of options in MyMarkup syntax, a title line followed by a table of options and their descriptions
+pub fn options_markup(title: &str, options: &[OptionDef]) -> String {
+    let mut res = format!("⌊{title}⌋:");
+    for option in options {
+        res += &format!("\n│{}│{}│", option.markup_names(), option.help);
+    }
+    res
+}
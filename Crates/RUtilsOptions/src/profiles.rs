@@ -0,0 +1,110 @@
+// profiles.rs - Named profiles of RUtils tools, saved invocations run with @name
+// Stored in file rutils-profiles.toml, next to config file rutils.toml
+//
+// 2026-10-19   PV      First version
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::defaults::{config_path, string_list};
+
+/// Profiles file name, in the same folder as config file. It's a separate file since it's rewritten by option -save
+pub const PROFILES_FILE: &str = "rutils-profiles.toml";
+
+/// Profiles file, in the same folder as config file
+pub fn profiles_path() -> Option<PathBuf> {
+    config_path().map(|p| p.with_file_name(PROFILES_FILE))
+}
+
+/// A profile name is made of letters, digits, - and _, so it's also a TOML bare key
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Profiles of an app from profiles file content. Each app has its own table, a profile is either a string or an array
+/// of strings:
+/// [rfind]
+/// cleanbuild = ["C:\\Development\\**\\{bin,obj}", "-d", "-rmdir"]
+/// tmp = "C:\\Temp\\*.tmp -mtime +30"
+pub fn profiles_from_toml(content: &str, app_name: &str) -> Result<BTreeMap<String, Vec<String>>, String> {
+    let table = content.parse::<toml::Table>().map_err(|e| e.to_string())?;
+    let Some(app_table) = table.get(app_name) else {
+        return Ok(BTreeMap::new());
+    };
+    let app_table = app_table.as_table().ok_or(format!("[{app_name}] must be a table"))?;
+
+    app_table
+        .iter()
+        .map(|(name, value)| Ok((name.clone(), string_list(value, &format!("[{app_name}] profile {name}"))?)))
+        .collect()
+}
+
+/// Profiles of an app from profiles file, empty if file doesn't exist
+pub fn read_profiles(app_name: &str) -> Result<BTreeMap<String, Vec<String>>, String> {
+    match profiles_path().filter(|p| p.is_file()) {
+        Some(path) => {
+            let content = fs::read_to_string(&path).map_err(|e| format!("Can't read profiles file {}: {e}", path.display()))?;
+            profiles_from_toml(&content, app_name).map_err(|e| format!("Invalid profiles file {}: {e}", path.display()))
+        }
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+/// Replaces a first argument @name (index 1, index 0 is executable) by arguments of profile name, other arguments
+/// follow and can complete or override the profile
+pub fn expand_profile(args: Vec<String>, profiles: &BTreeMap<String, Vec<String>>) -> Result<Vec<String>, String> {
+    let Some(name) = args.get(1).and_then(|a| a.strip_prefix('@')) else {
+        return Ok(args);
+    };
+    let Some(profile) = profiles.get(name) else {
+        return Err(if profiles.is_empty() {
+            format!("Unknown profile {name}, no profile is defined")
+        } else {
+            let names: Vec<&str> = profiles.keys().map(|k| k.as_str()).collect();
+            format!("Unknown profile {name}, defined profiles: {}", names.join(", "))
+        });
+    };
+
+    let mut res = Vec::with_capacity(args.len() + profile.len());
+    let mut it = args.into_iter();
+    res.extend(it.next());
+    it.next();
+    res.extend(profile.iter().cloned());
+    res.extend(it);
+    Ok(res)
+}
+
+/// Profiles file content with profile name of an app set to args, other apps and profiles are kept. Comments of
+/// original content are not kept
+pub fn profile_to_toml(content: &str, app_name: &str, name: &str, args: &[String]) -> Result<String, String> {
+    if !is_valid_profile_name(name) {
+        return Err(format!("Invalid profile name {name}, use only letters, digits, - and _"));
+    }
+    let mut table = content.parse::<toml::Table>().map_err(|e| e.to_string())?;
+    let app_table = table
+        .entry(app_name)
+        .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+        .as_table_mut()
+        .ok_or(format!("[{app_name}] must be a table"))?;
+    let value = args.iter().map(|a| toml::Value::String(a.clone())).collect();
+    app_table.insert(name.to_string(), toml::Value::Array(value));
+    Ok(table.to_string())
+}
+
+/// Saves profile name of an app in profiles file, creating it if needed. Returns the path of profiles file
+pub fn save_profile(app_name: &str, name: &str, args: &[String]) -> Result<PathBuf, String> {
+    let path = profiles_path().ok_or("No config folder to save profiles")?;
+    let content = if path.is_file() {
+        fs::read_to_string(&path).map_err(|e| format!("Can't read profiles file {}: {e}", path.display()))?
+    } else {
+        String::new()
+    };
+    let content = profile_to_toml(&content, app_name, name, args).map_err(|e| format!("Can't save profile in {}: {e}", path.display()))?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Can't create folder {}: {e}", dir.display()))?;
+    }
+    fs::write(&path, content).map_err(|e| format!("Can't write profiles file {}: {e}", path.display()))?;
+    Ok(path)
+}
@@ -0,0 +1,246 @@
+// tests.rs - RUtilsOptions tests
+//
+// 2026-10-19   PV      First version
+// 2026-10-19   PV      Completion scripts
+// 2026-10-19   PV      Profiles
+// 2026-10-19   PV      Options tables rendering
+
+#![cfg(test)]
+
+use mymarkup::TextBackend;
+
+use crate::*;
+
+const OPTIONS: [OptionDef; 3] = [
+    OptionDef::flag(&["-v"], "Verbose output"),
+    OptionDef::flag(&["-glob"], "Globbing options").arg("opt"),
+    OptionDef::param(&["source"], "File or directory to search"),
+];
+
+const COMPLETION_OPTIONS: [OptionDef; 6] = [
+    OptionDef::flag(&["-f", "-type f"], "Search for files"),
+    OptionDef::flag(&["-d", "-type d"], "Search for directories"),
+    OptionDef::flag(&["-nop[rint]"], "Do nothing. Useful with ⦃-v⦄"),
+    OptionDef::flag(&["-glob"], "Globbing ⟨options⟩; see extended help")
+        .arg("opt[,opt]…")
+        .list_values(&["ci", "cs", "l2"]),
+    OptionDef::flag(&["-msg"], "Message with ⟮'quotes'⟯").arg("text"),
+    OptionDef::param(&["source"], "File or directory to search"),
+];
+
+fn args(a: &[&str]) -> Vec<String> {
+    a.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn test_special_arg() {
+    assert_eq!(ToolSpec::special_arg(&args(&["app", "?"])), Some(SpecialArg::Usage));
+    assert_eq!(ToolSpec::special_arg(&args(&["app", "-HELP"])), Some(SpecialArg::Usage));
+    assert_eq!(ToolSpec::special_arg(&args(&["app", "??"])), Some(SpecialArg::ExtendedUsage));
+    assert_eq!(ToolSpec::special_arg(&args(&["app", "--help"])), Some(SpecialArg::ExtendedUsage));
+    assert_eq!(ToolSpec::special_arg(&args(&["app", "--version"])), Some(SpecialArg::Version));
+    assert_eq!(
+        ToolSpec::special_arg(&args(&["app", "--completions", "fish"])),
+        Some(SpecialArg::Completions("fish".into()))
+    );
+    assert_eq!(
+        ToolSpec::special_arg(&args(&["app", "--completions"])),
+        Some(SpecialArg::Completions("".into()))
+    );
+    assert_eq!(ToolSpec::special_arg(&args(&["app", "-F", "help"])), None);
+    assert_eq!(ToolSpec::special_arg(&args(&["app"])), None);
+}
+
+#[test]
+fn test_options_markup() {
+    assert_eq!(
+        options_markup("Options", &OPTIONS),
+        "⌊Options⌋:\n│⦃-v⦄│Verbose output│\n│⦃-glob⦄ ⟨opt⟩│Globbing options│\n│⟨source⟩│File or directory to search│"
+    );
+    assert_eq!(
+        MyMarkup::build_markup_backend(&options_markup("Options", &OPTIONS), &mut TextBackend::default(), false, 30),
+        "Options:\n-v         Verbose output\n-glob opt  Globbing options\nsource     File or directory\n           to search"
+    );
+
+    let spec = ToolSpec::new("app", "1.0.0", "Test app")
+        .synopsis("[⟨option⟩...] ⟨source⟩...")
+        .group("Options", &OPTIONS);
+    let usage = spec.usage_markup();
+    assert!(usage.starts_with("⌊Usage⌋: app ¬[⟨option⟩...] ⟨source⟩...\n\n⌊Options⌋:\n│⦃?⦄|⦃-?⦄|⦃-h⦄|⦃help⦄│Show this message│\n"));
+    assert!(usage.ends_with("\n│⟨source⟩│File or directory to search│"));
+    assert!(MyMarkup::check_markup(&usage).is_empty());
+    assert_eq!(spec.all_options().count(), 7);
+
+    let spec = spec.usage_notes("⌊Types⌋:\nabc   Some type");
+    let usage = spec.usage_markup();
+    assert!(usage.ends_with("│File or directory to search│\n\n⌊Types⌋:\nabc   Some type"));
+}
+
+#[test]
+fn test_split_args() {
+    assert_eq!(split_args("  -v   -glob l2 "), args(&["-v", "-glob", "l2"]));
+    assert_eq!(split_args(r#"-name "My file.txt" """#), args(&["-name", "My file.txt", ""]));
+    assert!(split_args("").is_empty());
+}
+
+#[test]
+fn test_defaults_from_toml() {
+    let content = "[rfind]\noptions = \"-v -glob l2\"\n\n[rgrep]\noptions = [\"-i\", \"-a-\"]\n\n[rwc]\n";
+    assert_eq!(defaults_from_toml(content, "rfind").unwrap(), args(&["-v", "-glob", "l2"]));
+    assert_eq!(defaults_from_toml(content, "rgrep").unwrap(), args(&["-i", "-a-"]));
+    assert!(defaults_from_toml(content, "rwc").unwrap().is_empty());
+    assert!(defaults_from_toml(content, "rtree").unwrap().is_empty());
+
+    assert!(defaults_from_toml("[rfind]\noptions = 1", "rfind").is_err());
+    assert!(defaults_from_toml("[rfind]\nopts = \"-v\"", "rfind").is_err());
+    assert!(defaults_from_toml("rfind = 1", "rfind").is_err());
+    assert!(defaults_from_toml("[rfind", "rfind").is_err());
+}
+
+#[test]
+fn test_env_var_defaults() {
+    assert_eq!(env_var_name("rfind"), "RFIND_OPTIONS");
+
+    // Environment variable of a dedicated app name, so other tests are not affected
+    let spec = ToolSpec::new("rutilsoptions-test", "1.0.0", "Test app");
+    unsafe { std::env::set_var("RUTILSOPTIONS_TEST_OPTIONS", "-a- \"x y\"") };
+    let res = spec.args_from(args(&["app", "-v", "src"]), || {}).unwrap();
+    assert_eq!(res, Some(args(&["app", "-a-", "x y", "-v", "src"])));
+
+    // Special arguments are processed before defaults are inserted
+    assert_eq!(spec.args_from(args(&["app", "--version"]), || {}).unwrap(), None);
+    let mut extended = false;
+    assert_eq!(spec.args_from(args(&["app", "??"]), || extended = true).unwrap(), None);
+    assert!(extended);
+    unsafe { std::env::remove_var("RUTILSOPTIONS_TEST_OPTIONS") };
+}
+
+#[test]
+fn test_completion_items() {
+    let spec = ToolSpec::new("app", "1.0.0", "Test app").group("Options", &COMPLETION_OPTIONS);
+    let items = spec.completion_items();
+    let flags: Vec<&str> = items.iter().map(|i| i.flag.as_str()).collect();
+    assert_eq!(
+        flags,
+        [
+            "-h",
+            "--help",
+            "--version",
+            "--completions",
+            "-f",
+            "-type",
+            "-d",
+            "-nop",
+            "-noprint",
+            "-glob",
+            "-msg"
+        ]
+    );
+
+    let find = |flag: &str| items.iter().find(|i| i.flag == flag).unwrap();
+    assert_eq!(find("-type").values, ["f", "d"]);
+    assert_eq!(find("-type").help, "Value f|d");
+    assert_eq!(find("-glob").values, ["ci", "cs", "l2"]);
+    assert!(find("-glob").list);
+    assert_eq!(find("-glob").help, "Globbing options");
+    assert_eq!(find("-nop").help, "Do nothing");
+    assert_eq!(find("-msg").help, "Message with 'quotes'");
+    assert_eq!(find("--completions").values, Shell::NAMES);
+    assert!(!find("--completions").list);
+
+    assert_eq!(Shell::from_name("PWSH"), Some(Shell::PowerShell));
+    assert_eq!(Shell::from_name("cmd"), None);
+    assert!(spec.args_from(args(&["app", "--completions", "cmd"]), || {}).is_err());
+}
+
+#[test]
+fn test_completion_scripts() {
+    let spec = ToolSpec::new("my-app", "1.0.0", "Test app").group("Options", &COMPLETION_OPTIONS);
+
+    let bash = spec.completion_script(Shell::Bash);
+    assert!(bash.contains(r#"COMPREPLY=($(compgen -P "$prefix" -W 'ci cs l2' -- "${cur##*,}"))"#));
+    assert!(bash.contains("        '-type')\n            COMPREPLY=($(compgen -W 'f d' -- \"$cur\"))\n"));
+    assert!(bash.ends_with("complete -o filenames -o bashdefault -F _my_app my-app\n"));
+
+    let zsh = spec.completion_script(Shell::Zsh);
+    assert!(zsh.starts_with("#compdef my-app\n"));
+    assert!(zsh.contains("compset -P '*,'\n            compadd -S '' -- 'ci' 'cs' 'l2'\n"));
+    assert!(zsh.contains(r#"'-msg:Message with '\''quotes'\'''"#));
+
+    let fish = spec.completion_script(Shell::Fish);
+    assert!(fish.contains("complete -c my-app -o 'glob' -x -a '(__fish_complete_list , __my_app_glob_values)' -d 'Globbing options'\n"));
+    assert!(fish.contains("complete -c my-app -l 'completions' -x -a 'bash zsh fish powershell'"));
+    assert!(fish.contains(r#"complete -c my-app -o 'msg' -d 'Message with '\''quotes'\'''"#));
+
+    let ps = spec.completion_script(Shell::PowerShell);
+    assert!(ps.contains("-CommandName my-app, my-app.exe"));
+    assert!(ps.contains("'-glob' { $values = @('ci', 'cs', 'l2'); $list = $true }"));
+    assert!(ps.contains(",@('-msg', 'Message with ''quotes''')"));
+}
+
+#[test]
+fn test_profiles() {
+    let content = "[rfind]\ncleanbuild = [\"C:\\\\Dev\\\\**\\\\{bin,obj}\", \"-d\", \"-rmdir\"]\ntmp = \"*.tmp -mtime +30\"\n\n[rgrep]\nx = 1\n";
+    let profiles = profiles_from_toml(content, "rfind").unwrap();
+    assert_eq!(profiles["cleanbuild"], args(&[r"C:\Dev\**\{bin,obj}", "-d", "-rmdir"]));
+    assert_eq!(profiles["tmp"], args(&["*.tmp", "-mtime", "+30"]));
+    assert!(profiles_from_toml(content, "rtree").unwrap().is_empty());
+    assert!(profiles_from_toml(content, "rgrep").is_err());
+
+    // Extra arguments follow profile arguments, @ is only special as first argument
+    assert_eq!(
+        expand_profile(args(&["app", "@tmp", "-n"]), &profiles).unwrap(),
+        args(&["app", "*.tmp", "-mtime", "+30", "-n"])
+    );
+    assert_eq!(
+        expand_profile(args(&["app", "-v", "@tmp"]), &profiles).unwrap(),
+        args(&["app", "-v", "@tmp"])
+    );
+    assert_eq!(
+        expand_profile(args(&["app", "@x"]), &profiles).unwrap_err(),
+        "Unknown profile x, defined profiles: cleanbuild, tmp"
+    );
+
+    // Saved profile is added to existing ones, values are read back unchanged
+    let content = profile_to_toml(content, "rfind", "docs", &args(&[r"C:\My Docs\*.pdf", "-printf", "%f\\n"])).unwrap();
+    let profiles = profiles_from_toml(&content, "rfind").unwrap();
+    assert_eq!(profiles.len(), 3);
+    assert_eq!(profiles["docs"], args(&[r"C:\My Docs\*.pdf", "-printf", "%f\\n"]));
+    assert_eq!(
+        profiles_from_toml(&profile_to_toml("", "rfind", "a", &[]).unwrap(), "rfind").unwrap()["a"],
+        args(&[])
+    );
+    assert!(profile_to_toml("", "rfind", "a b", &[]).is_err());
+    assert!(profile_to_toml("", "rfind", "", &[]).is_err());
+
+    assert_eq!(invocation_args(&args(&["app", "src", "-SAVE", "p", "-n"]), "p"), args(&["src", "-n"]));
+}
+
+#[test]
+fn test_save_profile() {
+    // Config file in a dedicated temp folder, created by save; other tests don't depend on config file
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path().join("config");
+    unsafe { std::env::set_var(CONFIG_ENV_VAR, dir.join("rutils.toml")) };
+    assert_eq!(profiles_path(), Some(dir.join(PROFILES_FILE)));
+
+    let spec = ToolSpec::new("app", "1.0.0", "Test app").profiles();
+    let path = spec.save_profile_from(args(&["app", "src", "-f", "-save", "base"]), "base").unwrap();
+    assert!(path.is_file());
+    spec.save_profile_from(args(&["app", "@base", "-v", "-save", "verbose"]), "verbose")
+        .unwrap();
+    assert_eq!(read_profiles("app").unwrap()["verbose"], args(&["src", "-f", "-v"]));
+
+    let res = spec.args_from(args(&["app", "@verbose", "-n"]), || {}).unwrap();
+    assert_eq!(res, Some(args(&["app", "src", "-f", "-v", "-n"])));
+    assert!(spec.args_from(args(&["app", "@unknown"]), || {}).is_err());
+    let extended = spec.extended_markup(&[], "");
+    assert!(extended.contains("- ⟦@verbose⟧: ¬⟦src -f -v⟧"));
+    assert!(MyMarkup::check_markup(&extended).is_empty());
+
+    // Without profiles support, @name is a normal argument
+    let spec = ToolSpec::new("app", "1.0.0", "Test app");
+    assert_eq!(spec.args_from(args(&["app", "@base"]), || {}).unwrap(), Some(args(&["app", "@base"])));
+
+    unsafe { std::env::remove_var(CONFIG_ENV_VAR) };
+}
@@ -7,6 +7,7 @@
 // 2025-05-06   PV      1.2.0 check_eightbit fixed (was converting the whole buffer_1000 regardless of actual length)
 // 2025-06-24   PV      1.3.0 check_utf8 checks correctly for a possibly truncated UTF-8 sequence at the end of a 1000 bytes buffer
 // 2025-10-22   PV      1.3.1 Clippy review; fixed check_75percent_text bool variable bug
+// 2026-10-19   PV      1.4.0 Detection result emitted as a debug diagnostic through log facade
 
 #![allow(unused_variables, dead_code, unused_imports)]
 
@@ -18,6 +19,7 @@ use std::path::Path;
 
 // external crates imports
 use encoding_rs::{Encoding, UTF_8, UTF_16BE, UTF_16LE, WINDOWS_1252};
+use log::debug;
 use tempfile as _;
 
 // -----------------------------------
@@ -28,7 +30,7 @@ mod tests;
 // -----------------------------------
 // Globals
 
-const LIB_VERSION: &str = "1.3.1";
+const LIB_VERSION: &str = "1.4.0";
 
 // -----------------------------------
 // Structures
@@ -69,6 +71,15 @@ impl TextAutoDecode {
     /// If encoding is recognized, returns TextAutoDecode with text as a Some(String) and encoding as TextFileEncoding.
     /// If encoding is not recognized, returns TextAutoDecode with text:None, and encoding NotText.
     pub fn read_text_file(path: &Path) -> Result<TextAutoDecode, io::Error> {
+        let res = Self::read_text_file_core(path);
+        match &res {
+            Ok(tad) => debug!("{}: {:?}", path.display(), tad.encoding),
+            Err(e) => debug!("{}: {e}", path.display()),
+        }
+        res
+    }
+
+    fn read_text_file_core(path: &Path) -> Result<TextAutoDecode, io::Error> {
         let mut file = File::open(path)?;
         let mut buffer_1000 = [0; 1000];
         // Read up to 1000 bytes
@@ -9,6 +9,7 @@ use std::path::Path;
 
 use tempfile as _;
 use encoding_rs as _;
+use log as _;
 
 pub use textautodecode::*;
 
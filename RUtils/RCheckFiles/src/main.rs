@@ -27,6 +27,11 @@
 // 2025-10-25   PV      3.2.2 πΔΩ allowed with other scripts
 // 2025-11-04   PV      3.3.0 Test unbalanced spaces around dashes
 // 2025-12-19   PV      3.3.1 Print message and terminate when no options have been provided instead of crashing
+// 2026-10-19   PV      3.5.0 Renames logged with structured fields (operation, type, path, new_name, error) for JSON-lines log
+// 2026-10-19   PV      Logging 2.0: LogWriter passed by shared reference
+// 2026-10-19   PV      3.6.0 Plural helper and final duration/errors summary provided by Logging
+// 2026-10-19   PV      3.7.0 Options help, version, defaults and shell completion with RUtilsOptions crate
+// 2026-10-19   PV      3.7.1 Field new_name of renames replaced by new_path, always a full path
 
 // Note: Can't use MyGlob crate since directories names can be updated during recursive enumeration, this is not a
 // supported use case of MyGlob, so hierarchical exploration is handled directly
@@ -39,11 +44,10 @@ use std::os::windows::prelude::*;
 use std::path::Path;
 use std::process;
 use std::sync::LazyLock;
-use std::time::Instant;
 
 // External crates imports
 use getopt::Opt;
-use logging::{LogWriter, log, logln};
+use logging::{Level, LogWriter, log, logln, logln_fields, s};
 use regex::Regex;
 use serde::Deserialize;
 use unicode_ident::{is_xid_continue, is_xid_start};
@@ -272,9 +276,7 @@ fn main() {
     let transformation_data = get_transformation_data();
 
     // Prepare log writer
-    let mut writer = logging::new(APP_NAME, APP_VERSION, !options.yaml_output);
-
-    let start = Instant::now();
+    let writer = logging::new(APP_NAME, APP_VERSION, !options.yaml_output);
 
     if options.yaml_file.is_empty() {
         let mut files_stats = Statistics { ..Default::default() };
@@ -282,19 +284,17 @@ fn main() {
 
         for source in &options.sources {
             if !options.yaml_output {
-                logln(&mut writer, &format!("Analyzing {}", source));
+                logln(&writer, &format!("Analyzing {}", source));
             }
             let p = Path::new(&source);
             if p.is_file() {
-                process_file(p, &mut files_stats, &options, &mut writer, &transformation_data);
+                process_file(p, &mut files_stats, &options, &writer, &transformation_data);
             } else {
-                process_directory(p, &mut dirs_stats, &mut files_stats, &options, &mut writer, &transformation_data);
+                process_directory(p, &mut dirs_stats, &mut files_stats, &options, &writer, &transformation_data);
             }
         }
 
-        let duration = start.elapsed();
-
-        fn final_status(writer: &mut LogWriter, stats: &Statistics, typename: &str) {
+        fn final_status(writer: &LogWriter, stats: &Statistics, typename: &str) {
             log(writer, &format!("{} {}{} checked", stats.total, typename, s(stats.total)));
             if stats.nnn > 0 {
                 log(writer, &format!(", {} non-normalized", stats.nnn));
@@ -345,45 +345,39 @@ fn main() {
         }
 
         if !options.yaml_output {
-            logln(&mut writer, "");
-            final_status(&mut writer, &dirs_stats, "dir");
-            final_status(&mut writer, &files_stats, "file");
+            logln(&writer, "");
+            final_status(&writer, &dirs_stats, "dir");
+            final_status(&writer, &files_stats, "file");
 
             if options.count_extensions {
                 // Print extensions counter by decreasing order of count
                 let mut extensions: Vec<_> = files_stats.ext_counter.iter().collect();
                 extensions.sort_by(|a, b| b.1.cmp(a.1));
 
-                logln(&mut writer, "Extensions:");
+                logln(&writer, "Extensions:");
                 for (ext, cnt) in extensions {
-                    logln(&mut writer, format!("  {ext}: {cnt}").as_str());
+                    logln(&writer, format!("  {ext}: {cnt}").as_str());
                 }
-                logln(&mut writer, "");
+                logln(&writer, "");
             }
 
-            logln(&mut writer, &format!("Total duration: {:.3}s", duration.as_secs_f64()));
+            writer.log_summary();
         }
     } else {
-        let res = process_yaml_file(&mut writer, &options);
+        let res = process_yaml_file(&writer, &options);
 
         match res {
             Ok(_) => {}
             Err(e) => {
-                logln(&mut writer, &format!("Error processing yaml file: {}", e));
+                logln(&writer, &format!("Error processing yaml file: {}", e));
             }
         }
 
-        let duration = start.elapsed();
-        logln(&mut writer, &format!("Total duration: {:.3}s", duration.as_secs_f64()));
+        writer.log_summary();
     }
 }
 
-// Helper for plurals
-fn s(n: u32) -> &'static str {
-    if n > 1 { "s" } else { "" }
-}
-
-fn process_yaml_file(writer: &mut LogWriter, options: &Options) -> Result<(), Box<dyn Error>> {
+fn process_yaml_file(writer: &LogWriter, options: &Options) -> Result<(), Box<dyn Error>> {
     let yaml_content = fs::read_to_string(&options.yaml_file)?;
 
     // Deserialize the YAML string into a vector of `RenameItem` structs.
@@ -407,13 +401,20 @@ fn process_yaml_file(writer: &mut LogWriter, options: &Options) -> Result<(), Bo
                 }
                 match fs::rename(item.old.as_str(), item.new.as_str()) {
                     Err(e) => {
-                        logln(
+                        logln_fields(
                             writer,
+                            Level::Error,
                             format!("*** Renaming dir «{}» into «{}» caused error: {}", item.old, item.new, e).as_str(),
+                            &rename_fields("dir", &item.old, &item.new, Some(&e.to_string())),
                         );
                     }
                     Ok(_) => {
-                        logln(writer, format!("Success renaming dir «{}» into «{}»", item.old, item.new).as_str());
+                        logln_fields(
+                            writer,
+                            Level::Info,
+                            format!("Success renaming dir «{}» into «{}»", item.old, item.new).as_str(),
+                            &rename_fields("dir", &item.old, &item.new, None),
+                        );
                     }
                 }
             }
@@ -427,13 +428,20 @@ fn process_yaml_file(writer: &mut LogWriter, options: &Options) -> Result<(), Bo
                 }
                 match fs::rename(item.old.as_str(), item.new.as_str()) {
                     Err(e) => {
-                        logln(
+                        logln_fields(
                             writer,
+                            Level::Error,
                             format!("*** Renaming file «{}» into «{}» caused error: {}", item.old, item.new, e).as_str(),
+                            &rename_fields("file", &item.old, &item.new, Some(&e.to_string())),
                         );
                     }
                     Ok(_) => {
-                        logln(writer, format!("Success renaming file «{}» into «{}»", item.old, item.new).as_str());
+                        logln_fields(
+                            writer,
+                            Level::Info,
+                            format!("Success renaming file «{}» into «{}»", item.old, item.new).as_str(),
+                            &rename_fields("file", &item.old, &item.new, None),
+                        );
                     }
                 }
             }
@@ -448,7 +456,7 @@ fn process_directory(
     dirs_stats: &mut Statistics,
     files_stats: &mut Statistics,
     options: &Options,
-    writer: &mut LogWriter,
+    writer: &LogWriter,
     transformation_data: &TransformationData,
 ) {
     let mut pb = pa.to_path_buf();
@@ -473,14 +481,27 @@ fn process_directory(
     if let Some(new_name) = check_name(pa, "dir", dirs_stats, options, writer, transformation_data, false)
         && options.fixit
     {
-        logln(writer, &format!("  --> rename directory \"{new_name}\""));
+        let path = pb.to_string_lossy().to_string();
         let newpath = pb.parent().unwrap().join(Path::new(&new_name));
+        let new_path = newpath.to_string_lossy().to_string();
+        logln_fields(
+            writer,
+            Level::Info,
+            &format!("  --> rename directory \"{new_name}\""),
+            &rename_fields("dir", &path, &new_path, None),
+        );
         match fs::rename(&pb, &newpath) {
             Ok(_) => {
                 dirs_stats.fix += 1;
                 pb = newpath;
             }
-            Err(e) => logln(writer, &format!("*** Error {e}")), // Rename failed, but we continue anyway, don't really know if it's Ok or not...
+            // Rename failed, but we continue anyway, don't really know if it's Ok or not...
+            Err(e) => logln_fields(
+                writer,
+                Level::Error,
+                &format!("*** Error {e}"),
+                &rename_fields("dir", &path, &new_path, Some(&e.to_string())),
+            ),
         }
     }
 
@@ -506,7 +527,7 @@ fn process_directory(
     }
 }
 
-fn process_file(p: &Path, files_stats: &mut Statistics, options: &Options, writer: &mut LogWriter, transformation_data: &TransformationData) {
+fn process_file(p: &Path, files_stats: &mut Statistics, options: &Options, writer: &LogWriter, transformation_data: &TransformationData) {
     files_stats.total += 1;
 
     // Count extension
@@ -522,22 +543,46 @@ fn process_file(p: &Path, files_stats: &mut Statistics, options: &Options, write
     if let Some(new_name) = check_name(p, "file", files_stats, options, writer, transformation_data, false)
         && options.fixit
     {
-        logln(writer, &format!("  --> rename file \"{new_name}\""));
+        let path = p.to_string_lossy().to_string();
         let newpath = p.parent().unwrap().join(Path::new(&new_name));
+        let new_path = newpath.to_string_lossy().to_string();
+        logln_fields(
+            writer,
+            Level::Info,
+            &format!("  --> rename file \"{new_name}\""),
+            &rename_fields("file", &path, &new_path, None),
+        );
         match fs::rename(p, &newpath) {
             Ok(_) => files_stats.fix += 1,
-            Err(e) => logln(writer, &format!("*** Error {e}")), // Rename failed
+            // Rename failed
+            Err(e) => logln_fields(
+                writer,
+                Level::Error,
+                &format!("*** Error {e}"),
+                &rename_fields("file", &path, &new_path, Some(&e.to_string())),
+            ),
         }
     }
 }
 
+// Structured fields of a rename, written in JSON-lines log if enabled in logging config:
+// operation is always "rename", type is "dir" or "file", path and new_path are full paths before and after renaming,
+// error is only present when renaming failed
+fn rename_fields<'a>(typ: &'a str, path: &'a str, new_path: &'a str, error: Option<&'a str>) -> Vec<(&'a str, &'a str)> {
+    let mut fields = vec![("operation", "rename"), ("type", typ), ("path", path), ("new_path", new_path)];
+    if let Some(error) = error {
+        fields.push(("error", error));
+    }
+    fields
+}
+
 // Either a directly name, or a file name with extension
 fn check_name(
     p: &Path,
     pt: &str,
     stats: &mut Statistics,
     options: &Options,
-    writer: &mut LogWriter,
+    writer: &LogWriter,
     transformation_data: &TransformationData,
     fixit: bool,
 ) -> Option<String> {
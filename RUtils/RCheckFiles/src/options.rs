@@ -8,6 +8,8 @@
 // 2025-10-24   PV      Problem das for dashes confusables, and mex for mixed scripts
 // 2025-11-03   PV      Problem usd for unbalanced spaces around dashes
 // 2025-12-19   PV      Print message and terminate when no options have been provided instead of crashing
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Options described with RUtilsOptions: help, version, defaults and shell completion
 
 // Application imports
 use crate::*;
@@ -18,6 +20,7 @@ use std::fs;
 
 // External crates imports
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, ToolSpec};
 
 // Dedicated struct to store command line arguments
 #[derive(Debug, Default)]
@@ -44,25 +47,19 @@ pub fn dir_exists(path: &str) -> bool {
     fs::metadata(path).map(|metadata| metadata.is_dir()).unwrap_or(false)
 }
 
-impl Options {
-    fn header() {
-        println!("{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}");
-    }
+/// Problem types of option -p
+pub const PROBLEM_TYPES: [&str; 13] = ["nnn", "bra", "spc", "apo", "das", "car", "sp2", "lig", "sba", "ewd", "dex", "mix", "usd"];
+
+const OPTIONS: [OptionDef; 6] = [
+    OptionDef::flag(&["-p"], "Only report specific types, see list below").arg("type[,type]…").list_values(&PROBLEM_TYPES),
+    OptionDef::flag(&["-f"], "Automatic problems fixing"),
+    OptionDef::flag(&["-y"], "Yaml output"),
+    OptionDef::flag(&["-F"], "Rename files using old/new fields of provided yaml file").arg("yamlfile"),
+    OptionDef::flag(&["-e"], "Count extensions"),
+    OptionDef::param(&["source"], "File or directory to analyze (note: glob pattern is not supported)"),
+];
 
-    fn usage() {
-        Options::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} ¬[⦃?⦄|⦃-?⦄|⦃-h⦄|⦃??⦄|⦃-??⦄] [⦃-p type[,type]...⦄] [⦃-f⦄] [⦃-y⦄] [⦃-F⦄ ⟨yamlfile⟩] [⦃-e⦄] ⟨source⟩...
-⦃?⦄|⦃-?⦄|⦃-h⦄     ¬Show this message
-⦃??⦄|⦃-??⦄      ¬Show advanced usage notes
-⦃-p type[,type]...⦄ ¬Only report specific types. type: nnn|bra|apo|spc|car|sp2|lig|sba|ewd|dex
-⦃-f⦄          ¬Automatic problems fixing
-⦃-y⦄          ¬Yaml output
-⦃-F⦄ ⟨yamlfile⟩ ¬Rename files using old/new fields of provided yaml file
-⦃-e⦄          ¬Count extensions
-⟨source⟩      ¬File or directory to analyze (note: glob pattern is not supported)
-
-⌊Types⌋⟫:
+const TYPES: &str = "⌊Types⌋:
 nnn   Non-normalized names     ¬Only NFC names are valid
 bra   Bracket issue            ¬Check correct balance end embedding for Balanced and embedding () [] {} «» ‹›
 spc   Incorrect space          ¬Spaces confusables replaced by ASCII space
@@ -77,52 +74,44 @@ dex   Double extension         ¬Reports files ending with .ext.ext
 mix   Mixed scripts            ¬Separate words shouldn't contain mixed scripts
 usd   Unbalanced spaces/dashes ¬A dash should either be surrounded by 0 or 2 spaces";
 
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl Options {
+    pub fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⦃-p⦄ ⟨type[,type]…⟩] [⦃-f⦄] [⦃-y⦄] [⦃-F⦄ ⟨yamlfile⟩] [⦃-e⦄] ⟨source⟩...")
+            .group("Options", &OPTIONS)
+            .usage_notes(TYPES)
     }
 
     fn extended_usage() {
-        Options::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- Logging: {}", logging::version());
-        println!("- getopt: {}", env!("DEP_GETOPT_VERSION"));
-        println!("- regex: {}", env!("DEP_REGEX_VERSION"));
-        println!("- serde: {}", env!("DEP_SERDE_VERSION"));
-        println!("- serde_yaml: {}", env!("DEP_SERDE_YAML_VERSION"));
-        println!("- unicode-normalization: {}", env!("DEP_UNICODE_NORMALIZATION_VERSION"));
-        println!();
-
-        let text = "⟪⌊Advanced usage notes⌋⟫
-
-Option ⦃-y⦄ generates yaml output, including extra non-yaml header and footer. If output is redirected to a file to be edited and later processed with -F option, don't forget to remove non-yaml parts.\n";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+        let text = "Option ⦃-y⦄ generates yaml output, including extra non-yaml header and footer. If output is redirected to a file to be edited and later processed with -F option, don't forget to remove non-yaml parts.";
+
+        let dependencies = [
+            ("MyMarkup", MyMarkup::version()),
+            ("Logging", logging::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("getopt", env!("DEP_GETOPT_VERSION")),
+            ("regex", env!("DEP_REGEX_VERSION")),
+            ("serde", env!("DEP_SERDE_VERSION")),
+            ("serde_yaml", env!("DEP_SERDE_YAML_VERSION")),
+            ("unicode-normalization", env!("DEP_UNICODE_NORMALIZATION_VERSION")),
+        ];
+        Self::spec().extended_usage(&dependencies, text);
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<Options, Box<dyn Error>> {
         if std::env::args().len()==1 {
-            Options::header();
+            Self::spec().header();
             eprintln!("\nNo folder specified.\nUse {APP_NAME} ? to show options or {APP_NAME} ?? for advanced usage notes.");
             return Err("".into());
         }
 
-        let mut args: Vec<String> = std::env::args().collect();
-        if args.len() > 1 { 
-		    if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
-
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
-		}
+        // Help and version are processed by RUtilsOptions, default options are inserted
+        let Some(mut args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
         let mut options = Options { ..Default::default() };
         let mut opts = getopt::Parser::new(&args, "h?p:fyF:e");
@@ -132,7 +121,7 @@ Option ⦃-y⦄ generates yaml output, including extra non-yaml header and foote
                 None => break,
                 Some(opt) => match opt {
                     Opt('h', None) | Opt('?', None) => {
-                        Self::usage();
+                        Self::spec().usage();
                         return Err("".into());
                     }
 
@@ -142,23 +131,8 @@ Option ⦃-y⦄ generates yaml output, including extra non-yaml header and foote
                         }
                         for problem in problems.unwrap().split(',') {
                             let pb = problem.trim().to_lowercase();
-                            if pb != "nnn"
-                                && pb != "bra"
-                                && pb != "spc"
-                                && pb != "apo"
-                                && pb != "das"
-                                && pb != "car"
-                                && pb != "sp2"
-                                && pb != "lig"
-                                && pb != "sba"
-                                && pb != "ewd"
-                                && pb != "dex"
-                                && pb != "mix"
-                                && pb != "usd"
-                            {
-                                return Err(
-                                    format!("Invalid problem type {}, must be one of nnn|bra|spc|apo|das|car|sp2|lig|sba|ewd|dex|mix|usd", problem).into(),
-                                );
+                            if !PROBLEM_TYPES.contains(&pb.as_str()) {
+                                return Err(format!("Invalid problem type {}, must be one of {}", problem, PROBLEM_TYPES.join("|")).into());
                             }
                             if !options.report_types.contains(&pb) {
                                 options.report_types.insert(pb);
@@ -95,7 +95,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -114,7 +114,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -133,7 +133,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -152,7 +152,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -171,7 +171,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -189,7 +189,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -208,7 +208,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -228,7 +228,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -247,7 +247,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -266,7 +266,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -285,7 +285,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -304,7 +304,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -322,7 +322,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -340,7 +340,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -368,7 +368,7 @@ pub mod check_basename_tests {
             "file",
             &mut files_stats,
             &SHARED_DATA.options,
-            &mut logwriter_none(),
+            &logwriter_none(),
             &SHARED_DATA.transformation_data,
             true,
         );
@@ -4,6 +4,7 @@
 // 2025-07-05   PV      First version, with the help of Gemini
 // 2025-10-22   PV      Clippy review
 // 2026-02-16   PV      Added icon, file description and copyright
+// 2026-10-19   PV      regex version
 
 use std::env;
 use std::fs;
@@ -28,6 +29,7 @@ fn main() {
     generate_variable(packages, "trash");
     generate_variable(packages, "chrono");
     generate_variable(packages, "num-format");
+    generate_variable(packages, "regex");
 
     println!("cargo:CARGO_BIN_EXE_rfind=target/debug/rfind");
 
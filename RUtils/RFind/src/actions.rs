@@ -15,13 +15,23 @@
 // 2025-10-29   PV      ActionXargs renamed ActionExecg and entirely rewritten to limit command size at 7800 UTF-16 chars
 // 2025-10-30   PV      Flush output after writing a line in ActionPrint
 // 2025-11-15   PV      Pass options: &Options to action and conclusion instead of individual bools
+// 2026-10-19   PV      ActionRename with sed-style substitution, collision checks and undo journal
+// 2026-10-19   PV      ActionTransfer to copy, move, hardlink or symlink files into a destination tree
+// 2026-10-19   PV      ActionRecord for JSON, JSON Lines and CSV output, ActionPrint0
+// 2026-10-19   PV      ActionPrintf with custom output template
+// 2026-10-19   PV      ActionExec runs commands in parallel in a JobPool with option -j
+// 2026-10-19   PV      ActionExec and ActionExecg keep glob root for placeholder {rel}
+// 2026-10-19   PV      Confirmation of ActionDelete, ActionRmdir and ActionExec with -ok, -confirm and -max-delete
+// 2026-10-19   PV      Limit of -max-delete checked in main for all deletions, pending deletions can be cancelled
 
 // Crate imports
 use super::*;
 
 // Standard library imports
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io::{self, Write};
+use std::path::PathBuf;
 
 // External library imports
 use chrono::{DateTime, Local, Utc};
@@ -49,7 +59,7 @@ impl Action for ActionPrint {
         "Print".into()
     }
 
-    fn action(&mut self, lw: &mut LogWriter, path: &Path, _options: &Options) {
+    fn action(&mut self, lw: &LogWriter, path: &Path, _options: &Options) {
         if path.is_file() {
             // Includes links to existing files
             logln(lw, path.display().to_string().as_str());
@@ -66,7 +76,7 @@ impl Action for ActionPrint {
         }
     }
 
-    fn conclusion(&mut self, _lw: &mut LogWriter, _options: &Options) {}
+    fn conclusion(&mut self, _lw: &LogWriter, _options: &Options) {}
 }
 
 // ===============================================================
@@ -86,7 +96,7 @@ impl Action for ActionDir {
         "Dir".into()
     }
 
-    fn action(&mut self, lw: &mut LogWriter, path: &Path, _options: &Options) {
+    fn action(&mut self, lw: &LogWriter, path: &Path, _options: &Options) {
         let link_string = if path.is_symlink() {
             let target_path = fs::read_link(path).unwrap();
             let t = target_path.to_string_lossy().replace(r"\\?\", "");
@@ -188,7 +198,61 @@ impl Action for ActionDir {
         }
     }
 
-    fn conclusion(&mut self, _lw: &mut LogWriter, _options: &Options) {}
+    fn conclusion(&mut self, _lw: &LogWriter, _options: &Options) {}
+}
+
+// ===============================================================
+// Deletions deferred to conclusion, with options -confirm and -max-delete
+
+fn deferred_deletion(options: &Options) -> bool {
+    options.confirm == ConfirmMode::Batch || options.max_delete.is_some()
+}
+
+/// Size of a file, or of the content of a directory without following links
+fn deletion_size(path: &Path) -> u64 {
+    let Ok(meta) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !meta.is_dir() {
+        return meta.len();
+    }
+    match fs::read_dir(path) {
+        Ok(entries) => entries.flatten().map(|e| deletion_size(&e.path())).sum(),
+        Err(_) => 0,
+    }
+}
+
+/// Lists pending deletions; with -confirm, shows total size and asks once. Limit of -max-delete has already been checked.
+/// Returns true if deletions can be executed
+fn confirm_deletions(lw: &LogWriter, paths: &[PathBuf], command: impl Fn(&Path) -> String, what: &str, options: &Options) -> bool {
+    let n = paths.len();
+    if n == 0 {
+        return false;
+    }
+
+    // With -ok, each deletion is listed when it's confirmed
+    if options.confirm == ConfirmMode::Each && !options.noaction {
+        return true;
+    }
+    for path in paths.iter() {
+        logln(lw, command(path).as_str());
+    }
+    if options.confirm != ConfirmMode::Batch {
+        return !options.noaction;
+    }
+
+    let size: u64 = paths.iter().map(|p| deletion_size(p)).sum();
+    let (hs, sizefmt) = (human_size(size), size.to_formatted_string(&Locale::fr));
+    let msg = format!("{n} {what}(s) to delete, total size {hs} ({sizefmt} bytes)");
+    logln(lw, msg.as_str());
+    if options.noaction {
+        return false;
+    }
+    if !ask_yes_no(format!("Delete {n} {what}(s)?").as_str()) {
+        logln(lw, "Deletion cancelled, nothing deleted");
+        return false;
+    }
+    true
 }
 
 // ===============================================================
@@ -197,11 +261,56 @@ impl Action for ActionDir {
 #[derive(Debug)]
 pub struct ActionDelete {
     recycle: bool,
+    prompt: Prompt,
+    pending: Vec<PathBuf>, // Deletions deferred to conclusion
 }
 
 impl ActionDelete {
     pub fn new(recycle: bool) -> Self {
-        ActionDelete { recycle }
+        ActionDelete {
+            recycle,
+            prompt: Prompt::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    fn command(&self, path: &Path) -> String {
+        format!("{} {}", if self.recycle { "RECYCLE" } else { "DEL" }, quoted_path(path))
+    }
+
+    // With option -ok, deletion is listed and executed only if it's confirmed
+    fn delete_file(&mut self, lw: &LogWriter, path: &Path, options: &Options) {
+        let command = self.command(path);
+        if options.confirm == ConfirmMode::Each && !options.noaction {
+            if !self.prompt.ask(&command) {
+                return;
+            }
+            logln(lw, command.as_str());
+        }
+        if options.noaction {
+            return;
+        }
+
+        let qp = quoted_path(path);
+        if !self.recycle {
+            match fs::remove_file(path) {
+                Ok(_) => {
+                    if options.verbose {
+                        logln(lw, format!("File {} deleted successfully.", qp).as_str());
+                    }
+                }
+                Err(e) => logln(lw, format!("*** Error deleting file (fs::remove_file) {}: {}", qp, e).as_str()),
+            }
+        } else {
+            match delete(path) {
+                Ok(_) => {
+                    if options.verbose {
+                        logln(lw, format!("File {} deleted successfully.", qp).as_str());
+                    }
+                }
+                Err(e) => logln(lw, format!("*** Error deleting file (trash::delete) {}: {}", qp, e).as_str()),
+            }
+        }
     }
 }
 
@@ -215,38 +324,35 @@ impl Action for ActionDelete {
         .into()
     }
 
-    fn action(&mut self, lw: &mut LogWriter, path: &Path, options: &Options) {
+    fn action(&mut self, lw: &LogWriter, path: &Path, options: &Options) {
         if path.is_file() {
-            let qp = quoted_path(path);
-            if !self.recycle {
-                logln(lw, format!("DEL {}", qp).as_str());
-                if !options.noaction {
-                    match fs::remove_file(path) {
-                        Ok(_) => {
-                            if options.verbose {
-                                logln(lw, format!("File {} deleted successfully.", qp).as_str());
-                            }
-                        }
-                        Err(e) => logln(lw, format!("*** Error deleting file (fs::remove_file) {}: {}", qp, e).as_str()),
-                    }
-                }
-            } else {
-                logln(lw, format!("RECYCLE {}", qp).as_str());
-                if !options.noaction {
-                    match delete(path) {
-                        Ok(_) => {
-                            if options.verbose {
-                                logln(lw, format!("File {} deleted successfully.", qp).as_str());
-                            }
-                        }
-                        Err(e) => logln(lw, format!("*** Error deleting file (trash::delete) {}: {}", qp, e).as_str()),
-                    }
-                }
+            if deferred_deletion(options) {
+                self.pending.push(path.to_path_buf());
+                return;
+            }
+            if options.confirm != ConfirmMode::Each || options.noaction {
+                logln(lw, self.command(path).as_str());
+            }
+            self.delete_file(lw, path, options);
+        }
+    }
+
+    fn conclusion(&mut self, lw: &LogWriter, options: &Options) {
+        let pending = std::mem::take(&mut self.pending);
+        if confirm_deletions(lw, &pending, |p| self.command(p), "file", options) {
+            for path in pending.iter() {
+                self.delete_file(lw, path, options);
             }
         }
     }
 
-    fn conclusion(&mut self, _lw: &mut LogWriter, _options: &Options) {}
+    fn pending_deletions(&self) -> usize {
+        self.pending.len()
+    }
+
+    fn cancel_deletions(&mut self) {
+        self.pending.clear();
+    }
 }
 
 // ===============================================================
@@ -255,11 +361,67 @@ impl Action for ActionDelete {
 #[derive(Debug)]
 pub struct ActionRmdir {
     recycle: bool,
+    prompt: Prompt,
+    pending: Vec<PathBuf>, // Deletions deferred to conclusion
 }
 
 impl ActionRmdir {
     pub fn new(recycle: bool) -> Self {
-        ActionRmdir { recycle }
+        ActionRmdir {
+            recycle,
+            prompt: Prompt::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    fn command(&self, path: &Path) -> String {
+        format!("{} {}", if self.recycle { "RECYCLE (dir)" } else { "RD /S" }, quoted_path(path))
+    }
+
+    // Directories inside another pending directory are deleted with it, they're neither listed nor counted
+    fn outer_pending(&self) -> Vec<PathBuf> {
+        let all: HashSet<&Path> = self.pending.iter().map(|p| p.as_path()).collect();
+        self.pending
+            .iter()
+            .filter(|p| !p.ancestors().skip(1).any(|a| all.contains(a)))
+            .cloned()
+            .collect()
+    }
+
+    // With option -ok, deletion is listed and executed only if it's confirmed
+    fn delete_dir(&mut self, writer: &LogWriter, path: &Path, options: &Options) {
+        let command = self.command(path);
+        if options.confirm == ConfirmMode::Each && !options.noaction {
+            if !self.prompt.ask(&command) {
+                return;
+            }
+            logln(writer, command.as_str());
+        }
+        if options.noaction {
+            return;
+        }
+
+        let s = quoted_path(path);
+        let qp = s.as_str();
+        if !self.recycle {
+            match fs::remove_dir_all(path) {
+                Ok(_) => {
+                    if options.verbose {
+                        logln(writer, format!("Dir {} deleted successfully.", qp).as_str());
+                    }
+                }
+                Err(e) => logln(writer, format!("*** Error deleting dir (fs::remove_dir_all) {}: {}", qp, e).as_str()),
+            }
+        } else {
+            match delete(path) {
+                Ok(_) => {
+                    if options.verbose {
+                        logln(writer, format!("Dir '{}' deleted successfully.", qp).as_str());
+                    }
+                }
+                Err(e) => logln(writer, format!("*** Error deleting dir (trash::delete) {}: {}", qp, e).as_str()),
+            }
+        }
     }
 }
 
@@ -273,39 +435,36 @@ impl Action for ActionRmdir {
         .into()
     }
 
-    fn action(&mut self, writer: &mut LogWriter, path: &Path, options: &Options) {
+    fn action(&mut self, writer: &LogWriter, path: &Path, options: &Options) {
         if path.is_dir() {
-            let s = quoted_path(path);
-            let qp = s.as_str();
-            if !self.recycle {
-                logln(writer, format!("RD /S {}", qp).as_str());
-                if !options.noaction {
-                    match fs::remove_dir_all(path) {
-                        Ok(_) => {
-                            if options.verbose {
-                                logln(writer, format!("Dir {} deleted successfully.", qp).as_str());
-                            }
-                        }
-                        Err(e) => logln(writer, format!("*** Error deleting dir (fs::remove_dir_all) {}: {}", qp, e).as_str()),
-                    }
-                }
-            } else {
-                logln(writer, format!("RECYCLE (dir) {}", quoted_path(path)).as_str());
-                if !options.noaction {
-                    match delete(path) {
-                        Ok(_) => {
-                            if options.verbose {
-                                logln(writer, format!("Dir '{}' deleted successfully.", qp).as_str());
-                            }
-                        }
-                        Err(e) => logln(writer, format!("*** Error deleting dir (trash::delete) {}: {}", qp, e).as_str()),
-                    }
-                }
+            if deferred_deletion(options) {
+                self.pending.push(path.to_path_buf());
+                return;
+            }
+            if options.confirm != ConfirmMode::Each || options.noaction {
+                logln(writer, self.command(path).as_str());
             }
+            self.delete_dir(writer, path, options);
         }
     }
 
-    fn conclusion(&mut self, _lw: &mut LogWriter, _options: &Options) {}
+    fn conclusion(&mut self, writer: &LogWriter, options: &Options) {
+        let pending = self.outer_pending();
+        self.pending.clear();
+        if confirm_deletions(writer, &pending, |p| self.command(p), "dir", options) {
+            for path in pending.iter() {
+                self.delete_dir(writer, path, options);
+            }
+        }
+    }
+
+    fn pending_deletions(&self) -> usize {
+        self.outer_pending().len()
+    }
+
+    fn cancel_deletions(&mut self) {
+        self.pending.clear();
+    }
 }
 
 // ===============================================================
@@ -314,11 +473,43 @@ impl Action for ActionRmdir {
 #[derive(Debug)]
 pub struct ActionExec {
     ctr: CommandToRun,
+    root: PathBuf,
+    pool: Option<JobPool>, // Only with option -j
+    tag: bool,
+    prompt: Prompt,
+    launched: u64,
+    failures: Vec<JobResult>,
 }
 
 impl ActionExec {
-    pub fn new(ctr: &CommandToRun) -> Self {
-        ActionExec { ctr: (*ctr).clone() }
+    pub fn new(ctr: &CommandToRun, jobs: usize, tag: bool) -> Self {
+        ActionExec {
+            ctr: (*ctr).clone(),
+            root: PathBuf::new(),
+            pool: if jobs > 0 { Some(JobPool::new(jobs, tag)) } else { None },
+            tag,
+            prompt: Prompt::new(),
+            launched: 0,
+            failures: Vec::new(),
+        }
+    }
+
+    // Prints output of terminated commands, prefixed by path with option -tag, and keeps failures for conclusion
+    fn process_results(&mut self, lw: &LogWriter, results: Vec<JobResult>) {
+        for res in results {
+            if let Some(output) = &res.output {
+                for line in output.lines() {
+                    if self.tag {
+                        logln(lw, format!("{}: {line}", res.path).as_str());
+                    } else {
+                        logln(lw, line);
+                    }
+                }
+            }
+            if !res.success() {
+                self.failures.push(res);
+            }
+        }
     }
 }
 
@@ -327,8 +518,33 @@ impl Action for ActionExec {
         format!("Exec «{}» {}", self.ctr.command, self.ctr.args.join(" "))
     }
 
-    fn action(&mut self, lw: &mut LogWriter, path: &Path, options: &Options) {
-        match self.ctr.exec1(path, options.noaction, options.syncronous_exec) {
+    fn set_root(&mut self, root: &Path) {
+        self.root = root.to_path_buf();
+    }
+
+    fn action(&mut self, lw: &LogWriter, path: &Path, options: &Options) {
+        if options.confirm == ConfirmMode::Each && !options.noaction {
+            let command_line = self.ctr.with_path(path, &self.root).command_line();
+            if !self.prompt.ask(&command_line) {
+                return;
+            }
+        }
+
+        if let Some(pool) = &self.pool
+            && !options.noaction
+        {
+            let ctr = self.ctr.with_path(path, &self.root);
+            if options.verbose {
+                logln(lw, ctr.command_line().as_str());
+            }
+            pool.submit(path.display().to_string(), ctr);
+            self.launched += 1;
+            let results = pool.terminated();
+            self.process_results(lw, results);
+            return;
+        }
+
+        match self.ctr.exec1(path, &self.root, options.noaction, options.syncronous_exec) {
             Ok(s) => {
                 if options.verbose {
                     logln(lw, s.as_str());
@@ -340,7 +556,24 @@ impl Action for ActionExec {
         }
     }
 
-    fn conclusion(&mut self, _lw: &mut LogWriter, _options: &Options) {}
+    // With a job pool, wait for all commands to terminate and report failures
+    fn conclusion(&mut self, lw: &LogWriter, options: &Options) {
+        let Some(pool) = self.pool.as_mut() else {
+            return;
+        };
+        let results = pool.finish();
+        self.process_results(lw, results);
+
+        for res in self.failures.iter() {
+            match &res.status {
+                Ok(status) => logln(lw, format!("*** Error running command {} for {}: {}", res.command, res.path, status).as_str()),
+                Err(e) => logln(lw, format!("*** Error running command {} for {}: {}", res.command, res.path, e).as_str()),
+            }
+        }
+        if options.verbose || !self.failures.is_empty() {
+            logln(lw, format!("{} command(s) run, {} failed", self.launched, self.failures.len()).as_str());
+        }
+    }
 }
 
 // ===============================================================
@@ -349,14 +582,16 @@ impl Action for ActionExec {
 #[derive(Debug)]
 pub struct ActionExecg {
     ctr: CommandToRun,
-    args: Vec<String>,
+    root: PathBuf,
+    paths: Vec<(PathBuf, PathBuf)>, // Path found and its glob root
 }
 
 impl ActionExecg {
     pub fn new(ctr: &CommandToRun) -> Self {
         ActionExecg {
             ctr: (*ctr).clone(),
-            args: Vec::new(),
+            root: PathBuf::new(),
+            paths: Vec::new(),
         }
     }
 }
@@ -366,15 +601,19 @@ impl Action for ActionExecg {
         format!("Execg «{}» {}", self.ctr.command, self.ctr.args.join(" "))
     }
 
-    // arguments are already quoted if needed in paths
-    fn action(&mut self, _lw: &mut LogWriter, path: &Path, _options: &Options) {
-        self.args.push(quoted_string(&path.display().to_string()));
+    fn set_root(&mut self, root: &Path) {
+        self.root = root.to_path_buf();
     }
 
-    fn conclusion(&mut self, lw: &mut LogWriter, options: &Options) {
+    // Placeholders are replaced and quoted when chunks are built
+    fn action(&mut self, _lw: &LogWriter, path: &Path, _options: &Options) {
+        self.paths.push((path.to_path_buf(), self.root.clone()));
+    }
+
+    fn conclusion(&mut self, lw: &LogWriter, options: &Options) {
         // For now we hardcode command limit size at 7500 UTF-16 chars despite win32 CreateProcess 32K limit since cmd /c has a limit of 8000
         // Maybe I'll add an option later to control this size since it's command-dependent
-        let chunks = self.ctr.make_chunks(&self.args, 7500);
+        let chunks = self.ctr.make_chunks(&self.paths, 7500);
         for chunk in chunks.iter() {
             match chunk.exec(options.noaction, options.syncronous_exec) {
                 Ok(s) => {
@@ -407,7 +646,7 @@ impl Action for ActionYaml {
         "Yaml".into()
     }
 
-    fn action(&mut self, lw: &mut LogWriter, path: &Path, _options: &Options) {
+    fn action(&mut self, lw: &LogWriter, path: &Path, _options: &Options) {
         if path.is_file() {
             logln(lw, "- typ: file");
         } else {
@@ -418,7 +657,482 @@ impl Action for ActionYaml {
         logln(lw, &format!("  new: {}\n", qp));
     }
 
-    fn conclusion(&mut self, _lw: &mut LogWriter, _options: &Options) {}
+    fn conclusion(&mut self, _lw: &LogWriter, _options: &Options) {}
+}
+
+// ===============================================================
+// Machine-readable output actions
+
+/// Format of records written by ActionRecord
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordFormat {
+    Json,  // Array of objects, one per line
+    Jsonl, // JSON Lines, one object per line
+    Csv,   // Header line, then one record per line
+}
+
+#[derive(Debug)]
+pub struct ActionRecord {
+    format: RecordFormat,
+    count: u64,
+}
+
+impl ActionRecord {
+    pub fn new(format: RecordFormat) -> Self {
+        ActionRecord { format, count: 0 }
+    }
+}
+
+impl Action for ActionRecord {
+    fn name(&self) -> String {
+        format!("{:?}", self.format)
+    }
+
+    fn action(&mut self, lw: &LogWriter, path: &Path, _options: &Options) {
+        let info = PathInfo::new(path);
+        match self.format {
+            // Separator is written before next object since we don't know which object is the last one
+            RecordFormat::Json => log(lw, &format!("{}  {}", if self.count == 0 { "[\n" } else { ",\n" }, info.to_json())),
+            RecordFormat::Jsonl => logln(lw, &info.to_json()),
+            RecordFormat::Csv => {
+                if self.count == 0 {
+                    logln(lw, &PathInfo::FIELDS.join(","));
+                }
+                logln(lw, &info.to_csv());
+            }
+        }
+        io::stdout().flush().unwrap();
+        self.count += 1;
+    }
+
+    fn conclusion(&mut self, lw: &LogWriter, _options: &Options) {
+        match self.format {
+            RecordFormat::Json => logln(lw, if self.count == 0 { "[]" } else { "\n]" }),
+            RecordFormat::Jsonl => {}
+            RecordFormat::Csv => {
+                if self.count == 0 {
+                    logln(lw, &PathInfo::FIELDS.join(","));
+                }
+            }
+        }
+    }
+}
+
+// ===============================================================
+// Printf action, output with a custom template
+
+#[derive(Debug)]
+pub struct ActionPrintf {
+    template: Template,
+    root: PathBuf,
+}
+
+impl ActionPrintf {
+    pub fn new(template: &Template) -> Self {
+        ActionPrintf {
+            template: template.clone(),
+            root: PathBuf::new(),
+        }
+    }
+}
+
+impl Action for ActionPrintf {
+    fn name(&self) -> String {
+        format!("Printf «{}»", self.template)
+    }
+
+    fn set_root(&mut self, root: &Path) {
+        self.root = root.to_path_buf();
+    }
+
+    fn action(&mut self, lw: &LogWriter, path: &Path, _options: &Options) {
+        logln(lw, &self.template.render(path, &self.root));
+        io::stdout().flush().unwrap();
+    }
+
+    fn conclusion(&mut self, _lw: &LogWriter, _options: &Options) {}
+}
+
+// ===============================================================
+// Print0 action, paths terminated by a NUL character for xargs -0
+
+#[derive(Debug)]
+pub struct ActionPrint0 {}
+
+impl ActionPrint0 {
+    pub fn new() -> Self {
+        ActionPrint0 {}
+    }
+}
+
+impl Action for ActionPrint0 {
+    fn name(&self) -> String {
+        "Print0".into()
+    }
+
+    fn action(&mut self, lw: &LogWriter, path: &Path, _options: &Options) {
+        log(lw, &format!("{}\0", path.display()));
+    }
+
+    fn conclusion(&mut self, _lw: &LogWriter, _options: &Options) {
+        io::stdout().flush().unwrap();
+    }
+}
+
+// ===============================================================
+// Rename action
+
+#[derive(Debug)]
+pub struct ActionRename {
+    substitution: Substitution,
+    journal: Option<String>,
+    renames: Vec<(PathBuf, PathBuf)>,
+}
+
+impl ActionRename {
+    pub fn new(substitution: &Substitution, journal: Option<String>) -> Self {
+        ActionRename {
+            substitution: substitution.clone(),
+            journal,
+            renames: Vec::new(),
+        }
+    }
+
+    // Key to compare paths, case-insensitive on Windows
+    fn path_key(path: &Path) -> String {
+        if cfg!(target_os = "windows") {
+            path.to_string_lossy().to_lowercase()
+        } else {
+            path.to_string_lossy().to_string()
+        }
+    }
+
+    // Journal in rcheckfiles -F format to undo renames: old and new are swapped, and last rename comes first
+    fn write_journal(journal: &str, done: &[(PathBuf, PathBuf, bool)]) -> io::Result<()> {
+        let mut text = format!("# {APP_NAME} rename journal, undo with: rcheckfiles -F {}\n", quoted_string(journal));
+        for (old, new, is_dir) in done.iter().rev() {
+            let old = std::path::absolute(old).unwrap_or(old.clone());
+            let new = std::path::absolute(new).unwrap_or(new.clone());
+            text += &format!("- typ: {}\n", if *is_dir { "dir" } else { "file" });
+            text += &format!("  old: {}\n", to_yaml_single_quoted(&new.to_string_lossy()));
+            text += &format!("  new: {}\n\n", to_yaml_single_quoted(&old.to_string_lossy()));
+        }
+        fs::write(journal, text)
+    }
+}
+
+impl Action for ActionRename {
+    fn name(&self) -> String {
+        match &self.journal {
+            Some(j) => format!("Rename {}, journal {}", self.substitution, quoted_string(j)),
+            None => format!("Rename {}", self.substitution),
+        }
+    }
+
+    // Renames are only collected here, they're checked and executed at the end, once search is complete
+    fn action(&mut self, lw: &LogWriter, path: &Path, _options: &Options) {
+        let Some(name) = path.file_name() else {
+            return;
+        };
+        let Some(name) = name.to_str() else {
+            logln(lw, format!("*** Can't rename {}: name is not valid Unicode", quoted_path(path)).as_str());
+            return;
+        };
+
+        let new_name = self.substitution.apply(name);
+        if new_name == name {
+            return;
+        }
+        if new_name.is_empty() || new_name == "." || new_name == ".." || new_name.contains(std::path::is_separator) {
+            let qp = quoted_path(path);
+            logln(lw, format!("*** Can't rename {qp}: invalid new name «{new_name}»").as_str());
+            return;
+        }
+        self.renames.push((path.to_path_buf(), path.with_file_name(new_name)));
+    }
+
+    fn conclusion(&mut self, lw: &LogWriter, options: &Options) {
+        if self.renames.is_empty() {
+            if options.verbose {
+                logln(lw, "Nothing to rename");
+            }
+            return;
+        }
+
+        // Refuse renames to the same target, and to an existing path unless it's the same path with a different case
+        let mut targets: HashMap<String, usize> = HashMap::new();
+        for (_, new) in self.renames.iter() {
+            *targets.entry(Self::path_key(new)).or_default() += 1;
+        }
+        let mut planned: Vec<(PathBuf, PathBuf)> = Vec::new();
+        for (old, new) in self.renames.drain(..) {
+            let (qo, qn) = (quoted_path(&old), quoted_path(&new));
+            if targets[&Self::path_key(&new)] > 1 {
+                logln(lw, format!("*** Can't rename {qo}: several paths would be renamed {qn}").as_str());
+            } else if fs::symlink_metadata(&new).is_ok() && Self::path_key(&old) != Self::path_key(&new) {
+                logln(lw, format!("*** Can't rename {qo}: {qn} already exists").as_str());
+            } else {
+                planned.push((old, new));
+            }
+        }
+
+        // List planned renames before executing any of them
+        for (old, new) in planned.iter() {
+            let new_name = new.file_name().unwrap().to_string_lossy();
+            logln(lw, format!("REN {} {}", quoted_path(old), quoted_string(&new_name)).as_str());
+        }
+        if options.noaction {
+            for (old, _) in planned.iter() {
+                lw.count(if old.is_dir() { "dir to rename" } else { "file to rename" });
+            }
+            return;
+        }
+
+        // Deepest paths first, so that renaming a directory doesn't invalidate paths of its content
+        planned.sort_by_key(|(old, _)| std::cmp::Reverse(old.components().count()));
+        let mut done: Vec<(PathBuf, PathBuf, bool)> = Vec::new();
+        for (old, new) in planned {
+            let is_dir = old.is_dir();
+            let (qo, qn) = (quoted_path(&old), quoted_path(&new));
+            match fs::rename(&old, &new) {
+                Ok(_) => {
+                    lw.count(if is_dir { "dir renamed" } else { "file renamed" });
+                    done.push((old, new, is_dir));
+                }
+                Err(e) => logln(lw, format!("*** Error renaming {qo} to {qn}: {e}").as_str()),
+            }
+        }
+
+        if let Some(journal) = &self.journal
+            && !done.is_empty()
+        {
+            match Self::write_journal(journal, &done) {
+                Ok(_) => {
+                    if options.verbose {
+                        logln(lw, format!("Undo journal written to {}", quoted_string(journal)).as_str());
+                    }
+                }
+                Err(e) => logln(lw, format!("*** Error writing undo journal {}: {}", quoted_string(journal), e).as_str()),
+            }
+        }
+    }
+}
+
+// ===============================================================
+// Copy, move, hardlink and symlink actions
+
+/// How a file is transferred into destination tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferKind {
+    Copy,
+    Move,
+    HardLink,
+    SymLink,
+}
+
+impl TransferKind {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "copy" => Some(TransferKind::Copy),
+            "move" => Some(TransferKind::Move),
+            "hardlink" => Some(TransferKind::HardLink),
+            "symlink" => Some(TransferKind::SymLink),
+            _ => None,
+        }
+    }
+
+    fn verb(&self) -> &'static str {
+        match self {
+            TransferKind::Copy => "copied",
+            TransferKind::Move => "moved",
+            TransferKind::HardLink => "hardlinked",
+            TransferKind::SymLink => "symlinked",
+        }
+    }
+}
+
+/// What to do when target file already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Collision {
+    #[default]
+    Skip,
+    Overwrite,
+    Rename,
+}
+
+impl Collision {
+    pub const NAMES: [&'static str; 3] = ["skip", "overwrite", "rename"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "skip" => Some(Collision::Skip),
+            "overwrite" => Some(Collision::Overwrite),
+            "rename" => Some(Collision::Rename),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ActionTransfer {
+    kind: TransferKind,
+    dest: PathBuf,
+    dest_abs: PathBuf,
+    collision: Collision,
+    root: PathBuf,
+    done: u64,
+    skipped: u64,
+    errors: u64,
+}
+
+impl ActionTransfer {
+    pub fn new(kind: TransferKind, dest: &str, collision: Collision) -> Self {
+        let dest = PathBuf::from(dest);
+        ActionTransfer {
+            kind,
+            dest_abs: std::path::absolute(&dest).unwrap_or(dest.clone()),
+            dest,
+            collision,
+            root: PathBuf::new(),
+            done: 0,
+            skipped: 0,
+            errors: 0,
+        }
+    }
+
+    // Path below glob root, or just file name for a constant glob
+    fn target(&self, path: &Path) -> PathBuf {
+        match path.strip_prefix(&self.root) {
+            Ok(rel) if !rel.as_os_str().is_empty() => self.dest.join(rel),
+            _ => self.dest.join(path.file_name().unwrap_or_default()),
+        }
+    }
+
+    fn transfer(&self, source: &Path, target: &Path) -> io::Result<()> {
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        match self.kind {
+            TransferKind::Copy => fs::copy(source, target).map(|_| ()),
+            // fs::rename replaces an existing target; between volumes, it's a copy followed by a delete
+            TransferKind::Move => match fs::rename(source, target) {
+                Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+                    fs::copy(source, target)?;
+                    fs::remove_file(source)
+                }
+                res => res,
+            },
+            TransferKind::HardLink | TransferKind::SymLink => {
+                if fs::symlink_metadata(target).is_ok() {
+                    fs::remove_file(target)?;
+                }
+                if self.kind == TransferKind::HardLink {
+                    fs::hard_link(source, target)
+                } else {
+                    symlink_file(&std::path::absolute(source)?, target)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn symlink_file(original: &Path, link: &Path) -> io::Result<()> {
+    std::os::windows::fs::symlink_file(original, link)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn symlink_file(original: &Path, link: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(original, link)
+}
+
+/// First free path name (1).ext, name (2).ext...
+pub fn numbered_path(path: &Path) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let ext = path.extension().map(|e| format!(".{}", e.to_string_lossy())).unwrap_or_default();
+    (1..)
+        .map(|n| path.with_file_name(format!("{stem} ({n}){ext}")))
+        .find(|p| fs::symlink_metadata(p).is_err())
+        .unwrap()
+}
+
+impl Action for ActionTransfer {
+    fn name(&self) -> String {
+        let collision = format!("{:?}", self.collision).to_lowercase();
+        format!("{:?} files to {} (collision: {collision})", self.kind, quoted_path(&self.dest))
+    }
+
+    fn set_root(&mut self, root: &Path) {
+        self.root = root.to_path_buf();
+    }
+
+    // Only files are transferred, directories of destination tree are created when needed
+    fn action(&mut self, lw: &LogWriter, path: &Path, options: &Options) {
+        if !path.is_file() {
+            return;
+        }
+        // Files already in destination tree are ignored, destination can be below source
+        if let Ok(abs) = std::path::absolute(path)
+            && abs.starts_with(&self.dest_abs)
+        {
+            return;
+        }
+
+        let qp = quoted_path(path);
+        let mut target = self.target(path);
+        if fs::symlink_metadata(&target).is_ok() {
+            if target.is_dir() {
+                logln(lw, format!("*** Can't transfer {qp}: {} is a directory", quoted_path(&target)).as_str());
+                self.errors += 1;
+                return;
+            }
+            match self.collision {
+                Collision::Skip => {
+                    if options.verbose {
+                        logln(lw, format!("Skipped {qp}: {} already exists", quoted_path(&target)).as_str());
+                    }
+                    lw.count("file skipped");
+                    self.skipped += 1;
+                    return;
+                }
+                Collision::Overwrite => {}
+                Collision::Rename => target = numbered_path(&target),
+            }
+        }
+
+        let qt = quoted_path(&target);
+        logln(lw, format!("{} {qp} {qt}", format!("{:?}", self.kind).to_uppercase()).as_str());
+        if options.noaction {
+            self.done += 1;
+            return;
+        }
+        match self.transfer(path, &target) {
+            Ok(_) => {
+                lw.count(&format!("file {}", self.kind.verb()));
+                self.done += 1;
+            }
+            Err(e) => {
+                logln(lw, format!("*** Error transferring {qp} to {qt}: {e}").as_str());
+                self.errors += 1;
+            }
+        }
+    }
+
+    fn conclusion(&mut self, lw: &LogWriter, options: &Options) {
+        let verb = if options.noaction {
+            format!("to be {}", self.kind.verb())
+        } else {
+            self.kind.verb().to_string()
+        };
+        let mut msg = format!("{} file(s) {verb} to {}", self.done, quoted_path(&self.dest));
+        if self.skipped > 0 {
+            msg += &format!(", {} skipped (target exists)", self.skipped);
+        }
+        if self.errors > 0 {
+            msg += &format!(", {} error(s)", self.errors);
+        }
+        logln(lw, msg.as_str());
+    }
 }
 
 /// Wraps a string in single quotes for safe inclusion in a YAML file.
@@ -3,11 +3,65 @@
 //
 // 2025-10-30   PV      Move structure to this separate file, including exec/exec1/make_chunks so it can be shared
 // 2025-11-04   PV      quoted_string doesn'r re-quote an already quoted string
+// 2026-10-19   PV      with_path separated from exec1 for parallel execution
+// 2026-10-19   PV      Placeholders {/}, {//}, {.}, {/.}, {ext} and {rel} in addition to {}
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
-// Represents a command to be executed where args contain {} placeholder(s)
+/// Placeholders replaced in commands, longest first so that {//} is not taken for {/}
+pub const PLACEHOLDERS: [&str; 7] = ["{//}", "{/.}", "{ext}", "{rel}", "{/}", "{.}", "{}"];
+
+/// True if arg contains at least one placeholder
+pub fn has_placeholder(arg: &str) -> bool {
+    PLACEHOLDERS.iter().any(|p| arg.contains(p))
+}
+
+/// Replaces placeholders of arg for a path found below glob root, values are quoted if needed with quote.<br/>
+/// Replacement is done in a single pass, so braces in a path are never interpreted as placeholders.
+pub fn expand_placeholders(arg: &str, path: &Path, root: &Path, quote: bool) -> String {
+    let os_string = |s: Option<&std::ffi::OsStr>| s.map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    let value = |placeholder: &str| -> String {
+        match placeholder {
+            "{}" => path.display().to_string(),
+            "{/}" => os_string(path.file_name()),
+            "{//}" => match path.parent() {
+                Some(p) if !p.as_os_str().is_empty() => p.display().to_string(),
+                _ => ".".to_string(),
+            },
+            "{.}" => path.with_extension("").display().to_string(),
+            "{/.}" => os_string(path.file_stem()),
+            "{ext}" => os_string(path.extension()),
+            "{rel}" => match path.strip_prefix(root) {
+                Ok(rel) if !rel.as_os_str().is_empty() => rel.display().to_string(),
+                _ => path.display().to_string(),
+            },
+            _ => unreachable!(),
+        }
+    };
+
+    let mut res = String::new();
+    let mut rest = arg;
+    while let Some(pos) = rest.find('{') {
+        res.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        match PLACEHOLDERS.iter().find(|p| rest.starts_with(*p)) {
+            Some(p) => {
+                let v = value(p);
+                res.push_str(&if quote { quoted_string(&v) } else { v });
+                rest = &rest[p.len()..];
+            }
+            None => {
+                res.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    res.push_str(rest);
+    res
+}
+
+// Represents a command to be executed where args contain placeholder(s) such as {}
 #[derive(Debug, Default, Clone)]
 pub struct CommandToRun {
     pub command: String,
@@ -15,25 +69,25 @@ pub struct CommandToRun {
 }
 
 impl CommandToRun {
-    pub fn exec1(&self, path: &Path, noaction: bool, syncronous_exec: bool) -> Result<String, String> {
-        let mut ctr_final = self.clone();
-
-        // Special case
-        if ctr_final.command.contains("{}") {
-            ctr_final.command = ctr_final.command.replace("{}", path.display().to_string().as_str());
-        }
+    pub fn exec1(&self, path: &Path, root: &Path, noaction: bool, syncronous_exec: bool) -> Result<String, String> {
+        self.with_path(path, root).exec(noaction, syncronous_exec)
+    }
 
-        for refarg in ctr_final.args.iter_mut() {
-            if refarg.contains("{}") {
-                *refarg = refarg.replace("{}", path.display().to_string().as_str());
-            }
+    /// Command for a path found below glob root, placeholders replaced in command and arguments
+    pub fn with_path(&self, path: &Path, root: &Path) -> CommandToRun {
+        CommandToRun {
+            command: expand_placeholders(&self.command, path, root, false),
+            args: self.args.iter().map(|arg| expand_placeholders(arg, path, root, false)).collect(),
         }
+    }
 
-        ctr_final.exec(noaction, syncronous_exec)
+    /// Command line, as it's shown in verbose mode
+    pub fn command_line(&self) -> String {
+        format!("exec {} {}", quoted_string(&self.command), self.args.join(" "))
     }
 
     pub fn exec(&self, noaction: bool, syncronous_exec: bool) -> Result<String, String> {
-        let res = format!("exec {} {}", quoted_string(&self.command), self.args.join(" "));
+        let res = self.command_line();
         if !noaction {
             let status = Command::new(self.command.as_str()).args(&self.args).spawn();
             match status {
@@ -63,20 +117,20 @@ impl CommandToRun {
     // Helpers for grouped execution, breaking a parameters replacement into one or more RunCommands to ensure that an individual
     // command will not exceed len16_max UTF-16 characters
     // This code is not trivial...
-    pub fn make_chunks(&self, paths: &[String], len16_max: usize) -> Vec<CommandToRun> {
+    // paths contains (path, glob root) pairs, values of placeholders are quoted if needed
+    pub fn make_chunks(&self, paths: &[(PathBuf, PathBuf)], len16_max: usize) -> Vec<CommandToRun> {
         let mut res = Vec::<CommandToRun>::new();
         let mut braces_args = Vec::<Vec<String>>::new();
 
-        fn transform_path(arg: &str, path: &str) -> String {
-            // For now, we just support {}, but in the future, we may support transformations between {}
-            arg.replace("{}", path)
-        }
-
-        // First pass, prepare transformations, calculate sizes of fixed args (without {})
+        // First pass, prepare transformations, calculate sizes of fixed args (without placeholders)
+        // Lengths are computed on transformed args, so placeholders expanding to a part of the path are accounted correctly
         let mut len16_fixed: usize = 0;
         for arg in self.args.iter() {
-            if arg.contains('{') {
-                let ba = paths.iter().map(|pa| transform_path(arg, pa)).collect::<Vec<String>>();
+            if has_placeholder(arg) {
+                let ba = paths
+                    .iter()
+                    .map(|(path, root)| expand_placeholders(arg, path, root, true))
+                    .collect::<Vec<String>>();
                 braces_args.push(ba);
             } else {
                 len16_fixed += 1 + arg.encode_utf16().count();
@@ -97,7 +151,7 @@ impl CommandToRun {
             let mut ixba = 0; // Follows progression in braces_args, firs arg with {} is at index 0
             // We process args in sequence
             for arg in ctr.args.iter() {
-                if arg.contains('{') {
+                if has_placeholder(arg) {
                     let ba = &braces_args[ixba];
                     ixba += 1;
                     #[allow(clippy::needless_range_loop)]
@@ -0,0 +1,112 @@
+// confirm.rs, interactive confirmation of destructive actions
+//
+// 2026-10-19   PV      First version
+
+// Standard library imports
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// How destructive actions are confirmed before execution
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmMode {
+    #[default]
+    None, // Execute without asking
+    Each,  // -ok/-i: ask for each path of -delete, -rmdir and -exec
+    Batch, // -confirm: list pending deletions of -delete and -rmdir with total size, then ask once
+}
+
+/// Answer to a per-path question
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    Yes,
+    No,
+    All,  // Yes for this path and all remaining paths of the action
+    Quit, // No for this path and all remaining paths, stops the search
+}
+
+impl Answer {
+    /// Case-insensitive y[es], n[o], a[ll] or q[uit], an empty answer is No
+    pub fn parse(answer: &str) -> Option<Answer> {
+        match &answer.trim().to_lowercase()[..] {
+            "y" | "yes" => Some(Answer::Yes),
+            "n" | "no" | "" => Some(Answer::No),
+            "a" | "all" => Some(Answer::All),
+            "q" | "quit" => Some(Answer::Quit),
+            _ => None,
+        }
+    }
+}
+
+// Answer q is global: once given, no action asks again and main stops the search
+static QUIT: AtomicBool = AtomicBool::new(false);
+
+/// True once answer q has been given, or when stdin is closed
+pub fn quit_requested() -> bool {
+    QUIT.load(Ordering::Relaxed)
+}
+
+// Prints question on stderr to keep stdout clean, and reads a line on stdin. None at end of input
+fn read_answer(question: &str) -> Option<String> {
+    eprint!("{question} ");
+    let _ = io::stderr().flush();
+    let mut line = String::new();
+    match io::stdin().read_line(&mut line) {
+        Ok(0) | Err(_) => {
+            eprintln!();
+            None
+        }
+        Ok(_) => Some(line),
+    }
+}
+
+/// Per-path prompt of an action, remembers answer a
+#[derive(Debug, Default)]
+pub struct Prompt {
+    all: bool,
+}
+
+impl Prompt {
+    pub fn new() -> Self {
+        Prompt { all: false }
+    }
+
+    /// Asks question until a valid answer is given, returns true to execute. End of input is considered as q
+    pub fn ask(&mut self, question: &str) -> bool {
+        if quit_requested() {
+            return false;
+        }
+        if self.all {
+            return true;
+        }
+        loop {
+            let Some(line) = read_answer(&format!("{question} [y/n/a/q]")) else {
+                QUIT.store(true, Ordering::Relaxed);
+                return false;
+            };
+            match Answer::parse(&line) {
+                Some(Answer::Yes) => return true,
+                Some(Answer::No) => return false,
+                Some(Answer::All) => {
+                    self.all = true;
+                    return true;
+                }
+                Some(Answer::Quit) => {
+                    QUIT.store(true, Ordering::Relaxed);
+                    return false;
+                }
+                None => eprintln!("Answer y (yes), n (no), a (yes to all) or q (quit)"),
+            }
+        }
+    }
+}
+
+/// Single y/n question, anything else than y[es] is no
+pub fn ask_yes_no(question: &str) -> bool {
+    if quit_requested() {
+        return false;
+    }
+    match read_answer(&format!("{question} [y/n]")) {
+        Some(line) => Answer::parse(&line) == Some(Answer::Yes),
+        None => false,
+    }
+}
@@ -0,0 +1,107 @@
+// filter_expr.rs, parsing of filter expressions
+// Filters and operators -not, -and, -or and parentheses from command line are converted into a tree of filters.
+// -not has highest precedence, then -and, then -or; two filters without operator are combined with -and.
+//
+// 2026-10-19   PV      First version
+
+use super::*;
+
+use filters::{FilterAnd, FilterNot, FilterOr};
+
+/// Element of a filter expression, collected from command line in order
+#[derive(Debug)]
+pub enum FilterToken {
+    Filter(Box<dyn Filter>),
+    Not,
+    And,
+    Or,
+    Open,
+    Close,
+}
+
+/// Converts tokens (with command line text used in error messages) into a single filter, None if there is no filter
+pub fn parse_filter_expr(tokens: Vec<(FilterToken, String)>) -> Result<Option<Box<dyn Filter>>, String> {
+    if tokens.is_empty() {
+        return Ok(None);
+    }
+
+    let mut parser = Parser {
+        tokens: tokens.into_iter().map(|(t, text)| (Some(t), text)).collect(),
+        pos: 0,
+    };
+    let filter = parser.parse_or()?;
+    if let Some((_, text)) = parser.tokens.get(parser.pos) {
+        // Only a closing parenthesis can stop parse_or before the end
+        return Err(format!("Unexpected {text} in filter expression"));
+    }
+    Ok(Some(filter))
+}
+
+// Tokens are taken (replaced by None) when they're consumed
+struct Parser {
+    tokens: Vec<(Option<FilterToken>, String)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&FilterToken> {
+        self.tokens.get(self.pos).and_then(|(t, _)| t.as_ref())
+    }
+
+    // Text of current token, or of previous one at the end of expression
+    fn error_expected(&self) -> String {
+        match self.tokens.get(self.pos) {
+            Some((_, text)) => format!("Filter expected before {text} in filter expression"),
+            None => format!("Filter expected after {} in filter expression", self.tokens[self.pos - 1].1),
+        }
+    }
+
+    // or_expr := and_expr (-or and_expr)*
+    fn parse_or(&mut self) -> Result<Box<dyn Filter>, String> {
+        let mut filters = vec![self.parse_and()?];
+        while matches!(self.peek(), Some(FilterToken::Or)) {
+            self.pos += 1;
+            filters.push(self.parse_and()?);
+        }
+        Ok(if filters.len() == 1 { filters.pop().unwrap() } else { Box::new(FilterOr::new(filters)) })
+    }
+
+    // and_expr := unary ([-and] unary)*
+    fn parse_and(&mut self) -> Result<Box<dyn Filter>, String> {
+        let mut filters = vec![self.parse_unary()?];
+        loop {
+            match self.peek() {
+                Some(FilterToken::And) => self.pos += 1,
+                Some(FilterToken::Filter(_) | FilterToken::Not | FilterToken::Open) => {}
+                _ => break,
+            }
+            filters.push(self.parse_unary()?);
+        }
+        Ok(if filters.len() == 1 { filters.pop().unwrap() } else { Box::new(FilterAnd::new(filters)) })
+    }
+
+    // unary := -not unary | ( or_expr ) | filter
+    fn parse_unary(&mut self) -> Result<Box<dyn Filter>, String> {
+        if self.pos >= self.tokens.len() || matches!(self.peek(), Some(FilterToken::And | FilterToken::Or | FilterToken::Close)) {
+            return Err(self.error_expected());
+        }
+
+        let open_pos = self.pos;
+        let token = self.tokens[self.pos].0.take();
+        self.pos += 1;
+
+        match token.unwrap() {
+            FilterToken::Filter(f) => Ok(f),
+            FilterToken::Not => Ok(Box::new(FilterNot::new(self.parse_unary()?))),
+            FilterToken::Open => {
+                let filter = self.parse_or()?;
+                if !matches!(self.peek(), Some(FilterToken::Close)) {
+                    return Err(format!("Missing ) for {} in filter expression", self.tokens[open_pos].1));
+                }
+                self.pos += 1;
+                Ok(filter)
+            }
+            _ => unreachable!(),
+        }
+    }
+}
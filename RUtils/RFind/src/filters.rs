@@ -1,10 +1,20 @@
 // filters.rs, definition of filters
 //
 // 2025-10-27	PV      First version
+// 2026-10-19   PV      Filters size, newer, mtime/atime/ctime, regex and iname; name() returns a String
+// 2026-10-19   PV      Boolean operators FilterNot, FilterAnd and FilterOr
+// 2026-10-19   PV      FilterContains on text content decoded by TextAutoDecode
 
 use super::*;
 
 // Standard library imports
+use std::time::{Duration, SystemTime};
+
+// External crates imports
+use chrono::{Days, Local, NaiveDate, NaiveTime};
+use myglob::{MyGlobBuilder, Segment};
+use regex::{Regex, RegexBuilder};
+use textautodecode::{TextAutoDecode, TextFileEncoding};
 
 // ===============================================================
 // Empty filter
@@ -19,11 +29,11 @@ impl FilterEmpty {
 }
 
 impl Filter for FilterEmpty {
-    fn name(&self) -> &'static str {
-        "Empty: Select empty files and directories"
+    fn name(&self) -> String {
+        "Empty: Select empty files and directories".into()
     }
 
-    fn filter(&mut self, lw: &mut LogWriter, path: &Path, _verbose: bool) -> bool {
+    fn filter(&mut self, lw: &LogWriter, path: &Path, _verbose: bool) -> bool {
         if path.is_file() {
             fs::metadata(path).unwrap().len() == 0
         } else if path.is_dir() {
@@ -56,15 +66,15 @@ impl FilterADS {
 }
 
 impl Filter for FilterADS {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> String {
         if self.ignore_small_streams {
-            "adsx: Select files with alternate data streams other than Zone.identification"
+            "adsx: Select files with alternate data streams other than Zone.identification".into()
         } else {
-            "ads: Select files with alternate data streams"
+            "ads: Select files with alternate data streams".into()
         }
     }
 
-    fn filter(&mut self, _lw: &mut LogWriter, path: &Path, _verbose: bool) -> bool {
+    fn filter(&mut self, _lw: &LogWriter, path: &Path, _verbose: bool) -> bool {
         if path.is_file() {
             let streams = match fa_streams::get_streams_list(path, false) {
                 Ok(s) => s,
@@ -91,3 +101,453 @@ impl Filter for FilterADS {
         }
     }
 }
+
+// ===============================================================
+// Size filter
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compare {
+    Less,
+    Equal,
+    Greater,
+}
+
+#[derive(Debug)]
+pub struct FilterSize {
+    compare: Compare,
+    size: u64,
+    arg: String,
+}
+
+impl FilterSize {
+    /// Size argument is +n (more than n), -n (less than n) or n (exactly n), n is followed by an optional unit c (bytes,
+    /// default), k, M, G or T (powers of 1024), units are case insensitive
+    pub fn new(arg: &str) -> Result<Self, String> {
+        let (compare, rest) = match arg.chars().next() {
+            Some('+') => (Compare::Greater, &arg[1..]),
+            Some('-') => (Compare::Less, &arg[1..]),
+            _ => (Compare::Equal, arg),
+        };
+
+        let digits_end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let (digits, unit) = rest.split_at(digits_end);
+        let n = digits.parse::<u64>().map_err(|_| format!("Invalid size {arg}, expecting [+|-]n[k|M|G|T]"))?;
+        let multiplier: u64 = match unit.to_lowercase().as_str() {
+            "" | "c" => 1,
+            "k" => 1 << 10,
+            "m" => 1 << 20,
+            "g" => 1 << 30,
+            "t" => 1 << 40,
+            _ => return Err(format!("Invalid size unit {unit} in {arg}, valid units are c, k, M, G or T")),
+        };
+        let size = n.checked_mul(multiplier).ok_or(format!("Size {arg} is too large"))?;
+
+        Ok(FilterSize {
+            compare,
+            size,
+            arg: arg.to_string(),
+        })
+    }
+
+    pub fn matches(&self, len: u64) -> bool {
+        match self.compare {
+            Compare::Less => len < self.size,
+            Compare::Equal => len == self.size,
+            Compare::Greater => len > self.size,
+        }
+    }
+}
+
+impl Filter for FilterSize {
+    fn name(&self) -> String {
+        format!("size {}: Select files by size", self.arg)
+    }
+
+    fn filter(&mut self, _lw: &LogWriter, path: &Path, _verbose: bool) -> bool {
+        // Directories have no meaningful size, they're never selected
+        match fs::metadata(path) {
+            Ok(md) if md.is_file() => self.matches(md.len()),
+            _ => false,
+        }
+    }
+}
+
+// ===============================================================
+// Newer filter
+
+#[derive(Debug)]
+pub struct FilterNewer {
+    reference: SystemTime,
+    file: String,
+}
+
+impl FilterNewer {
+    /// Reference time is last modification time of file, read once when filter is created
+    pub fn new(file: &str) -> Result<Self, String> {
+        let reference = fs::metadata(file)
+            .and_then(|md| md.modified())
+            .map_err(|e| format!("Can't get modification time of {file}: {e}"))?;
+        Ok(FilterNewer {
+            reference,
+            file: file.to_string(),
+        })
+    }
+}
+
+impl Filter for FilterNewer {
+    fn name(&self) -> String {
+        format!("newer: Select files and dirs modified more recently than {}", self.file)
+    }
+
+    fn filter(&mut self, _lw: &LogWriter, path: &Path, _verbose: bool) -> bool {
+        match fs::metadata(path).and_then(|md| md.modified()) {
+            Ok(t) => t > self.reference,
+            Err(_) => false,
+        }
+    }
+}
+
+// ===============================================================
+// Time filters: -mtime, -atime, -ctime
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeKind {
+    Modified,
+    Accessed,
+    Created,
+}
+
+impl TimeKind {
+    fn option(&self) -> &'static str {
+        match self {
+            TimeKind::Modified => "mtime",
+            TimeKind::Accessed => "atime",
+            TimeKind::Created => "ctime",
+        }
+    }
+
+    fn get(&self, md: &fs::Metadata) -> std::io::Result<SystemTime> {
+        match self {
+            TimeKind::Modified => md.modified(),
+            TimeKind::Accessed => md.accessed(),
+            TimeKind::Created => md.created(),
+        }
+    }
+}
+
+/// Half-open interval of time [from, to), a missing bound is unlimited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeRange {
+    pub from: Option<SystemTime>,
+    pub to: Option<SystemTime>,
+}
+
+const DAY: Duration = Duration::from_secs(86400);
+
+impl TimeRange {
+    /// Range from an argument of -mtime/-atime/-ctime, relative to now for a number of days:
+    /// +n: more than n full days ago, -n: less than n days ago, n: between n and n+1 days ago,
+    /// date: this local day, date1..date2: from date1 to date2 included, date1.. or ..date2: open range.
+    /// Dates use format YYYY-MM-DD
+    pub fn parse(arg: &str, now: SystemTime) -> Result<Self, String> {
+        let days = |n: &str| n.parse::<u32>().map_err(|_| format!("Invalid number of days in {arg}"));
+        let days_ago = |n: u32| DAY.checked_mul(n).and_then(|d| now.checked_sub(d)).ok_or(format!("Number of days too large in {arg}"));
+
+        if let Some(n) = arg.strip_prefix('+') {
+            return Ok(TimeRange {
+                from: None,
+                to: Some(days_ago(days(n)?.saturating_add(1))?),
+            });
+        }
+        if let Some(n) = arg.strip_prefix('-') {
+            return Ok(TimeRange {
+                from: Some(days_ago(days(n)?)?),
+                to: None,
+            });
+        }
+        if !arg.is_empty() && arg.chars().all(|c| c.is_ascii_digit()) {
+            let n = days(arg)?;
+            return Ok(TimeRange {
+                from: Some(days_ago(n.saturating_add(1))?),
+                to: Some(days_ago(n)?),
+            });
+        }
+
+        let (first, last) = arg.split_once("..").unwrap_or((arg, arg));
+        if first.is_empty() && last.is_empty() {
+            return Err(format!("Invalid date range {arg}, at least one date is required"));
+        }
+        let from = if first.is_empty() { None } else { Some(start_of_day(first, 0)?) };
+        let to = if last.is_empty() { None } else { Some(start_of_day(last, 1)?) };
+        if let (Some(f), Some(t)) = (from, to)
+            && f >= t
+        {
+            return Err(format!("Invalid date range {arg}, first date is after last date"));
+        }
+        Ok(TimeRange { from, to })
+    }
+
+    pub fn contains(&self, t: SystemTime) -> bool {
+        self.from.is_none_or(|f| t >= f) && self.to.is_none_or(|to| t < to)
+    }
+}
+
+// Local midnight of date YYYY-MM-DD plus a number of days
+fn start_of_day(date: &str, add_days: u64) -> Result<SystemTime, String> {
+    let d = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| format!("Invalid date {date}, expecting YYYY-MM-DD"))?;
+    let d = d.checked_add_days(Days::new(add_days)).ok_or(format!("Invalid date {date}"))?;
+    let dt = d.and_time(NaiveTime::MIN).and_local_timezone(Local).earliest().ok_or(format!("Invalid local date {date}"))?;
+    Ok(SystemTime::from(dt))
+}
+
+#[derive(Debug)]
+pub struct FilterTime {
+    kind: TimeKind,
+    range: TimeRange,
+    arg: String,
+}
+
+impl FilterTime {
+    pub fn new(kind: TimeKind, arg: &str) -> Result<Self, String> {
+        Ok(FilterTime {
+            kind,
+            range: TimeRange::parse(arg, SystemTime::now())?,
+            arg: arg.to_string(),
+        })
+    }
+}
+
+impl Filter for FilterTime {
+    fn name(&self) -> String {
+        let what = match self.kind {
+            TimeKind::Modified => "modification",
+            TimeKind::Accessed => "access",
+            TimeKind::Created => "creation",
+        };
+        format!("{} {}: Select files and dirs by {what} time", self.kind.option(), self.arg)
+    }
+
+    fn filter(&mut self, _lw: &LogWriter, path: &Path, _verbose: bool) -> bool {
+        match fs::metadata(path).and_then(|md| self.kind.get(&md)) {
+            Ok(t) => self.range.contains(t),
+            Err(_) => false,
+        }
+    }
+}
+
+// ===============================================================
+// Regex filter, on full path
+
+#[derive(Debug)]
+pub struct FilterRegex {
+    re: Regex,
+}
+
+impl FilterRegex {
+    pub fn new(pattern: &str) -> Result<Self, String> {
+        let re = Regex::new(pattern).map_err(|e| format!("Invalid regex {pattern}: {e}"))?;
+        Ok(FilterRegex { re })
+    }
+}
+
+impl Filter for FilterRegex {
+    fn name(&self) -> String {
+        format!("regex: Select full paths matching {}", self.re.as_str())
+    }
+
+    fn filter(&mut self, _lw: &LogWriter, path: &Path, _verbose: bool) -> bool {
+        let full = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        self.re.is_match(&full.to_string_lossy())
+    }
+}
+
+// ===============================================================
+// Name filter, case-insensitive glob pattern on last component of path
+
+#[derive(Debug)]
+pub struct FilterIName {
+    segment: Segment,
+    pattern: String,
+}
+
+impl FilterIName {
+    pub fn new(pattern: &str) -> Result<Self, String> {
+        let mut segments = MyGlobBuilder::glob_to_segments(pattern, false).map_err(|e| format!("Invalid pattern {pattern}: {e}"))?;
+        if segments.len() != 1 || matches!(segments[0], Segment::Recurse) {
+            return Err(format!("Invalid pattern {pattern}, -iname pattern can't contain a path separator or **"));
+        }
+        Ok(FilterIName {
+            segment: segments.remove(0),
+            pattern: pattern.to_string(),
+        })
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        match &self.segment {
+            Segment::Constant(c) => c.to_lowercase() == name.to_lowercase(),
+            Segment::Filter(re) => re.is_match(name),
+            Segment::Recurse => false,
+        }
+    }
+}
+
+impl Filter for FilterIName {
+    fn name(&self) -> String {
+        format!("iname: Select names matching {}", self.pattern)
+    }
+
+    fn filter(&mut self, _lw: &LogWriter, path: &Path, _verbose: bool) -> bool {
+        match path.file_name() {
+            Some(name) => self.matches(&name.to_string_lossy()),
+            None => false,
+        }
+    }
+}
+
+// ===============================================================
+// Contains filter, regex on content of text files, decoded with TextAutoDecode
+
+#[derive(Debug)]
+pub struct FilterContains {
+    re: Regex,
+    pattern: String,
+    ignore_case: bool,
+    whole_word: bool,
+}
+
+impl FilterContains {
+    pub fn new(pattern: &str, ignore_case: bool, whole_word: bool) -> Result<Self, String> {
+        let spat = if whole_word {
+            format!("\\b(?:{pattern})\\b")
+        } else {
+            pattern.to_string()
+        };
+        // Multi-line mode, ^ and $ match begin and end of lines, with \r\n line terminators
+        let re = RegexBuilder::new(&spat)
+            .case_insensitive(ignore_case)
+            .multi_line(true)
+            .crlf(true)
+            .build()
+            .map_err(|e| format!("Invalid regex {pattern}: {e}"))?;
+        Ok(FilterContains {
+            re,
+            pattern: pattern.to_string(),
+            ignore_case,
+            whole_word,
+        })
+    }
+
+    pub fn matches(&self, text: &str) -> bool {
+        self.re.is_match(text)
+    }
+}
+
+impl Filter for FilterContains {
+    fn name(&self) -> String {
+        let mut modes = Vec::new();
+        if self.ignore_case {
+            modes.push("case-insensitive");
+        }
+        if self.whole_word {
+            modes.push("whole word");
+        }
+        let modes = if modes.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", modes.join(", "))
+        };
+        format!("contains: Select text files containing {}{modes}", self.pattern)
+    }
+
+    // Directories and non-text files are not selected
+    fn filter(&mut self, lw: &LogWriter, path: &Path, verbose: bool) -> bool {
+        if !path.is_file() {
+            return false;
+        }
+        match TextAutoDecode::read_text_file(path) {
+            Ok(tad) => {
+                if tad.encoding == TextFileEncoding::NotText {
+                    if verbose {
+                        logln(lw, format!("Ignored non-text file {}", path.display()).as_str());
+                    }
+                    return false;
+                }
+                tad.text.is_some_and(|t| self.matches(&t))
+            }
+            Err(e) => {
+                logln(lw, format!("*** Error reading file {}: {}", path.display(), e).as_str());
+                false
+            }
+        }
+    }
+}
+
+// ===============================================================
+// Boolean operators, built by filter_expr parser
+
+#[derive(Debug)]
+pub struct FilterNot {
+    filter: Box<dyn Filter>,
+}
+
+impl FilterNot {
+    pub fn new(filter: Box<dyn Filter>) -> Self {
+        FilterNot { filter }
+    }
+}
+
+impl Filter for FilterNot {
+    fn name(&self) -> String {
+        format!("-not {}", self.filter.name())
+    }
+
+    fn filter(&mut self, lw: &LogWriter, path: &Path, verbose: bool) -> bool {
+        !self.filter.filter(lw, path, verbose)
+    }
+}
+
+/// All filters must match, evaluation stops at first filter that doesn't match
+#[derive(Debug)]
+pub struct FilterAnd {
+    filters: Vec<Box<dyn Filter>>,
+}
+
+impl FilterAnd {
+    pub fn new(filters: Vec<Box<dyn Filter>>) -> Self {
+        FilterAnd { filters }
+    }
+}
+
+impl Filter for FilterAnd {
+    fn name(&self) -> String {
+        let names: Vec<String> = self.filters.iter().map(|f| f.name()).collect();
+        format!("({})", names.join(" -and "))
+    }
+
+    fn filter(&mut self, lw: &LogWriter, path: &Path, verbose: bool) -> bool {
+        self.filters.iter_mut().all(|f| f.filter(lw, path, verbose))
+    }
+}
+
+/// At least one filter must match, evaluation stops at first filter that matches
+#[derive(Debug)]
+pub struct FilterOr {
+    filters: Vec<Box<dyn Filter>>,
+}
+
+impl FilterOr {
+    pub fn new(filters: Vec<Box<dyn Filter>>) -> Self {
+        FilterOr { filters }
+    }
+}
+
+impl Filter for FilterOr {
+    fn name(&self) -> String {
+        let names: Vec<String> = self.filters.iter().map(|f| f.name()).collect();
+        format!("({})", names.join(" -or "))
+    }
+
+    fn filter(&mut self, lw: &LogWriter, path: &Path, verbose: bool) -> bool {
+        self.filters.iter_mut().any(|f| f.filter(lw, path, verbose))
+    }
+}
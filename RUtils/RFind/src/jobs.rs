@@ -0,0 +1,125 @@
+// jobs.rs, bounded pool of worker threads running -exec commands in parallel
+//
+// 2026-10-19   PV      First version
+
+// Standard library imports
+use std::process::{Command, ExitStatus};
+use std::sync::mpsc::{Receiver, SyncSender, channel, sync_channel};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+// Application imports
+use crate::command_to_run::CommandToRun;
+
+/// A command to run for a path
+#[derive(Debug)]
+struct Job {
+    path: String,
+    ctr: CommandToRun,
+}
+
+/// Result of a job: exit status or launch error, and captured output if it has been requested
+#[derive(Debug)]
+pub struct JobResult {
+    pub path: String,
+    pub command: String,
+    pub status: Result<ExitStatus, String>,
+    pub output: Option<String>,
+}
+
+impl JobResult {
+    pub fn success(&self) -> bool {
+        matches!(&self.status, Ok(s) if s.success())
+    }
+}
+
+/// Pool of workers running at most jobs commands at the same time. Submitting a job blocks while all workers are busy
+/// and the queue is full, so the number of pending commands remains bounded
+#[derive(Debug)]
+pub struct JobPool {
+    sender: Option<SyncSender<Job>>,
+    results: Receiver<JobResult>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl JobPool {
+    /// With capture_output, stdout and stderr of commands are returned in JobResult, otherwise they're inherited
+    pub fn new(jobs: usize, capture_output: bool) -> Self {
+        let (sender, receiver) = sync_channel::<Job>(jobs);
+        let receiver = Arc::new(Mutex::new(receiver));
+        let (results_sender, results) = channel::<JobResult>();
+
+        let workers = (0..jobs)
+            .map(|_| {
+                let receiver = Arc::clone(&receiver);
+                let results_sender = results_sender.clone();
+                thread::spawn(move || {
+                    loop {
+                        // Lock is released as soon as a job is received
+                        let job = receiver.lock().unwrap().recv();
+                        let Ok(job) = job else {
+                            break;
+                        };
+                        if results_sender.send(job.run(capture_output)).is_err() {
+                            break;
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        JobPool {
+            sender: Some(sender),
+            results,
+            workers,
+        }
+    }
+
+    pub fn submit(&self, path: String, ctr: CommandToRun) {
+        if let Some(sender) = &self.sender {
+            // Only fails if all workers have stopped, which doesn't happen while sender exists
+            let _ = sender.send(Job { path, ctr });
+        }
+    }
+
+    /// Results of jobs terminated so far, without waiting
+    pub fn terminated(&self) -> Vec<JobResult> {
+        self.results.try_iter().collect()
+    }
+
+    /// Waits for all jobs to terminate, and returns remaining results
+    pub fn finish(&mut self) -> Vec<JobResult> {
+        self.sender = None;
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+        self.terminated()
+    }
+}
+
+impl Job {
+    fn run(self, capture_output: bool) -> JobResult {
+        let mut command = Command::new(&self.ctr.command);
+        command.args(&self.ctr.args);
+
+        let (status, output) = if capture_output {
+            match command.output() {
+                Ok(o) => {
+                    let mut text = String::from_utf8_lossy(&o.stdout).to_string();
+                    text += &String::from_utf8_lossy(&o.stderr);
+                    (Ok(o.status), Some(text))
+                }
+                Err(e) => (Err(e.to_string()), None),
+            }
+        } else {
+            (command.status().map_err(|e| e.to_string()), None)
+        };
+
+        JobResult {
+            path: self.path,
+            command: self.ctr.command,
+            status,
+            output,
+        }
+    }
+}
@@ -34,13 +34,31 @@
 // 2025-11-15   PV      2.6.0 Option -w to make actions -exec/-execg synchronous (wait for command to terminate)
 // 2025-11-13   PV      3.0.0 Refactoring of all MyGlob options with -glob opp[,opt]... mygloboptions.ts not moved yet to MyGlob crate
 // 2025-11-16   PV      3.0.1 Moved MyGlobCLOptions to MyGlob crate; flag old MyGlob options as deprecated (but they still work)
+// 2026-10-19   PV      3.1.1 With option -dbg, MyGlob diagnostics are shown through Logging log facade
+// 2026-10-19   PV      Logging 2.0: LogWriter passed by shared reference
+// 2026-10-19   PV      3.2.0 Counters, spans and final summary provided by Logging
+// 2026-10-19   PV      3.3.0 Options help, version and defaults from config file or RFIND_OPTIONS with RUtilsOptions crate
+// 2026-10-19   PV      3.4.0 Shell completion scripts with option --completions, including -glob values
+// 2026-10-19   PV      3.5.0 Filters -size, -newer, -mtime/-atime/-ctime, -regex and -iname
+// 2026-10-19   PV      3.6.0 Filter expressions with -not, -and, -or and parentheses
+// 2026-10-19   PV      3.7.0 Action -rename with sed-style substitution and undo journal
+// 2026-10-19   PV      3.8.0 Actions -copy, -move, -hardlink and -symlink with collision policy
+// 2026-10-19   PV      3.9.0 Machine-readable output with actions -json, -jsonl, -csv and -print0
+// 2026-10-19   PV      3.10.0 Action -printf with custom output template
+// 2026-10-19   PV      3.11.0 Option -j to run -exec commands in parallel, option -tag to prefix their output with path
+// 2026-10-19   PV      3.12.0 Placeholders {/}, {//}, {.}, {/.}, {ext} and {rel} for -exec/-execg
+// 2026-10-19   PV      3.13.0 Filter -contains on text content with TextAutoDecode, case-insensitive and whole word variants
+// 2026-10-19   PV      3.14.0 Options -ok/-i to confirm each deletion or command, -confirm to confirm all deletions at once, -max-delete
+// 2026-10-19   PV      3.15.0 Profiles run with @name, saved with option -save
+// 2026-10-19   PV      3.15.1 Counters of paths to rename with -n are per file and dir, as for actual renames
+// 2026-10-19   PV      3.15.2 Option -w can't be combined with -j, it was silently ignored
+// 2026-10-19   PV      3.15.3 Limit of -max-delete applies to deletions of -delete and -rmdir together
 
 // Notes:
 // - Finding denormalized paths is handled by rcheckfiles and checknnn, no need for a third version :-)
 
 // ToDo:
 // - Accent insensitive search (actually maybe not useful, but everything does it)
-// - Option -rename with a simplified sed syntax
 
 #![allow(unused)]
 
@@ -49,10 +67,9 @@ use std::fmt::Debug;
 use std::fs;
 use std::path::Path;
 use std::process;
-use std::time::Instant;
 
 // External crates imports
-use logging::{LogWriter, log, logln, logwriter_none};
+use logging::{LevelFilter, LogWriter, json_string, log, logln, logwriter_none, s};
 use myglob::{MyGlobMatch, MyGlobSearch, MyGlobCLOptions};
 use windows as _;
 
@@ -61,9 +78,15 @@ use windows as _;
 
 mod actions;
 mod command_to_run;
+mod confirm;
 mod fa_streams;
+mod filter_expr;
 mod filters;
+mod jobs;
 mod options;
+mod path_info;
+mod printf;
+mod substitution;
 
 mod tests;
 
@@ -71,7 +94,13 @@ mod tests;
 // Modules
 
 use command_to_run::*;
+use confirm::*;
+use filter_expr::*;
+use jobs::*;
 use options::*;
+use path_info::*;
+use printf::*;
+use substitution::*;
 
 // -----------------------------------
 // Global constants
@@ -85,13 +114,24 @@ const APP_DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
 
 trait Action: Debug {
     fn name(&self) -> String;
-    fn action(&mut self, lw: &mut LogWriter, path: &Path, options: &Options);
-    fn conclusion(&mut self, lw: &mut LogWriter, options: &Options);
+    fn action(&mut self, lw: &LogWriter, path: &Path, options: &Options);
+    fn conclusion(&mut self, lw: &LogWriter, options: &Options);
+
+    /// Called with the root of each glob before it's explored, for actions working on paths relative to it
+    fn set_root(&mut self, _root: &Path) {}
+
+    /// Number of deletions deferred to conclusion, checked against -max-delete before any conclusion is called
+    fn pending_deletions(&self) -> usize {
+        0
+    }
+
+    /// Drops deletions deferred to conclusion, nothing is deleted
+    fn cancel_deletions(&mut self) {}
 }
 
 trait Filter: Debug {
-    fn name(&self) -> &'static str;
-    fn filter(&mut self, lw: &mut LogWriter, path: &Path, verbose: bool) -> bool;
+    fn name(&self) -> String;
+    fn filter(&mut self, lw: &LogWriter, path: &Path, verbose: bool) -> bool;
 }
 
 // ==============================================================================================
@@ -105,20 +145,21 @@ fn main() {
             process::exit(0);
         }
         logln(
-            &mut logwriter_none(),
+            &logwriter_none(),
             format!("*** {APP_NAME}: Problem parsing arguments: {}", err).as_str(),
         );
         process::exit(1);
     });
 
     // Prepare log writer
-    let mut writer = if options.log {
+    let writer = if options.log {
         logging::new(APP_NAME, APP_VERSION, options.verbose)
     } else {
         logwriter_none()
     };
-
-    let start = Instant::now();
+    if options.debug {
+        let _ = writer.install_log_facade(LevelFilter::Debug);
+    }
 
     // Adjust sources if option -name is used (for compatibility with XFind/Search)
     // In this case, appends \**\name to each source that is a valid directory
@@ -157,95 +198,107 @@ fn main() {
         match resgs {
             Ok(gs) => {
                 if options.debug {
-                    logln(&mut writer, format!("dbg: {} -> {:?}", source, gs.segments).as_str());
+                    logln(&writer, format!("dbg: {} -> {:?}", source, gs.segments).as_str());
                 }
                 sources.push((source, gs));
             }
             Err(e) => {
-                logln(&mut writer, format!("*** Error building MyGlob: {:?}", e).as_str());
+                logln(&writer, format!("*** Error building MyGlob: {:?}", e).as_str());
             }
         }
     }
     if sources.is_empty() {
-        Options::header();
+        Options::spec().header();
         eprintln!("\nNo source/pattern specified.\nUse {APP_NAME} ? to show options or {APP_NAME} ?? for advanced usage notes.");
         process::exit(0);
     }
 
     if options.verbose {
-        log(&mut writer, "\nSources(s): ");
+        log(&writer, "\nSources(s): ");
         if options.search_dirs && options.search_files {
-            logln(&mut writer, "(search for files and directories)");
+            logln(&writer, "(search for files and directories)");
         } else if options.search_dirs {
-            logln(&mut writer, "(search for directories)");
+            logln(&writer, "(search for directories)");
         } else {
-            logln(&mut writer, "(search for files)");
+            logln(&writer, "(search for files)");
         }
 
         for source in sources.iter() {
-            logln(&mut writer, format!("- {}", source.0).as_str());
+            logln(&writer, format!("- {}", source.0).as_str());
         }
     }
 
-    let mut filters = Vec::<Box<dyn Filter>>::new();
-    for &filter_name in options.filters_names.iter() {
-        match filter_name {
-            "empty" => filters.push(Box::new(filters::FilterEmpty::new())),
-            "ads" => filters.push(Box::new(filters::FilterADS::new(false))),
-            "adsx" => filters.push(Box::new(filters::FilterADS::new(true))),
-            _ => panic!("{APP_NAME}: Internal error, unknown filter_name {filter_name}"),
-        }
-    }
+    // Filter expression is built while parsing options
+    let mut filter = options.filter.take();
 
     let mut actions = Vec::<Box<dyn Action>>::new();
     for action_name in options.actions_names.iter() {
         match *action_name {
             "print" => {
                 if options.actions_names.contains("dir") {
-                    logln(&mut writer, "*** Both actions print and dir used, action print ignored.");
+                    logln(&writer, "*** Both actions print and dir used, action print ignored.");
                 } else {
                     actions.push(Box::new(actions::ActionPrint::new()));
                 }
             }
             "yaml" => actions.push(Box::new(actions::ActionYaml::new())),
+            "json" => actions.push(Box::new(actions::ActionRecord::new(actions::RecordFormat::Json))),
+            "jsonl" => actions.push(Box::new(actions::ActionRecord::new(actions::RecordFormat::Jsonl))),
+            "csv" => actions.push(Box::new(actions::ActionRecord::new(actions::RecordFormat::Csv))),
+            "print0" => actions.push(Box::new(actions::ActionPrint0::new())),
+            "printf" => actions.push(Box::new(actions::ActionPrintf::new(options.printf.as_ref().unwrap()))),
             "dir" => actions.push(Box::new(actions::ActionDir::new())),
             "delete" => actions.push(Box::new(actions::ActionDelete::new(options.recycle))),
             "rmdir" => actions.push(Box::new(actions::ActionRmdir::new(options.recycle))),
+            "rename" => actions.push(Box::new(actions::ActionRename::new(
+                options.rename.as_ref().unwrap(),
+                options.journal.clone(),
+            ))),
             "nop" => {}
             _ => panic!("{APP_NAME}: Internal error, unknown action_name {action_name}"),
         }
     }
     for ctr in options.exec_commands.iter() {
-        actions.push(Box::new(actions::ActionExec::new(ctr)));
+        actions.push(Box::new(actions::ActionExec::new(ctr, options.jobs, options.tag)));
     }
     for ctr in options.execg_commands.iter() {
         actions.push(Box::new(actions::ActionExecg::new(ctr)));
     }
+    for (kind, dest) in options.transfers.iter() {
+        actions.push(Box::new(actions::ActionTransfer::new(*kind, dest, options.collision)));
+    }
 
     if options.verbose {
-        log(&mut writer, "\nAction(s): ");
+        log(&writer, "\nAction(s): ");
         if options.noaction {
-            logln(&mut writer, "(no action will be actually performed)");
+            logln(&writer, "(no action will be actually performed)");
         } else {
-            logln(&mut writer, "");
+            logln(&writer, "");
         }
         for ba in actions.iter() {
-            logln(&mut writer, format!("- {}", (**ba).name()).as_str());
+            logln(&writer, format!("- {}", (**ba).name()).as_str());
         }
 
-        if !filters.is_empty() {
-            logln(&mut writer, "\nFilter(s): ");
-            for ba in filters.iter() {
-                logln(&mut writer, format!("- {}", (**ba).name()).as_str());
-            }
+        if let Some(f) = &filter {
+            logln(&writer, "\nFilter: ");
+            logln(&writer, format!("- {}", f.name()).as_str());
         }
-        logln(&mut writer, "");
+        logln(&writer, "");
     }
 
-    let mut files_count = 0;
-    let mut dirs_count = 0;
-    let mut errs_count = 0;
-    for gs in sources.iter() {
+    // Counters reported in summary even if nothing is found
+    if options.search_files {
+        writer.add_count("file", 0);
+    }
+    if options.search_dirs {
+        writer.add_count("dir", 0);
+    }
+
+    let search_span = writer.span("search");
+    'search: for gs in sources.iter() {
+        for ba in actions.iter_mut() {
+            (**ba).set_root(Path::new(gs.1.root()));
+        }
         for ma in gs.1.explore_iter() {
             match ma {
                 MyGlobMatch::File(pb) => {
@@ -253,17 +306,17 @@ fn main() {
                         continue;
                     }
 
-                    let mut include = true;
-                    for f in filters.iter_mut() {
-                        if !(**f).filter(&mut writer, &pb, options.verbose) {
-                            include = false;
-                            break;
-                        }
-                    }
+                    let include = match filter.as_mut() {
+                        Some(f) => f.filter(&writer, &pb, options.verbose),
+                        None => true,
+                    };
                     if include {
-                        files_count += 1;
+                        writer.count("file");
                         for ba in actions.iter_mut() {
-                            (**ba).action(&mut writer, &pb, &options);
+                            (**ba).action(&writer, &pb, &options);
+                        }
+                        if quit_requested() {
+                            break 'search;
                         }
                     }
                 }
@@ -273,68 +326,55 @@ fn main() {
                         continue;
                     }
 
-                    let mut include = true;
-                    for f in filters.iter_mut() {
-                        if !(**f).filter(&mut writer, &pb, options.verbose) {
-                            include = false;
-                            break;
-                        }
-                    }
+                    let include = match filter.as_mut() {
+                        Some(f) => f.filter(&writer, &pb, options.verbose),
+                        None => true,
+                    };
                     if include {
-                        dirs_count += 1;
+                        writer.count("dir");
                         for ba in actions.iter_mut() {
-                            (**ba).action(&mut writer, &pb, &options);
+                            (**ba).action(&writer, &pb, &options);
+                        }
+                        if quit_requested() {
+                            break 'search;
                         }
                     }
                 }
 
                 MyGlobMatch::Error(err) => {
-                    errs_count += 1;
+                    writer.count_error();
                     if options.verbose {
-                        logln(&mut writer, format!("{APP_NAME}: MyGlobMatch error {}", err).as_str());
+                        logln(&writer, format!("{APP_NAME}: MyGlobMatch error {}", err).as_str());
                     }
                 }
             }
         }
     }
 
-    // Call conclusions
-    for ba in actions.iter_mut() {
-        (**ba).conclusion(&mut writer, &options);
-    }
-
-    let duration = start.elapsed();
+    drop(search_span);
 
-    fn s(n: i32) -> &'static str {
-        if n > 1 { "s" } else { "" }
-    }
-
-    if options.verbose {
-        let mut msg = String::new();
-
-        if files_count + dirs_count == 0 {
-            msg.push_str("No match");
-        } else {
-            if options.search_files {
-                if !msg.is_empty() {
-                    msg.push_str(", ");
-                }
-                msg.push_str(format!("{files_count} file{}", s(files_count)).as_str());
-            }
-            if options.search_dirs {
-                if !msg.is_empty() {
-                    msg.push_str(", ");
-                }
-                msg.push_str(format!("{dirs_count} dir{}", s(dirs_count)).as_str());
+    // Limit of -max-delete is for all deletions of -delete and -rmdir, nothing is deleted if it's exceeded
+    if let Some(max) = options.max_delete {
+        let n: usize = actions.iter().map(|ba| ba.pending_deletions()).sum();
+        if n > max {
+            let msg = format!("*** {n} path{} would be deleted, more than -max-delete {max}, nothing deleted", s(n));
+            logln(&writer, msg.as_str());
+            for ba in actions.iter_mut() {
+                ba.cancel_deletions();
             }
         }
+    }
 
-        if errs_count > 0 {
-            if !msg.is_empty() {
-                msg.push_str(", ");
-            }
-            msg.push_str(format!("{errs_count} error{}", s(errs_count)).as_str());
+    // Call conclusions
+    {
+        let _span = writer.span("conclusion");
+        for ba in actions.iter_mut() {
+            (**ba).conclusion(&writer, &options);
         }
-        logln(&mut writer, format!("\n{msg} found in {:.3}s", duration.as_secs_f64()).as_str());
+    }
+
+    if options.verbose {
+        logln(&writer, "");
+        writer.log_summary();
     }
 }
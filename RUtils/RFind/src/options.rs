@@ -18,6 +18,22 @@
 // 2025-10-29   PV      Added {} final for -exec/-execg if there is no {} in command
 // 2025-11-15   PV      -w to make actions -exec/-execg synchronous
 // 2025-11-16   PV      Grouped all MyGlob options into mgclo: GlobCLOptions; Use MyGlob to parse these options
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Help, version and default options processed by RUtilsOptions
+// 2026-10-19   PV      Values of -glob option for shell completion
+// 2026-10-19   PV      Filters -size, -newer, -mtime/-atime/-ctime, -regex and -iname; filters built during parsing
+// 2026-10-19   PV      Filter expressions with -not, -and, -or and parentheses
+// 2026-10-19   PV      Option -rename and -journal
+// 2026-10-19   PV      Options -copy, -move, -hardlink, -symlink and -collision
+// 2026-10-19   PV      Options -json, -jsonl, -csv and -print0
+// 2026-10-19   PV      Option -printf
+// 2026-10-19   PV      Options -j and -tag for parallel -exec
+// 2026-10-19   PV      Placeholders {/}, {//}, {.}, {/.}, {ext} and {rel} for -exec/-execg
+// 2026-10-19   PV      Filters -contains, -icontains, -wcontains and -iwcontains
+// 2026-10-19   PV      Options -ok/-i, -confirm and -max-delete
+// 2026-10-19   PV      Profiles run with @name, option -save
+// 2026-10-19   PV      Option -w rejected with option -j
+// 2026-10-19   PV      Option -max-delete counts deletions of -delete and -rmdir together
 
 // Application imports
 use crate::*;
@@ -29,109 +45,245 @@ use std::fmt::Debug;
 
 // External crates imports
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, SAVE_OPTION, ToolSpec, is_valid_profile_name};
+use textautodecode::TextAutoDecode;
 
 // Dedicated struct to store command line arguments
 #[derive(Debug, Default)]
 pub struct Options {
     pub sources: Vec<String>,
     pub actions_names: HashSet<&'static str>,
-    pub filters_names: HashSet<&'static str>,
+    pub filter: Option<Box<dyn Filter>>,
     pub exec_commands: Vec<CommandToRun>,
     pub execg_commands: Vec<CommandToRun>,
+    pub rename: Option<Substitution>,
+    pub journal: Option<String>,
+    pub transfers: Vec<(actions::TransferKind, String)>,
+    pub collision: actions::Collision,
+    pub printf: Option<Template>,
     pub search_files: bool,
     pub search_dirs: bool,
     pub names: Vec<String>,
     pub recycle: bool,
     pub mgclo: MyGlobCLOptions,
     pub noaction: bool,
+    pub confirm: ConfirmMode,
+    pub max_delete: Option<usize>,
+    pub save: Option<String>,
     pub syncronous_exec: bool,
+    pub jobs: usize, // 0 without option -j
+    pub tag: bool,
     pub verbose: bool,
     pub debug: bool,
     pub log: bool,
 }
 
-impl Options {
-    pub fn header() {
-        println!("{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}");
-    }
-
-    fn header_copyright() {
-        Self::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-    }
+const OPTIONS: [OptionDef; 13] = [
+    OptionDef::flag(&["-v"], "Verbose output"),
+    OptionDef::flag(
+        &["-w"],
+        "Actions ⦃-exec⦄/⦃-execg⦄ are synchronous (wait for command execution to terminate before continuing), default is asynchronous",
+    ),
+    OptionDef::flag(&["-j"], "Run up to ⟨n⟩ ⦃-exec⦄ commands in parallel, wait for them at the end and report failures").arg("n"),
+    OptionDef::flag(&["-tag"], "With ⦃-j⦄, prefix each line of commands output with the path"),
+    OptionDef::flag(&["-n"], "No action: display actions, but don't execute them"),
+    OptionDef::flag(&["-ok", "-i"], "Ask before each deletion of ⦃-delete⦄/⦃-rmdir⦄ and each command of ⦃-exec⦄: ⟦y⟧es, ⟦n⟧o, ⟦a⟧ll or ⟦q⟧uit"),
+    OptionDef::flag(&["-confirm"], "List all deletions of ⦃-delete⦄/⦃-rmdir⦄ with total size at the end of the search, then ask once before deleting"),
+    OptionDef::flag(&["-max-delete"], "Delete nothing if more than ⟨n⟩ files and directories of ⦃-delete⦄ and ⦃-rmdir⦄ would be deleted").arg("n"),
+    OptionDef::flag(&["-r+", "-r-"], "Delete to recycle bin (default) or delete forever; Recycle bin is not allowed on network sources"),
+    OptionDef::flag(
+        &["-collision"],
+        "When target of ⦃-copy⦄, ⦃-move⦄, ⦃-hardlink⦄ or ⦃-symlink⦄ exists: ⟦skip⟧ (default), ⟦overwrite⟧, or ⟦rename⟧ adding a suffix (1), (2)...",
+    )
+    .arg("policy")
+    .values(&actions::Collision::NAMES),
+    OptionDef::flag(&["-glob"], "Globbing specific options (see extended help)").arg("opt[,opt]…").list_values(&MyGlobCLOptions::VALUES),
+    SAVE_OPTION,
+    OptionDef::param(&["source"], "File or directory to search (glob pattern)"),
+];
+
+const FILTERS: [OptionDef; 12] = [
+    OptionDef::flag(&["-f", "-type f"], "Search for files"),
+    OptionDef::flag(&["-d", "-type d"], "Search for directories"),
+    OptionDef::flag(&["-e", "-empty"], "Only find empty files or directories"),
+    OptionDef::flag(&["-ads"], "Select files with alternate data streams"),
+    OptionDef::flag(
+        &["-adsx"],
+        "Select files with alternate data streams of 2KB or more (typically ignore Zone.identification, AFP_Resource, ms-properties...)",
+    ),
+    OptionDef::flag(&["-name"], "Append ⟦/**/⟨name⟩⟧ to each source directory (compatibility with XFind/Search)").arg("name"),
+    OptionDef::flag(&["-iname"], "Select names (last path component) matching glob ⟨pattern⟩, case-insensitive").arg("pattern"),
+    OptionDef::flag(
+        &["-contains", "-icontains", "-wcontains", "-iwcontains"],
+        "Select text files whose content matches regular expression ⟨regex⟩; with ⟦i⟧ case-insensitive, with ⟦w⟧ whole words only",
+    )
+    .arg("regex"),
+    OptionDef::flag(&["-regex"], "Select full paths matching regular expression ⟨regex⟩ (not anchored, use ^ and $)").arg("regex"),
+    OptionDef::flag(&["-size"], "Select files of more (+), less (-) or exactly ⟨n⟩ bytes, units k, M, G and T are powers of 1024")
+        .arg("[+|-]n[k|M|G|T]"),
+    OptionDef::flag(&["-newer"], "Select files and dirs modified more recently than ⟨file⟩").arg("file"),
+    OptionDef::flag(
+        &["-mtime", "-atime", "-ctime"],
+        "Select on modification, access or creation time: ⦃+n⦄ more than n days ago, ⦃-n⦄ less than n days ago, ⦃n⦄ n days ago, or date range (see extended help)",
+    )
+    .arg("range"),
+];
+
+const OPERATORS: [OptionDef; 4] = [
+    OptionDef::flag(&["-not", "!"], "Select paths that don't match following filter"),
+    OptionDef::flag(&["-and", "-a"], "Select paths matching both filters, default between two filters"),
+    OptionDef::flag(&["-or", "-o"], "Select paths matching either filter"),
+    OptionDef::flag(&["(", ")"], "Group filters"),
+];
+
+const ACTIONS: [OptionDef; 19] = [
+    OptionDef::flag(&["-print"], "Default, print matching files names and dir names"),
+    OptionDef::flag(&["-dir"], "Variant of ⦃-print⦄, with last modification date and size"),
+    OptionDef::flag(&["-nop[rint]"], "Do nothing, useful to replace default action ⦃-print⦄ to count files and folders with option ⦃-v⦄"),
+    OptionDef::flag(&["-delete"], "Delete matching files"),
+    OptionDef::flag(&["-rmdir"], "Delete matching directories, whether empty or not"),
+    OptionDef::flag(
+        &["-exec"],
+        "Execute command ⟨cmd⟩ for each path found, {} replaced by the path or added at the end, other placeholders in extended help. A single semicolon ⦃;⦄ marks the end of the command",
+    )
+    .arg("cmd"),
+    OptionDef::flag(
+        &["-execg"],
+        "Execute grouped command ⟨cmd⟩ at the end, {} replaced by all the paths found, other placeholders in extended help. A single semicolon ⦃;⦄ marks the end of the command",
+    )
+    .arg("cmd"),
+    OptionDef::flag(&["-yaml"], "Generate old/new yaml data for matches, to be edited and used by rcheckfiles -F"),
+    OptionDef::flag(&["-json"], "Output matches as a JSON array of objects (see extended help for fields)"),
+    OptionDef::flag(&["-jsonl"], "Output matches as JSON Lines, one object per line"),
+    OptionDef::flag(&["-csv"], "Output matches as CSV with a header line"),
+    OptionDef::flag(&["-printf"], "Print matches using ⟨template⟩ with %-directives such as %P, %f or %t{%Y-%m-%d} (see extended help)").arg("template"),
+    OptionDef::flag(&["-print0"], "Print matching paths terminated by a NUL character instead of a newline, for ⟦xargs -0⟧"),
+    OptionDef::flag(
+        &["-rename"],
+        "Rename matches applying sed-style substitution ⟨s/regex/repl/flags⟩ to their name (see extended help). Planned renames are listed first, collisions are refused",
+    )
+    .arg("s/regex/repl/flags"),
+    OptionDef::flag(&["-journal"], "With ⦃-rename⦄, write undo journal ⟨file⟩, undo renames with ⟦rcheckfiles -F ⟨file⟩⟧").arg("file"),
+    OptionDef::flag(&["-copy"], "Copy matching files to ⟨dest⟩, keeping their path relative to glob root").arg("dest"),
+    OptionDef::flag(&["-move"], "Move matching files to ⟨dest⟩, keeping their path relative to glob root").arg("dest"),
+    OptionDef::flag(&["-hardlink"], "Create hard links to matching files in ⟨dest⟩, keeping their path relative to glob root").arg("dest"),
+    OptionDef::flag(&["-symlink"], "Create symbolic links to matching files in ⟨dest⟩, keeping their path relative to glob root").arg("dest"),
+];
+
+// Machine-readable output actions
+const OUTPUT_FORMATS: [&str; 5] = ["json", "jsonl", "csv", "print0", "printf"];
+
+const ADVANCED_OPTIONS: [OptionDef; 2] = [
+    OptionDef::flag(&["-dbg"], "Debug mode, show internal dev information"),
+    OptionDef::flag(&["-log"], "Write log file in temp folder"),
+];
 
-    fn usage() {
-        Options::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} [⟨option⟩...] [⟨filter⟩...] [⟨action⟩...] ⟨source⟩...
-
-⌊Options⌋:
-⦃?⦄|⦃-?⦄|⦃help⦄|⦃-help⦄  ¬Show this message
-⦃??⦄|⦃-??⦄|⦃--help⦄    ¬Show advanced usage notes
-⦃-v⦄               ¬Verbose output
-⦃-w⦄               ¬Actions ⦃-exec⦄/⦃-execg⦄ are synchronous (wait for command execution to terminate before continuing), default is asynchronous
-⦃-n⦄               ¬No action: display actions, but don't execute them
-⦃-r+⦄|⦃-r-⦄          ¬Delete to recycle bin (default) or delete forever; Recycle bin is not allowed on network sources
-⦃-glob opt⟩[,⟨opt⟩]⦄… ¬Globbing specific options (see extended help)
-
-⟨source⟩           ¬File or directory to search (glob pattern)
-
-⌊Filters⌋:
-⦃-f⦄|⦃-type f⦄       ¬Search for files
-⦃-d⦄|⦃-type d⦄       ¬Search for directories
-⦃-e⦄|⦃-empty⦄        ¬Only find empty files or directories
-⦃-ads⦄             ¬Select files with alternate data streams
-⦃-adsx⦄            ¬Select files with alternate data streams of 2KB or more (typically ignore Zone.identification, AFP_Resource, ms-properties...)
-⦃-name⦄ ⟨name⟩       ¬Append ⟦/**/⟨name⟩⟧ to each source directory (compatibility with XFind/Search)
-
-⌊Actions⌋:
-⦃-print⦄           ¬Default, print matching files names and dir names
-⦃-dir⦄             ¬Variant of ⦃-print⦄, with last modification date and size
-⦃-nop[rint]⦄       ¬Do nothing, useful to replace default action ⦃-print⦄ to count files and folders with option ⦃-v⦄
-⦃-delete⦄          ¬Delete matching files
-⦃-rmdir⦄           ¬Delete matching directories, whether empty or not
-⦃-exec⦄ ⟨cmd⟩ [⦃;⦄]    ¬Execute command ⟨cmd⟩ for each path found, {} replaced by the path or added at the end. A single semicolon marks the end of the command
-⦃-execg⦄ ⟨cmd⟩ [⦃;⦄]   ¬Execute grouped command ⟨cmd⟩ at the end, {} replaced by all the paths found or added at the end. A single semicolon marks the end of the command
-⦃-yaml⦄            ¬Generate old/new yaml data for matches, to be edited and used by rcheckfiles -F";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl Options {
+    pub fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⟨option⟩...] [⟨filter⟩...] [⟨action⟩...] ⟨source⟩...")
+            .group("Options", &OPTIONS)
+            .group("Filters", &FILTERS)
+            .group("Filter operators", &OPERATORS)
+            .group("Actions", &ACTIONS)
+            .extended_group("Advanced options", &ADVANCED_OPTIONS)
+            .profiles()
     }
 
     fn extended_usage() {
-        Self::header_copyright();
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        println!("- MyGlob: {}", MyGlobSearch::version());
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- Logging: {}", logging::version());
-        println!("- trash: {}", env!("DEP_TRASH_VERSION"));
-        println!("- chrono: {}", env!("DEP_CHRONO_VERSION"));
-        println!("- num-format: {}", env!("DEP_NUM_FORMAT_VERSION"));
-        println!();
-
-        let text = "⟪⌊Advanced usage notes⌋⟫
-
-⌊Advanced options⌋:
-⦃-dbg⦄       ¬Debug mode, show internal dev information
-⦃-log⦄       ¬Write log file in temp folder\n\n"
-            .to_string()
-            + MyGlobCLOptions::options()
+        let text = MyGlobCLOptions::options().to_string()
             + "
-            
+
+⌊Date ranges⌋:
+Options ⦃-mtime⦄, ⦃-atime⦄ and ⦃-ctime⦄ accept a number of days or dates in format ⟦YYYY-MM-DD⟧, local time:
+- ¬⟦+n⟧: more than n full days ago; ⟦-n⟧: less than n days ago; ⟦n⟧: between n and n+1 days ago
+- ¬⟦2025-01-01⟧: this day only
+- ¬⟦2025-01-01..2025-06-30⟧: from first day to last day included
+- ¬⟦2025-01-01..⟧ or ⟦..2025-06-30⟧: since first day, or until last day included
+
+⌊Filter expressions⌋:
+Filters can be combined with operators ⦃-not⦄, ⦃-and⦄ and ⦃-or⦄, in this order of precedence, and parentheses. Two filters without operator are combined with ⦃-and⦄. Evaluation stops as soon as the result is known. For instance:
+⟦rfind C:\\Temp -e -or -ads⟧
+⟦rfind . -f -not -iname *.bak⟧
+⟦rfind . ( -e -or -size -1k ) -mtime +30⟧
+
+⌊Rename⌋:
+Action ⦃-rename⦄ applies a substitution ⟦s/regex/replacement/flags⟧ to the name (last path component) of matches. Any character can be used instead of /. Flag ⟦g⟧ replaces all matches instead of the first one, flag ⟦i⟧ makes regex case-insensitive. In replacement, ⟦\\1⟧ to ⟦\\9⟧ are capture groups and ⟦&⟧ the whole match; ⟦\\U⟧ and ⟦\\L⟧ convert to uppercase or lowercase until ⟦\\E⟧, ⟦\\u⟧ and ⟦\\l⟧ only convert next character.
+Renames are executed at the end of the search, deepest paths first. A rename is refused if target already exists or if several paths would get the same name. With option ⦃-n⦄, renames are only listed. For instance:
+⟦rfind . -f -iname *.jpeg -rename s/\\.jpeg$/.jpg/i⟧
+⟦rfind . -f -rename \"s/^(\\w)(.*)/\\u\\1\\L\\2/\" -journal undo.yaml⟧
+
+⌊Content search⌋:
+Filters ⦃-contains⦄, ⦃-icontains⦄, ⦃-wcontains⦄ and ⦃-iwcontains⦄ select text files whose content matches a regular expression, text encoding is detected automatically (UTF-8, UTF-16 or 8-bit ANSI). ^ and $ match begin and end of lines. Directories and non-text files are never selected. Since files are read, put these filters after cheaper ones, evaluation stops as soon as the result is known. For instance:
+⟦rfind . -f -iname *.log -mtime +30 -icontains \"fatal error\" -delete⟧
+
+⌊Machine-readable output⌋:
+Actions ⦃-json⦄, ⦃-jsonl⦄ and ⦃-csv⦄ write one record per match with fields ⟦path⟧, ⟦type⟧ (file, dir, or link for a link to an inexistent target), ⟦size⟧ (files only), ⟦mtime⟧ (ISO-8601 local time with offset, such as 2025-10-22T14:03:05+02:00), ⟦link_target⟧ and ⟦attributes⟧ (Windows attributes S, H and R). Missing values are null in JSON and empty in CSV. These actions can't be combined with another output action, but they can be combined with ⦃-exec⦄ and filters. Option ⦃-v⦄ adds human-readable text to output.
+
+⌊Printf templates⌋:
+Action ⦃-printf⦄ prints a line per match, built from a template with the following directives:
+- ¬⟦%p⟧: path as found; ⟦%P⟧: path relative to glob root (its constant part, before first wildcard)
+- ¬⟦%f⟧: name (last path component); ⟦%n⟧: name without extension; ⟦%x⟧: extension without dot; ⟦%h⟧: parent directory
+- ¬⟦%s⟧: size in bytes; ⟦%S⟧: size with unit K, M, G or T (powers of 1024). Empty for directories
+- ¬⟦%t⟧: modification time, format ⟦%Y-%m-%d %H:%M:%S⟧; ⟦%t{⟨format⟩}⟧: modification time with a strftime ⟨format⟩
+- ¬⟦%d⟧: depth below glob root; ⟦%y⟧: type file, dir or link; ⟦%l⟧: link target; ⟦%a⟧: Windows attributes S, H and R
+- ¬⟦%%⟧: a percent sign; ⟦\\n⟧, ⟦\\t⟧, ⟦\\0⟧ and ⟦\\\\⟧: newline, tab, NUL and backslash
+For instance: ⟦rfind . -f -printf \"%t{%d/%m/%Y}  %S  %P\"⟧
+
+⌊Copy, move and links⌋:
+Actions ⦃-copy⦄, ⦃-move⦄, ⦃-hardlink⦄ and ⦃-symlink⦄ only process files. The path of a file relative to the root of the glob (its constant part, before first wildcard) is kept below ⟨dest⟩, and missing directories are created. Files found in ⟨dest⟩ are ignored, so destination can be inside the searched tree. Option ⦃-collision⦄ controls what to do when target file already exists. A summary is printed at the end. For instance:
+⟦rfind C:\\Photos\\**\\*.jpg -mtime 2025-01-01..2025-12-31 -copy D:\\Backup\\2025⟧ copies ⟦C:\\Photos\\Trip\\a.jpg⟧ to ⟦D:\\Backup\\2025\\Trip\\a.jpg⟧.
+Symbolic links require administrator privileges or developer mode on Windows.
+
+⌊Command placeholders⌋:
+In commands of ⦃-exec⦄ and ⦃-execg⦄, the following placeholders are replaced, for instance for ⟦C:\\Data\\docs\\report.pdf⟧ found with glob ⟦C:\\Data\\**\\*.pdf⟧:
+- ¬⟦{}⟧: path, ⟦C:\\Data\\docs\\report.pdf⟧
+- ¬⟦{/}⟧: name, ⟦report.pdf⟧; ⟦{/.}⟧: name without extension, ⟦report⟧
+- ¬⟦{//}⟧: parent directory, ⟦C:\\Data\\docs⟧
+- ¬⟦{.}⟧: path without extension, ⟦C:\\Data\\docs\\report⟧; ⟦{ext}⟧: extension without dot, ⟦pdf⟧
+- ¬⟦{rel}⟧: path relative to glob root (its constant part, before first wildcard), ⟦docs\\report.pdf⟧
+With ⦃-execg⦄, an argument containing placeholders is repeated for each path found, and values containing spaces are quoted. For instance:
+⟦rfind C:\\Data\\**\\*.pdf -exec magick {} {.}.png ;⟧
+
+⌊Confirmation⌋:
+Actions ⦃-delete⦄, ⦃-rmdir⦄ and ⦃-exec⦄ are executed without asking, and option ⦃-n⦄ only lists them. With option ⦃-ok⦄ (or ⦃-i⦄), a question is asked on the console for each path: ⟦y⟧ executes, ⟦n⟧ skips, ⟦a⟧ executes for this path and all following ones of the same action, ⟦q⟧ skips this path and all following ones and stops the search. Closed input is considered as ⟦q⟧.
+With option ⦃-confirm⦄, deletions are not executed during the search: at the end, they're listed with the number of paths and total size, and a single question is asked before deleting. Option ⦃-max-delete⦄ also defers deletions to the end of the search, and deletes nothing if more than ⟨n⟩ paths would be deleted by ⦃-delete⦄ and ⦃-rmdir⦄ together. Directories inside another deleted directory are not counted. For instance:
+⟦rfind C:\\Development\\**\\target -d -rmdir -confirm -max-delete 50⟧
+
+⌊Profiles⌋:
+Long command lines used regularly can be saved as a profile with option ⦃-save⦄, and run later with ⟦@⟨name⟩⟧ as first argument (see Profiles below). Other options can follow, for instance to check a profile without executing its actions:
+⟦rfind C:\\Development\\**\\{bin,obj} C:\\Development\\**\\*.tmp -rmdir -delete -confirm -save cleanbuild⟧
+⟦rfind @cleanbuild -n⟧
+
 ⌊Compatibility with XFind⌋:
 - ¬Option ⦃-norecycle⦄ can be used instead of ⦃-r-⦄ to indicate to delete forever.
-- ¬Option ⦃-name⦄ can be used to indicate a specific file name or pattern to search.";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
-        println!();
-        MyMarkup::render_markup(MyGlobSearch::glob_syntax());
+- ¬Option ⦃-name⦄ can be used to indicate a specific file name or pattern to search.
+
+"
+            + MyGlobSearch::glob_syntax();
+
+        let dependencies = [
+            ("MyGlob", MyGlobSearch::version()),
+            ("MyMarkup", MyMarkup::version()),
+            ("Logging", logging::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("trash", env!("DEP_TRASH_VERSION")),
+            ("chrono", env!("DEP_CHRONO_VERSION")),
+            ("num-format", env!("DEP_NUM_FORMAT_VERSION")),
+            ("regex", env!("DEP_REGEX_VERSION")),
+            ("TextAutoDecode", TextAutoDecode::version()),
+        ];
+        Self::spec().extended_usage(&dependencies, &text);
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<Options, Box<dyn Error>> {
-        let args: Vec<String> = std::env::args().collect();
+        // Help and version are processed by RUtilsOptions, default options are inserted and profile @name is expanded
+        let Some(args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
         // Debug
         // let args = vec![String::from("app.exe"), String::from(r"C:\Temp\T\*"), String::from(r"-execg"),  String::from(r"cmd"),  String::from(r"/c"),  String::from(r"type"),  String::from(r"{}")];
@@ -159,17 +311,15 @@ impl Options {
             arg.starts_with('-')
         }
 
-        // Special options processing
-        if args.len() > 1 {
-            if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
+        // Argument of an option, or an error if it's missing
+        fn option_arg<'a>(args_iter: &mut impl Iterator<Item = &'a String>, option: &str) -> Result<&'a String, String> {
+            args_iter.next().ok_or(format!("Option -{option} requires an argument"))
+        }
 
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
+        // Filters and operators, in command line order, converted into a filter expression at the end
+        let mut filter_tokens: Vec<(FilterToken, String)> = Vec::new();
+        fn push_filter(tokens: &mut Vec<(FilterToken, String)>, arg: &str, filter: impl Filter + 'static) {
+            tokens.push((FilterToken::Filter(Box::new(filter)), arg.to_string()));
         }
 
         fn dep(old: &str, new: &str) {
@@ -187,9 +337,38 @@ impl Options {
                 match &arglc[..] {
                     "v" => options.verbose = true,
                     "w" => options.syncronous_exec = true,
+                    "j" => {
+                        let n = option_arg(&mut args_iter, &arglc)?;
+                        options.jobs = match n.parse::<usize>() {
+                            Ok(n) if n > 0 => n,
+                            _ => return Err(format!("Invalid argument {n} for option -j, expecting a number of jobs greater than 0").into()),
+                        };
+                    }
+                    "tag" => options.tag = true,
                     "log" => options.log = true,
                     "dbg" => options.debug = true,
                     "n" => options.noaction = true,
+                    "ok" | "i" | "confirm" => {
+                        let mode = if arglc == "confirm" { ConfirmMode::Batch } else { ConfirmMode::Each };
+                        if options.confirm != ConfirmMode::None && options.confirm != mode {
+                            return Err("Options -ok and -confirm can't be combined".into());
+                        }
+                        options.confirm = mode;
+                    }
+                    "save" => {
+                        let name = option_arg(&mut args_iter, &arglc)?;
+                        if !is_valid_profile_name(name) {
+                            return Err(format!("Invalid profile name {name} for option -save, use only letters, digits, - and _").into());
+                        }
+                        options.save = Some(name.clone());
+                    }
+                    "max-delete" => {
+                        let n = option_arg(&mut args_iter, &arglc)?;
+                        options.max_delete = match n.parse::<usize>() {
+                            Ok(n) => Some(n),
+                            _ => return Err(format!("Invalid argument {n} for option -max-delete, expecting a number of paths").into()),
+                        };
+                    }
 
                     "f" => options.search_files = true,
                     "d" => options.search_dirs = true,
@@ -273,16 +452,31 @@ impl Options {
                     }
 
                     // --
-                    "e" | "empty" => {
-                        options.filters_names.insert("empty");
-                    }
-                    "ads" => {
-                        options.filters_names.insert("ads");
+                    "e" | "empty" => push_filter(&mut filter_tokens, arg, filters::FilterEmpty::new()),
+                    "ads" => push_filter(&mut filter_tokens, arg, filters::FilterADS::new(false)),
+                    "adsx" => push_filter(&mut filter_tokens, arg, filters::FilterADS::new(true)),
+                    "size" => push_filter(&mut filter_tokens, arg, filters::FilterSize::new(option_arg(&mut args_iter, &arglc)?)?),
+                    "newer" => push_filter(&mut filter_tokens, arg, filters::FilterNewer::new(option_arg(&mut args_iter, &arglc)?)?),
+                    "mtime" | "atime" | "ctime" => {
+                        let kind = match &arglc[..] {
+                            "mtime" => filters::TimeKind::Modified,
+                            "atime" => filters::TimeKind::Accessed,
+                            _ => filters::TimeKind::Created,
+                        };
+                        push_filter(&mut filter_tokens, arg, filters::FilterTime::new(kind, option_arg(&mut args_iter, &arglc)?)?);
                     }
-                    "adsx" => {
-                        options.filters_names.insert("adsx");
+                    "regex" => push_filter(&mut filter_tokens, arg, filters::FilterRegex::new(option_arg(&mut args_iter, &arglc)?)?),
+                    "iname" => push_filter(&mut filter_tokens, arg, filters::FilterIName::new(option_arg(&mut args_iter, &arglc)?)?),
+                    "contains" | "icontains" | "wcontains" | "iwcontains" => {
+                        let pattern = option_arg(&mut args_iter, &arglc)?;
+                        let filter = filters::FilterContains::new(pattern, arglc.starts_with('i'), arglc.contains('w'))?;
+                        push_filter(&mut filter_tokens, arg, filter);
                     }
 
+                    "not" => filter_tokens.push((FilterToken::Not, arg.clone())),
+                    "and" | "a" => filter_tokens.push((FilterToken::And, arg.clone())),
+                    "or" | "o" => filter_tokens.push((FilterToken::Or, arg.clone())),
+
                     "r+" | "recycle" => options.recycle = true,
                     "r-" | "norecycle" => options.recycle = false,
 
@@ -295,6 +489,25 @@ impl Options {
                     "yaml" => {
                         options.actions_names.insert("yaml");
                     }
+                    "json" => {
+                        options.actions_names.insert("json");
+                    }
+                    "jsonl" => {
+                        options.actions_names.insert("jsonl");
+                    }
+                    "csv" => {
+                        options.actions_names.insert("csv");
+                    }
+                    "print0" => {
+                        options.actions_names.insert("print0");
+                    }
+                    "printf" => {
+                        if options.printf.is_some() {
+                            return Err("Option -printf can only be used once".into());
+                        }
+                        options.printf = Some(Template::new(option_arg(&mut args_iter, &arglc)?)?);
+                        options.actions_names.insert("printf");
+                    }
                     "nop" | "noprint" => {
                         options.actions_names.insert("nop");
                     }
@@ -304,6 +517,26 @@ impl Options {
                     "rd" | "rmdir" => {
                         options.actions_names.insert("rmdir");
                     }
+                    "rename" => {
+                        if options.rename.is_some() {
+                            return Err("Option -rename can only be used once".into());
+                        }
+                        options.rename = Some(Substitution::new(option_arg(&mut args_iter, &arglc)?)?);
+                        options.actions_names.insert("rename");
+                    }
+                    "journal" => options.journal = Some(option_arg(&mut args_iter, &arglc)?.clone()),
+
+                    "copy" | "move" | "hardlink" | "symlink" => {
+                        let dest = option_arg(&mut args_iter, &arglc)?;
+                        options.transfers.push((actions::TransferKind::from_name(&arglc).unwrap(), dest.clone()));
+                    }
+                    "collision" => {
+                        let policy = option_arg(&mut args_iter, &arglc)?;
+                        options.collision = actions::Collision::from_name(policy).ok_or(format!(
+                            "Invalid argument {policy} for option -collision, valid arguments are {}",
+                            actions::Collision::NAMES.join(", ")
+                        ))?;
+                    }
 
                     "exec" | "execg" => {
                         let mut args: Vec<String> = Vec::new();
@@ -313,9 +546,7 @@ impl Options {
                             if arg == ";" {
                                 break;
                             }
-                            // For now we just support {}, but in the future, maybe variants (basename, to lowercase, ...)
-                            // Will be updated when needed
-                            if arg.contains("{}") {
+                            if has_placeholder(arg) {
                                 placeholder_found = true;
                             }
                             args.push(arg.clone());
@@ -353,7 +584,7 @@ impl Options {
                 // Non-option, some values are special
                 match &arg.to_lowercase()[..] {
                     "?" | "h" | "help" => {
-                        Self::usage();
+                        Self::spec().usage();
                         return Err("".into());
                     }
 
@@ -362,12 +593,51 @@ impl Options {
                         return Err("".into());
                     }
 
+                    "!" => filter_tokens.push((FilterToken::Not, arg.clone())),
+                    "(" => filter_tokens.push((FilterToken::Open, arg.clone())),
+                    ")" => filter_tokens.push((FilterToken::Close, arg.clone())),
+
                     // Everything else is considered as a source (a glob pattern), it will be validated later
                     _ => options.sources.push(arg.clone()),
                 }
             }
         }
 
+        options.filter = parse_filter_expr(filter_tokens)?;
+
+        // Machine-readable output can't be mixed with other outputs
+        let outputs: Vec<String> = ["print", "dir", "yaml"]
+            .iter()
+            .chain(OUTPUT_FORMATS.iter())
+            .filter(|a| options.actions_names.contains(**a))
+            .map(|a| format!("-{a}"))
+            .collect();
+        if outputs.len() > 1 && OUTPUT_FORMATS.iter().any(|f| options.actions_names.contains(f)) {
+            return Err(format!("Output actions {} can't be combined", outputs.join(", ")).into());
+        }
+
+        if options.tag && options.jobs == 0 {
+            return Err("Option -tag requires option -j".into());
+        }
+        if options.syncronous_exec && options.jobs > 0 {
+            return Err("Option -w can't be combined with option -j, commands run with -j are always waited for at the end".into());
+        }
+
+        let deletion = options.actions_names.contains("delete") || options.actions_names.contains("rmdir");
+        if options.confirm == ConfirmMode::Each && !deletion && options.exec_commands.is_empty() {
+            return Err("Option -ok requires action -delete, -rmdir or -exec".into());
+        }
+        if options.confirm == ConfirmMode::Batch && !deletion {
+            return Err("Option -confirm requires action -delete or -rmdir".into());
+        }
+        if options.max_delete.is_some() && !deletion {
+            return Err("Option -max-delete requires action -delete or -rmdir".into());
+        }
+
+        if options.journal.is_some() && options.rename.is_none() {
+            return Err("Option -journal requires action -rename".into());
+        }
+
         // If neither filtering files or dirs has been requested, then we search for both
         if !options.search_dirs && !options.search_files {
             options.search_dirs = true;
@@ -375,10 +645,17 @@ impl Options {
         }
 
         // If no action is specified, then print action is default
-        if options.actions_names.is_empty() && options.exec_commands.is_empty() && options.execg_commands.is_empty() {
+        if options.actions_names.is_empty() && options.exec_commands.is_empty() && options.execg_commands.is_empty() && options.transfers.is_empty() {
             options.actions_names.insert("print");
         }
 
+        // Command line is only saved once it has been validated, and it's not executed
+        if let Some(name) = &options.save {
+            let path = Self::spec().save_profile(name)?;
+            println!("Profile {name} saved in {}, run it with {APP_NAME} @{name}", path.display());
+            return Err("".into());
+        }
+
         Ok(options)
     }
 }
@@ -0,0 +1,107 @@
+// path_info.rs, information about a path found, shared by machine-readable output actions
+//
+// 2026-10-19   PV      First version
+// 2026-10-19   PV      json_string from Logging
+
+use super::*;
+
+// External crates imports
+use chrono::{DateTime, Local, SecondsFormat};
+
+// Retrieve files/dirs attributes on Windows
+#[cfg(target_os = "windows")]
+use std::os::windows::fs::MetadataExt;
+
+/// Path information with stable field names: path, type, size, mtime, link_target and attributes
+#[derive(Debug)]
+pub struct PathInfo {
+    pub path: String,
+    pub typ: &'static str, // file, dir, or link for a link to an inexistent target
+    pub size: Option<u64>, // Only for files
+    pub modified: Option<DateTime<Local>>,
+    pub link_target: Option<String>,
+    pub attributes: String, // Windows attributes, S (system), H (hidden) and R (read-only), empty on Linux
+}
+
+impl PathInfo {
+    pub const FIELDS: [&'static str; 6] = ["path", "type", "size", "mtime", "link_target", "attributes"];
+
+    pub fn new(path: &Path) -> Self {
+        let link_target = if path.is_symlink() {
+            fs::read_link(path).ok().map(|t| t.to_string_lossy().replace(r"\\?\", ""))
+        } else {
+            None
+        };
+
+        // For a link whose target does not exist, we use metadata of the link itself
+        let (typ, meta) = match fs::metadata(path) {
+            Ok(m) => (if m.is_dir() { "dir" } else { "file" }, Some(m)),
+            Err(_) => ("link", fs::symlink_metadata(path).ok()),
+        };
+
+        #[allow(unused_mut)]
+        let mut attributes = String::new();
+        #[cfg(target_os = "windows")]
+        {
+            // Attributes of link itself, not of link target
+            if let Ok(metadata) = fs::symlink_metadata(path) {
+                let attr = metadata.file_attributes();
+                for (mask, letter) in [(0x4, 'S'), (0x2, 'H'), (0x1, 'R')] {
+                    if attr & mask != 0 {
+                        attributes.push(letter);
+                    }
+                }
+            }
+        }
+
+        PathInfo {
+            path: path.display().to_string(),
+            typ,
+            size: meta.as_ref().filter(|m| typ == "file" && m.is_file()).map(|m| m.len()),
+            modified: meta.as_ref().and_then(|m| m.modified().ok()).map(DateTime::<Local>::from),
+            link_target,
+            attributes,
+        }
+    }
+
+    /// Modification time in ISO-8601 format with local offset, such as 2025-10-22T14:03:05+02:00
+    pub fn mtime(&self) -> Option<String> {
+        self.modified.map(|dt| dt.to_rfc3339_opts(SecondsFormat::Secs, false))
+    }
+
+    /// Single-line JSON object
+    pub fn to_json(&self) -> String {
+        let opt_string = |s: Option<String>| s.map_or("null".to_string(), |s| json_string(&s));
+        format!(
+            "{{\"path\":{},\"type\":\"{}\",\"size\":{},\"mtime\":{},\"link_target\":{},\"attributes\":{}}}",
+            json_string(&self.path),
+            self.typ,
+            self.size.map_or("null".to_string(), |s| s.to_string()),
+            opt_string(self.mtime()),
+            opt_string(self.link_target.clone()),
+            json_string(&self.attributes)
+        )
+    }
+
+    /// CSV record, fields in the same order as FIELDS, missing values are empty
+    pub fn to_csv(&self) -> String {
+        [
+            csv_field(&self.path),
+            self.typ.to_string(),
+            self.size.map(|s| s.to_string()).unwrap_or_default(),
+            self.mtime().unwrap_or_default(),
+            csv_field(self.link_target.as_deref().unwrap_or_default()),
+            self.attributes.clone(),
+        ]
+        .join(",")
+    }
+}
+
+/// CSV field (RFC 4180), quoted only if it contains a comma, a double quote, a line break or leading/trailing spaces
+pub fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) || s.starts_with(' ') || s.ends_with(' ') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
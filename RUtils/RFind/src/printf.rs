@@ -0,0 +1,189 @@
+// printf.rs, custom output templates of action printf
+//
+// 2026-10-19   PV      First version
+
+use super::*;
+
+// Standard library imports
+use std::fmt;
+
+// External crates imports
+use chrono::format::{Item, StrftimeItems};
+
+/// Default format of %t
+const DEFAULT_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Part of a printf template
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplatePart {
+    Text(String),
+    Path,         // %p path as found
+    RelativePath, // %P path relative to glob root
+    Name,         // %f basename
+    Stem,         // %n basename without extension
+    Extension,    // %x extension without dot
+    Parent,       // %h parent directory
+    Size,         // %s size in bytes
+    HumanSize,    // %S size with unit K, M, G or T
+    Time(String), // %t or %t{strftime format}
+    Depth,        // %d depth below glob root
+    LinkTarget,   // %l link target
+    Attributes,   // %a Windows attributes
+    Type,         // %y file, dir or link
+}
+
+/// Compiled printf template: %-directives and \-escapes
+#[derive(Debug, Clone)]
+pub struct Template {
+    template: String,
+    parts: Vec<TemplatePart>,
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.template)
+    }
+}
+
+impl Template {
+    pub fn new(template: &str) -> Result<Self, String> {
+        let mut parts: Vec<TemplatePart> = Vec::new();
+        let mut text = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some('n') => text.push('\n'),
+                    Some('t') => text.push('\t'),
+                    Some('0') => text.push('\0'),
+                    Some('\\') => text.push('\\'),
+                    Some(e) => return Err(format!("Invalid escape \\{e} in template {template}, use \\n, \\t, \\0 or \\\\")),
+                    None => return Err(format!("Trailing backslash in template {template}")),
+                },
+                '%' => {
+                    let part = match chars.next() {
+                        Some('%') => {
+                            text.push('%');
+                            continue;
+                        }
+                        Some('p') => TemplatePart::Path,
+                        Some('P') => TemplatePart::RelativePath,
+                        Some('f') => TemplatePart::Name,
+                        Some('n') => TemplatePart::Stem,
+                        Some('x') => TemplatePart::Extension,
+                        Some('h') => TemplatePart::Parent,
+                        Some('s') => TemplatePart::Size,
+                        Some('S') => TemplatePart::HumanSize,
+                        Some('d') => TemplatePart::Depth,
+                        Some('l') => TemplatePart::LinkTarget,
+                        Some('a') => TemplatePart::Attributes,
+                        Some('y') => TemplatePart::Type,
+                        Some('t') => {
+                            let mut format = DEFAULT_TIME_FORMAT.to_string();
+                            if chars.peek() == Some(&'{') {
+                                chars.next();
+                                format.clear();
+                                let mut closed = false;
+                                for c in chars.by_ref() {
+                                    if c == '}' {
+                                        closed = true;
+                                        break;
+                                    }
+                                    format.push(c);
+                                }
+                                if !closed {
+                                    return Err(format!("Missing }} after %t{{ in template {template}"));
+                                }
+                                if StrftimeItems::new(&format).any(|i| i == Item::Error) {
+                                    return Err(format!("Invalid time format {format} in template {template}"));
+                                }
+                            }
+                            TemplatePart::Time(format)
+                        }
+                        Some(d) => return Err(format!("Invalid directive %{d} in template {template}")),
+                        None => return Err(format!("Trailing % in template {template}")),
+                    };
+                    if !text.is_empty() {
+                        parts.push(TemplatePart::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(part);
+                }
+                _ => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            parts.push(TemplatePart::Text(text));
+        }
+
+        Ok(Template {
+            template: template.to_string(),
+            parts,
+        })
+    }
+
+    /// Template applied to path found below glob root
+    pub fn render(&self, path: &Path, root: &Path) -> String {
+        // A constant glob matches its root, relative path is then the path itself
+        let relative = match path.strip_prefix(root) {
+            Ok(rel) if !rel.as_os_str().is_empty() => rel,
+            _ => path,
+        };
+        let os_string = |s: Option<&std::ffi::OsStr>| s.map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+
+        // Metadata is only retrieved if template needs it
+        let needs_info = self.parts.iter().any(|p| {
+            matches!(
+                p,
+                TemplatePart::Size
+                    | TemplatePart::HumanSize
+                    | TemplatePart::Time(_)
+                    | TemplatePart::LinkTarget
+                    | TemplatePart::Attributes
+                    | TemplatePart::Type
+            )
+        });
+        let info = if needs_info { Some(PathInfo::new(path)) } else { None };
+        let info = info.as_ref();
+
+        let mut res = String::new();
+        for part in self.parts.iter() {
+            match part {
+                TemplatePart::Text(t) => res.push_str(t),
+                TemplatePart::Path => res.push_str(&path.display().to_string()),
+                TemplatePart::RelativePath => res.push_str(&relative.display().to_string()),
+                TemplatePart::Name => res.push_str(&os_string(path.file_name())),
+                TemplatePart::Stem => res.push_str(&os_string(path.file_stem())),
+                TemplatePart::Extension => res.push_str(&os_string(path.extension())),
+                TemplatePart::Parent => res.push_str(&os_string(path.parent().map(|p| p.as_os_str()))),
+                TemplatePart::Size => res.push_str(&info.and_then(|i| i.size).map(|s| s.to_string()).unwrap_or_default()),
+                TemplatePart::HumanSize => res.push_str(&info.and_then(|i| i.size).map(human_size).unwrap_or_default()),
+                TemplatePart::Time(format) => {
+                    if let Some(dt) = info.and_then(|i| i.modified) {
+                        res.push_str(&dt.format(format).to_string());
+                    }
+                }
+                TemplatePart::Depth => res.push_str(&relative.components().count().to_string()),
+                TemplatePart::LinkTarget => res.push_str(info.and_then(|i| i.link_target.as_deref()).unwrap_or_default()),
+                TemplatePart::Attributes => res.push_str(info.map(|i| i.attributes.as_str()).unwrap_or_default()),
+                TemplatePart::Type => res.push_str(info.map(|i| i.typ).unwrap_or_default()),
+            }
+        }
+        res
+    }
+}
+
+/// Size with one decimal and unit K, M, G or T (powers of 1024), bytes below 1K
+pub fn human_size(size: u64) -> String {
+    const UNITS: [&str; 4] = ["K", "M", "G", "T"];
+    if size < 1024 {
+        return size.to_string();
+    }
+    let mut value = size as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1}{}", value, UNITS[unit])
+}
@@ -0,0 +1,193 @@
+// substitution.rs, sed-style substitution s/regex/replacement/flags used by action rename
+//
+// 2026-10-19   PV      First version
+
+// Standard library imports
+use std::fmt;
+
+// External crates imports
+use regex::{Captures, Regex, RegexBuilder};
+
+/// Part of a replacement string
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ReplacementPart {
+    Text(String),
+    Group(usize), // & is group 0
+    Case(CaseConversion),
+}
+
+/// Case-conversion escapes of replacement string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaseConversion {
+    Upper,     // \U, until \E or \L
+    Lower,     // \L, until \E or \U
+    End,       // \E
+    UpperNext, // \u, next character only
+    LowerNext, // \l, next character only
+}
+
+/// Compiled sed-style substitution ⟦s/regex/replacement/flags⟧.<br/>
+/// Any delimiter can be used instead of /, flags are g (replace all matches) and i (case-insensitive).
+#[derive(Debug, Clone)]
+pub struct Substitution {
+    expression: String,
+    re: Regex,
+    replacement: Vec<ReplacementPart>,
+    global: bool,
+}
+
+impl fmt::Display for Substitution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+impl Substitution {
+    pub fn new(expression: &str) -> Result<Self, String> {
+        let syntax_error = || format!("Invalid substitution {expression}, expecting s/regex/replacement/flags");
+
+        let mut chars = expression.chars();
+        if chars.next() != Some('s') {
+            return Err(syntax_error());
+        }
+        let delim = match chars.next() {
+            Some(c) if !c.is_alphanumeric() && !c.is_whitespace() && c != '\\' => c,
+            _ => return Err(syntax_error()),
+        };
+
+        // Split on unescaped delimiters, an escaped delimiter stands for itself, other escapes are kept
+        let mut parts: Vec<String> = vec![String::new()];
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                match chars.next() {
+                    Some(n) if n == delim => parts.last_mut().unwrap().push(n),
+                    Some(n) => {
+                        parts.last_mut().unwrap().push('\\');
+                        parts.last_mut().unwrap().push(n);
+                    }
+                    None => return Err(format!("Trailing backslash in substitution {expression}")),
+                }
+            } else if c == delim {
+                parts.push(String::new());
+            } else {
+                parts.last_mut().unwrap().push(c);
+            }
+        }
+        if parts.len() != 3 {
+            return Err(syntax_error());
+        }
+
+        let mut global = false;
+        let mut case_insensitive = false;
+        for flag in parts[2].chars() {
+            match flag {
+                'g' => global = true,
+                'i' | 'I' => case_insensitive = true,
+                _ => return Err(format!("Invalid flag {flag} in substitution {expression}, valid flags are g and i")),
+            }
+        }
+
+        if parts[0].is_empty() {
+            return Err(format!("Empty regex in substitution {expression}"));
+        }
+        let re = RegexBuilder::new(&parts[0])
+            .case_insensitive(case_insensitive)
+            .build()
+            .map_err(|e| format!("Invalid regex in substitution {expression}: {e}"))?;
+
+        let replacement = Self::parse_replacement(&parts[1]).map_err(|e| format!("{e} in substitution {expression}"))?;
+        for part in replacement.iter() {
+            if let ReplacementPart::Group(n) = part
+                && *n >= re.captures_len()
+            {
+                let groups = re.captures_len() - 1;
+                return Err(format!("Invalid reference \\{n} in substitution {expression}, regex has {groups} group(s)"));
+            }
+        }
+
+        Ok(Substitution {
+            expression: expression.to_string(),
+            re,
+            replacement,
+            global,
+        })
+    }
+
+    // Replacement: \1..\9 and & for groups, \U \L \E \u \l for case conversion, \& and \\ for literal characters
+    fn parse_replacement(s: &str) -> Result<Vec<ReplacementPart>, String> {
+        let mut parts: Vec<ReplacementPart> = Vec::new();
+        let mut text = String::new();
+        let push = |parts: &mut Vec<ReplacementPart>, text: &mut String, part: ReplacementPart| {
+            if !text.is_empty() {
+                parts.push(ReplacementPart::Text(std::mem::take(text)));
+            }
+            parts.push(part);
+        };
+
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '&' => push(&mut parts, &mut text, ReplacementPart::Group(0)),
+                '\\' => match chars.next() {
+                    Some(d @ '0'..='9') => push(&mut parts, &mut text, ReplacementPart::Group(d as usize - '0' as usize)),
+                    Some('U') => push(&mut parts, &mut text, ReplacementPart::Case(CaseConversion::Upper)),
+                    Some('L') => push(&mut parts, &mut text, ReplacementPart::Case(CaseConversion::Lower)),
+                    Some('E') => push(&mut parts, &mut text, ReplacementPart::Case(CaseConversion::End)),
+                    Some('u') => push(&mut parts, &mut text, ReplacementPart::Case(CaseConversion::UpperNext)),
+                    Some('l') => push(&mut parts, &mut text, ReplacementPart::Case(CaseConversion::LowerNext)),
+                    // Escaped delimiter has already been unescaped, what remains are escaped & and \ and other punctuation
+                    Some(p) if !p.is_alphanumeric() => text.push(p),
+                    Some(p) => return Err(format!("Invalid escape \\{p} in replacement")),
+                    None => return Err("Trailing backslash in replacement".into()),
+                },
+                _ => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            parts.push(ReplacementPart::Text(text));
+        }
+        Ok(parts)
+    }
+
+    /// Returns s after substitution of first match, or all matches with flag g
+    pub fn apply(&self, s: &str) -> String {
+        let mut res = String::new();
+        let mut last = 0;
+        for caps in self.re.captures_iter(s) {
+            let m = caps.get(0).unwrap();
+            res.push_str(&s[last..m.start()]);
+            self.expand(&caps, &mut res);
+            last = m.end();
+            if !self.global {
+                break;
+            }
+        }
+        res.push_str(&s[last..]);
+        res
+    }
+
+    // Appends replacement of a match to res, applying case conversions to both groups and literal text
+    fn expand(&self, caps: &Captures, res: &mut String) {
+        let mut mode = CaseConversion::End;
+        let mut next: Option<CaseConversion> = None;
+
+        let mut push_text = |text: &str, mode: CaseConversion, next: &mut Option<CaseConversion>| {
+            for c in text.chars() {
+                match next.take().unwrap_or(mode) {
+                    CaseConversion::Upper | CaseConversion::UpperNext => res.extend(c.to_uppercase()),
+                    CaseConversion::Lower | CaseConversion::LowerNext => res.extend(c.to_lowercase()),
+                    CaseConversion::End => res.push(c),
+                }
+            }
+        };
+
+        for part in self.replacement.iter() {
+            match part {
+                ReplacementPart::Text(t) => push_text(t, mode, &mut next),
+                ReplacementPart::Group(n) => push_text(caps.get(*n).map_or("", |m| m.as_str()), mode, &mut next),
+                ReplacementPart::Case(c @ (CaseConversion::UpperNext | CaseConversion::LowerNext)) => next = Some(*c),
+                ReplacementPart::Case(c) => mode = *c,
+            }
+        }
+    }
+}
@@ -1,12 +1,26 @@
 // rfind tests
 //
 // 2025-10-30   PV      First version of the tests
+// 2026-10-19   PV      Tests of size, time and iname filters
+// 2026-10-19   PV      Tests of filter expressions
+// 2026-10-19   PV      Tests of sed-style substitution and of action copy
+// 2026-10-19   PV      Tests of PathInfo JSON and CSV records
+// 2026-10-19   PV      Tests of printf templates
+// 2026-10-19   PV      Tests of JobPool
+// 2026-10-19   PV      Tests of command placeholders and make_chunks
+// 2026-10-19   PV      Tests of filter contains
+// 2026-10-19   PV      Tests of confirmation answers, -ok, -confirm and -max-delete
+// 2026-10-19   PV      Test of profiles
+// 2026-10-19   PV      Temporary folders of copy tests removed by tempfile::TempDir
+// 2026-10-19   PV      Test of -max-delete with -delete and -rmdir
 
 #[cfg(test)]
 mod tests {
     use assert_cmd::{Command, cargo};
     use predicates::prelude::*;
     use std::collections::HashSet;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
 
     use crate::*;
 
@@ -20,10 +34,16 @@ mod tests {
             let mut expected_files: HashSet<&str> = [
                 "src\\actions.rs",
                 "src\\command_to_run.rs",
+                "src\\confirm.rs",
                 "src\\fa_streams.rs",
+                "src\\filter_expr.rs",
                 "src\\filters.rs",
+                "src\\jobs.rs",
                 "src\\main.rs",
                 "src\\options.rs",
+                "src\\path_info.rs",
+                "src\\printf.rs",
+                "src\\substitution.rs",
                 "src\\tests.rs",
             ]
             .iter()
@@ -52,10 +72,16 @@ mod tests {
             let mut expected_files: HashSet<&str> = [
                 "src\\actions.rs",
                 "src\\command_to_run.rs",
+                "src\\confirm.rs",
                 "src\\fa_streams.rs",
+                "src\\filter_expr.rs",
                 "src\\filters.rs",
+                "src\\jobs.rs",
                 "src\\main.rs",
                 "src\\options.rs",
+                "src\\path_info.rs",
+                "src\\printf.rs",
+                "src\\substitution.rs",
                 "src\\tests.rs",
             ]
             .iter()
@@ -97,4 +123,464 @@ mod tests {
             expected_files.is_empty()
         }));
     }
+
+    #[test]
+    fn test_filter_size() {
+        let f = filters::FilterSize::new("+10M").unwrap();
+        assert!(f.matches(10 * 1024 * 1024 + 1));
+        assert!(!f.matches(10 * 1024 * 1024));
+
+        let f = filters::FilterSize::new("-1k").unwrap();
+        assert!(f.matches(1023));
+        assert!(!f.matches(1024));
+
+        let f = filters::FilterSize::new("100").unwrap();
+        assert!(f.matches(100));
+        assert!(!f.matches(101));
+        assert!(filters::FilterSize::new("2G").unwrap().matches(2 << 30));
+
+        assert!(filters::FilterSize::new("").is_err());
+        assert!(filters::FilterSize::new("+").is_err());
+        assert!(filters::FilterSize::new("10X").is_err());
+        assert!(filters::FilterSize::new("99999999999T").is_err());
+    }
+
+    #[test]
+    fn test_time_range() {
+        const DAY: Duration = Duration::from_secs(86400);
+        let now = SystemTime::now();
+        let ago = |days: u32, hours: u64| now - DAY * days - Duration::from_secs(hours * 3600);
+
+        let r = filters::TimeRange::parse("+2", now).unwrap();
+        assert!(r.contains(ago(3, 1)));
+        assert!(!r.contains(ago(2, 23)));
+
+        let r = filters::TimeRange::parse("-2", now).unwrap();
+        assert!(r.contains(ago(1, 23)));
+        assert!(!r.contains(ago(2, 1)));
+
+        let r = filters::TimeRange::parse("2", now).unwrap();
+        assert!(r.contains(ago(2, 1)));
+        assert!(!r.contains(ago(1, 23)));
+        assert!(!r.contains(ago(3, 1)));
+
+        let r = filters::TimeRange::parse("2025-01-01..2025-06-30", now).unwrap();
+        let day = |d: &str| filters::TimeRange::parse(d, now).unwrap().from.unwrap();
+        assert!(r.contains(day("2025-01-01")));
+        assert!(r.contains(day("2025-06-30") + DAY - Duration::from_secs(1)));
+        assert!(!r.contains(day("2025-07-01")));
+        assert!(!r.contains(day("2024-12-31")));
+
+        let r = filters::TimeRange::parse("..2025-06-30", now).unwrap();
+        assert!(r.from.is_none() && r.contains(day("2000-01-01")));
+        let r = filters::TimeRange::parse("2025-01-01..", now).unwrap();
+        assert!(r.to.is_none() && r.contains(now));
+
+        assert!(filters::TimeRange::parse("..", now).is_err());
+        assert!(filters::TimeRange::parse("2025-13-01", now).is_err());
+        assert!(filters::TimeRange::parse("2025-06-30..2025-01-01", now).is_err());
+        assert!(filters::TimeRange::parse("+x", now).is_err());
+    }
+
+    #[test]
+    fn test_filter_iname() {
+        let f = filters::FilterIName::new("*.RS").unwrap();
+        assert!(f.matches("main.rs"));
+        assert!(!f.matches("main.rsx"));
+        assert!(filters::FilterIName::new("Cargo.toml").unwrap().matches("CARGO.TOML"));
+        assert!(filters::FilterIName::new("*.{rs,toml}").unwrap().matches("Cargo.toml"));
+        assert!(filters::FilterIName::new("src\\*.rs").is_err());
+        assert!(filters::FilterIName::new("**").is_err());
+    }
+
+    // Filter expression from a command line such as "-iname a* -or ( -not -iname *.rs )", -iname is the only filter used
+    fn filter_expr(cmd: &str) -> Result<Option<Box<dyn Filter>>, String> {
+        let mut tokens = Vec::new();
+        let mut it = cmd.split_whitespace();
+        while let Some(arg) = it.next() {
+            let token = match arg {
+                "-iname" => FilterToken::Filter(Box::new(filters::FilterIName::new(it.next().unwrap()).unwrap())),
+                "-not" | "!" => FilterToken::Not,
+                "-and" | "-a" => FilterToken::And,
+                "-or" | "-o" => FilterToken::Or,
+                "(" => FilterToken::Open,
+                ")" => FilterToken::Close,
+                _ => panic!("Unexpected test token {arg}"),
+            };
+            tokens.push((token, arg.to_string()));
+        }
+        parse_filter_expr(tokens)
+    }
+
+    fn selected(cmd: &str, names: &[&str]) -> Vec<bool> {
+        let lw = logwriter_none();
+        let mut f = filter_expr(cmd).unwrap().unwrap();
+        names.iter().map(|n| f.filter(&lw, Path::new(n), false)).collect()
+    }
+
+    #[test]
+    fn test_filter_expr() {
+        assert!(filter_expr("").unwrap().is_none());
+
+        let names = ["a.rs", "a.txt", "b.rs", "b.txt"];
+        assert_eq!(selected("-iname a*", &names), [true, true, false, false]);
+        assert_eq!(selected("-iname a* -iname *.rs", &names), [true, false, false, false]);
+        assert_eq!(selected("-iname a* -and -iname *.rs", &names), [true, false, false, false]);
+        assert_eq!(selected("-iname a* -or -iname *.rs", &names), [true, true, true, false]);
+        assert_eq!(selected("-not -iname a*", &names), [false, false, true, true]);
+        assert_eq!(selected("! ! -iname a*", &names), [true, true, false, false]);
+
+        // -and has precedence over -or, parentheses change it
+        assert_eq!(selected("-iname b* -or -iname a* -iname *.rs", &names), [true, false, true, true]);
+        assert_eq!(selected("( -iname b* -or -iname a* ) -iname *.rs", &names), [true, false, true, false]);
+        assert_eq!(selected("-not ( -iname a* -or -iname *.rs )", &names), [false, false, false, true]);
+
+        assert_eq!(filter_expr("-iname a* -or").unwrap_err(), "Filter expected after -or in filter expression");
+        assert_eq!(filter_expr("-and -iname a*").unwrap_err(), "Filter expected before -and in filter expression");
+        assert_eq!(filter_expr("-iname a* -not").unwrap_err(), "Filter expected after -not in filter expression");
+        assert_eq!(filter_expr("( -iname a*").unwrap_err(), "Missing ) for ( in filter expression");
+        assert_eq!(filter_expr("-iname a* )").unwrap_err(), "Unexpected ) in filter expression");
+        assert_eq!(filter_expr("( )").unwrap_err(), "Filter expected before ) in filter expression");
+    }
+
+    #[test]
+    fn test_substitution() {
+        let apply = |expr: &str, name: &str| Substitution::new(expr).unwrap().apply(name);
+
+        assert_eq!(apply("s/a/b/", "banana"), "bbnana");
+        assert_eq!(apply("s/a/b/g", "banana"), "bbnbnb");
+        assert_eq!(apply("s/A/b/gi", "bAnana"), "bbnbnb");
+        assert_eq!(apply("s/x/y/", "banana"), "banana");
+        assert_eq!(apply(r"s/\.jpeg$/.jpg/i", "photo.JPEG"), "photo.jpg");
+        assert_eq!(apply(r"s#(\d+)-(\d+)#\2-\1#", "img 12-34.png"), "img 34-12.png");
+        assert_eq!(apply("s|^|[&]|", "x"), "[]x");
+        assert_eq!(apply(r"s/o/[&\&]/g", "foo"), "f[o&][o&]");
+        assert_eq!(apply(r"s/\//_/g", "a/b"), "a_b");
+        assert_eq!(apply(r"s/(\w+) (\w+)/\U\1\E \u\2/", "hello world"), "HELLO World");
+        assert_eq!(apply(r"s/^(\w)(.*)$/\u\1\L\2/", "rEADME.TXT"), "Readme.txt");
+        assert_eq!(apply(r"s/.*/\L\u&/", "ÉTÉ"), "Été");
+        assert_eq!(apply(r"s/.*/\lX\UyZ/", "a"), "xYZ");
+
+        assert!(Substitution::new("").is_err());
+        assert!(Substitution::new("y/a/b/").is_err());
+        assert!(Substitution::new("s/a/b").is_err());
+        assert!(Substitution::new("s/a/b/c/").is_err());
+        assert!(Substitution::new("s//b/").is_err());
+        assert!(Substitution::new("s/a/b/x").is_err());
+        assert!(Substitution::new("s/(a/b/").is_err());
+        assert!(Substitution::new(r"s/a/\1/").is_err());
+        assert!(Substitution::new(r"s/a/\n/").is_err());
+        assert!(Substitution::new("sabac").is_err());
+    }
+
+    #[test]
+    fn test_numbered_path() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        fs::write(dir.join("a.txt"), "").unwrap();
+        fs::write(dir.join("a (1).txt"), "").unwrap();
+
+        assert_eq!(actions::numbered_path(&dir.join("a.txt")), dir.join("a (2).txt"));
+        assert_eq!(actions::numbered_path(&dir.join("b")), dir.join("b (1)"));
+    }
+
+    #[test]
+    fn test_copy() {
+        // Destination folder is created by -copy
+        let tmp = tempfile::tempdir().unwrap();
+        let dest = tmp.path().join("dest");
+        let copy = |collision: &str| {
+            #[allow(deprecated)]
+            let mut cmd = Command::new(cargo::cargo_bin("rfind"));
+            cmd.arg("src\\fa*.rs").arg("-collision").arg(collision).arg("-copy").arg(&dest);
+            cmd.assert().success();
+        };
+
+        // Path relative to glob root src\ is kept
+        copy("skip");
+        assert!(dest.join("fa_streams.rs").is_file());
+        fs::write(dest.join("fa_streams.rs"), "").unwrap();
+
+        copy("skip");
+        assert_eq!(fs::metadata(dest.join("fa_streams.rs")).unwrap().len(), 0);
+
+        copy("rename");
+        assert!(dest.join("fa_streams (1).rs").is_file());
+
+        copy("overwrite");
+        assert!(fs::metadata(dest.join("fa_streams.rs")).unwrap().len() > 0);
+        assert!(!dest.join("fa_streams (2).rs").exists());
+    }
+
+    #[test]
+    fn test_path_info() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field(" lead"), "\" lead\"");
+
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let file = dir.join("a,b.txt");
+        fs::write(&file, "12345").unwrap();
+
+        let info = PathInfo::new(&file);
+        assert_eq!(info.typ, "file");
+        assert_eq!(info.size, Some(5));
+        let mtime = info.mtime().unwrap();
+        assert!(chrono::DateTime::parse_from_rfc3339(&mtime).is_ok());
+        let path = file.display().to_string();
+        assert_eq!(
+            info.to_json(),
+            format!(r#"{{"path":{},"type":"file","size":5,"mtime":"{mtime}","link_target":null,"attributes":""}}"#, json_string(&path))
+        );
+        assert_eq!(info.to_csv(), format!("{},file,5,{mtime},,", csv_field(&path)));
+
+        let info = PathInfo::new(dir);
+        assert_eq!(info.typ, "dir");
+        assert_eq!(info.size, None);
+        assert!(info.to_json().contains(r#""size":null"#));
+    }
+
+    #[test]
+    fn test_printf() {
+        assert_eq!(human_size(1023), "1023");
+        assert_eq!(human_size(1536), "1.5K");
+        assert_eq!(human_size(5 << 30), "5.0G");
+
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let file = dir.join("sub").join("report.final.txt");
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        fs::write(&file, "x".repeat(2048)).unwrap();
+
+        let render = |template: &str| Template::new(template).unwrap().render(&file, dir);
+        let sep = std::path::MAIN_SEPARATOR;
+        assert_eq!(render("%P"), format!("sub{sep}report.final.txt"));
+        assert_eq!(render("%f|%n|%x|%d|%y"), "report.final.txt|report.final|txt|2|file");
+        assert_eq!(render("%h"), dir.join("sub").display().to_string());
+        assert_eq!(render("%s %S 100%%\\t"), "2048 2.0K 100%\t");
+        let modified = chrono::DateTime::<chrono::Local>::from(fs::metadata(&file).unwrap().modified().unwrap());
+        assert_eq!(render("%t{%Y}"), modified.format("%Y").to_string());
+        assert_eq!(render("%t").len(), "2025-01-01 00:00:00".len());
+        assert_eq!(Template::new("%P").unwrap().render(&file, &file), file.display().to_string());
+
+        assert!(Template::new("%q").is_err());
+        assert!(Template::new("abc%").is_err());
+        assert!(Template::new("\\q").is_err());
+        assert!(Template::new("%t{%Y").is_err());
+        assert!(Template::new("%t{%Q}").is_err());
+    }
+
+    #[test]
+    fn test_job_pool() {
+        let (shell, flag) = if cfg!(target_os = "windows") { ("cmd", "/c") } else { ("sh", "-c") };
+        let mut pool = JobPool::new(2, true);
+        for i in 0..6 {
+            let ctr = CommandToRun {
+                command: shell.into(),
+                args: vec![flag.into(), format!("echo out{i}&& exit {}", i % 2)],
+            };
+            pool.submit(format!("p{i}"), ctr);
+        }
+
+        let mut results = pool.terminated();
+        results.extend(pool.finish());
+        assert_eq!(results.len(), 6);
+        for res in results.iter() {
+            let i: usize = res.path[1..].parse().unwrap();
+            assert_eq!(res.success(), i.is_multiple_of(2));
+            assert_eq!(res.output.as_deref().unwrap().trim(), format!("out{i}"));
+        }
+
+        let mut pool = JobPool::new(1, false);
+        let ctr = CommandToRun {
+            command: "rfind_no_such_command".into(),
+            args: vec![],
+        };
+        pool.submit("x".into(), ctr);
+        let results = pool.finish();
+        assert!(results[0].status.is_err() && !results[0].success());
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let root = Path::new("data");
+        let path = root.join("docs").join("my report.pdf");
+        let docs = root.join("docs").display().to_string();
+        let sep = std::path::MAIN_SEPARATOR;
+        let expand = |arg: &str| expand_placeholders(arg, &path, root, false);
+
+        assert_eq!(expand("{}"), path.display().to_string());
+        assert_eq!(expand("{/}"), "my report.pdf");
+        assert_eq!(expand("{//}"), docs);
+        assert_eq!(expand("{.}.png"), format!("{docs}{sep}my report.png"));
+        assert_eq!(expand("{/.}-{ext}"), "my report-pdf");
+        assert_eq!(expand("{rel}"), format!("docs{sep}my report.pdf"));
+        assert_eq!(expand("{x} {/} {"), "{x} my report.pdf {");
+        assert_eq!(expand_placeholders("{/}", &path, root, true), "\"my report.pdf\"");
+        assert_eq!(expand_placeholders("{//}", Path::new("a.txt"), Path::new("."), false), ".");
+        // Braces of a path are not placeholders
+        assert_eq!(expand_placeholders("{/}", Path::new("{}.txt"), root, false), "{}.txt");
+
+        assert!(has_placeholder("--out={/.}.txt"));
+        assert!(!has_placeholder("{x}"));
+    }
+
+    #[test]
+    fn test_make_chunks() {
+        let ctr = CommandToRun {
+            command: "cmd".into(),
+            args: vec!["-x".into(), "{/}".into(), "{x}".into()],
+        };
+        let root = PathBuf::from("root");
+        let paths: Vec<(PathBuf, PathBuf)> = ["aaaa", "bbbb", "cccc", "dddd"]
+            .iter()
+            .map(|n| (root.join("a very long directory name").join(n), root.clone()))
+            .collect();
+
+        // Fixed args -x and {x} count 7, each path adds 5 with basename only
+        let chunks = ctr.make_chunks(&paths, 18);
+        let args: Vec<Vec<String>> = chunks.iter().map(|c| c.args.clone()).collect();
+        assert_eq!(
+            args,
+            [["-x", "aaaa", "bbbb", "{x}"], ["-x", "cccc", "dddd", "{x}"]]
+                .iter()
+                .map(|v| v.iter().map(|s| s.to_string()).collect::<Vec<String>>())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(ctr.make_chunks(&paths, 1000).len(), 1);
+    }
+
+    #[test]
+    fn test_filter_contains() {
+        let f = filters::FilterContains::new("err(or)?", false, false).unwrap();
+        assert!(f.matches("line 1\r\nan error here\r\n"));
+        assert!(!f.matches("An ERROR"));
+        let f = filters::FilterContains::new("error", true, false).unwrap();
+        assert!(f.matches("An ERROR"));
+        let f = filters::FilterContains::new("err|warn", false, true).unwrap();
+        assert!(f.matches("an err here"));
+        assert!(!f.matches("an error, a warning"));
+        let f = filters::FilterContains::new("^end$", false, false).unwrap();
+        assert!(f.matches("start\r\nend\r\nmore"));
+        assert!(filters::FilterContains::new("(", false, false).is_err());
+
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let text = dir.join("a.txt");
+        fs::write(&text, "Hello world\n").unwrap();
+        let utf16 = dir.join("b.txt");
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend("Hello été\r\n".encode_utf16().flat_map(|u| u.to_le_bytes()));
+        fs::write(&utf16, bytes).unwrap();
+        let binary = dir.join("c.bin");
+        fs::write(&binary, b"Hello\x00\x01\x02world").unwrap();
+
+        let lw = logwriter_none();
+        let mut f = filters::FilterContains::new("hello", true, true).unwrap();
+        assert!(f.filter(&lw, &text, false));
+        assert!(f.filter(&lw, &utf16, false));
+        assert!(!f.filter(&lw, &binary, false));
+        assert!(!f.filter(&lw, dir, false));
+        let mut f = filters::FilterContains::new("été", false, false).unwrap();
+        assert!(f.filter(&lw, &utf16, false));
+        assert!(!f.filter(&lw, &text, false));
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::parse("y\n"), Some(Answer::Yes));
+        assert_eq!(Answer::parse("YES"), Some(Answer::Yes));
+        assert_eq!(Answer::parse(""), Some(Answer::No));
+        assert_eq!(Answer::parse(" n "), Some(Answer::No));
+        assert_eq!(Answer::parse("a"), Some(Answer::All));
+        assert_eq!(Answer::parse("Q"), Some(Answer::Quit));
+        assert_eq!(Answer::parse("x"), None);
+    }
+
+    #[test]
+    fn test_confirm_delete() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let create = || {
+            for name in ["a.tmp", "b.tmp", "c.tmp"] {
+                fs::write(dir.join(name), "12345").unwrap();
+            }
+        };
+        let remaining = || fs::read_dir(dir).unwrap().count();
+        let rfind = |options: &[&str], input: &str| {
+            #[allow(deprecated)]
+            let mut cmd = Command::new(cargo::cargo_bin("rfind"));
+            cmd.arg(dir.join("*.tmp")).arg("-delete").arg("-r-").args(options).write_stdin(input);
+            cmd.assert().success()
+        };
+
+        // Batch confirmation, refused then accepted
+        create();
+        rfind(&["-confirm"], "n\n").stdout(predicate::str::contains("3 file(s) to delete, total size 15"));
+        assert_eq!(remaining(), 3);
+        rfind(&["-confirm"], "y\n");
+        assert_eq!(remaining(), 0);
+
+        // Limit exceeded
+        create();
+        rfind(&["-max-delete", "2"], "").stdout(predicate::str::contains("more than -max-delete 2"));
+        assert_eq!(remaining(), 3);
+
+        // Per path: no for first file, yes for all others
+        rfind(&["-ok"], "n\na\n");
+        assert_eq!(remaining(), 1);
+
+        // Closed input is quit
+        create();
+        rfind(&["-ok"], "");
+        assert_eq!(remaining(), 3);
+    }
+
+    #[test]
+    fn test_max_delete_total() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        for name in ["a.tmp", "b.tmp"] {
+            fs::write(dir.join(name), "12345").unwrap();
+            fs::create_dir(dir.join(format!("d{name}"))).unwrap();
+        }
+        let remaining = || fs::read_dir(dir).unwrap().count();
+        let rfind = |max: &str| {
+            #[allow(deprecated)]
+            let mut cmd = Command::new(cargo::cargo_bin("rfind"));
+            cmd.arg(dir.join("*.tmp")).args(["-delete", "-rmdir", "-r-", "-max-delete", max]);
+            cmd.assert().success()
+        };
+
+        // 2 files and 2 dirs, limit applies to their total
+        rfind("3").stdout(predicate::str::contains("4 paths would be deleted, more than -max-delete 3"));
+        assert_eq!(remaining(), 4);
+        rfind("4");
+        assert_eq!(remaining(), 0);
+    }
+
+    #[test]
+    fn test_profile() {
+        // Profiles file in a dedicated temp folder
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let rfind = |args: &[&str]| {
+            #[allow(deprecated)]
+            let mut cmd = Command::new(cargo::cargo_bin("rfind"));
+            cmd.env("RUTILS_CONFIG", dir.join("rutils.toml")).env_remove("RFIND_OPTIONS").args(args);
+            cmd.assert()
+        };
+
+        // Saved command line is not executed
+        rfind(&["src\\*.rs", "-iname", "fa*", "-save", "fa"])
+            .success()
+            .stdout(predicate::str::contains("Profile fa saved").and(predicate::str::contains("fa_streams.rs").not()));
+
+        // Options following profile are added
+        rfind(&["@fa", "-printf", "%f"]).success().stdout("fa_streams.rs\n");
+        rfind(&["@unknown"]).failure().stdout(predicate::str::contains("Unknown profile unknown, defined profiles: fa"));
+        rfind(&["src\\*.rs", "-save", "a b"]).failure();
+    }
 }
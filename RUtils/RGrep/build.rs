@@ -24,7 +24,6 @@ fn main() {
 
     generate_variable(packages, "getopt");
     generate_variable(packages, "regex");
-    generate_variable(packages, "colored");
     // generate_variable(&packages, "atty");
 
     // Tell cargo to re-run the build script if Cargo.lock changes.
@@ -17,6 +17,9 @@
 // 2025-09-21   PV      1.8.0   Option -v (verbose) renamed -t (show execution time); Option -v to invert the sense of matching, to select non-matching lines
 // 2025-10-01   PV      1.8.1   Use MyGlob 1.10 supporting !SOURCES macro
 // 2025-10-31   PV      1.8.2   Option -n to force hide path
+// 2026-10-19   PV      1.10.0  Colors from MyMarkup theme (rgrep.path, rgrep.match), none if output is redirected or NO_COLOR is set; colored crate removed
+// 2026-10-19   PV      1.11.0  Options help, version and defaults from config file or RGREP_OPTIONS with RUtilsOptions crate
+// 2026-10-19   PV      1.11.1  Search and grep durations and final summary provided by Logging
 
 //#![allow(unused)]
 
@@ -25,12 +28,12 @@ use std::error::Error;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::process;
-use std::time::Instant;
 
 // External crates imports
-use colored::*;
 use getopt::Opt;
+use logging::logwriter_none;
 use myglob::{MyGlobMatch, MyGlobSearch};
+use mymarkup::{ColorSupport, Theme, ThemeStyle};
 use regex::Regex;
 use textautodecode::{TextAutoDecode, TextFileEncoding};
 
@@ -50,6 +53,24 @@ const APP_NAME: &str = env!("CARGO_PKG_NAME");
 const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
 const APP_DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
 
+// Output styles, elements rgrep.path and rgrep.match of MyMarkup theme can override default styles
+struct OutputStyles {
+    path: ThemeStyle,
+    matched: ThemeStyle,
+    support: ColorSupport,
+}
+
+impl OutputStyles {
+    fn new() -> OutputStyles {
+        let theme = Theme::load_default();
+        OutputStyles {
+            path: theme.named("rgrep.path").unwrap_or(ThemeStyle::parse("bright_black").unwrap()),
+            matched: theme.named("rgrep.match").unwrap_or(ThemeStyle::parse("bold red").unwrap()),
+            support: ColorSupport::detect(),
+        }
+    }
+}
+
 // -----------------------------------
 // Main
 
@@ -71,12 +92,15 @@ fn main() {
     }
     let re = re.unwrap();
 
-    let start = Instant::now();
+    let styles = OutputStyles::new();
+    // Console only LogWriter, for final summary
+    let writer = logwriter_none();
 
     // Building list of files
     // It could be better to process file just when it's returned by iterator rather than stored in a Vec and processed
     // later... but then we don't know when processing the first file whether there's more than one, to print paths...
     let mut files: Vec<PathBuf> = Vec::new();
+    let span = writer.span("search");
     for source in options.sources.iter() {
         let mut count = 0;
 
@@ -113,13 +137,16 @@ fn main() {
         }
     }
 
+    drop(span);
+
     // Finally processing files, if more than 1 file, prefix output with file
+    let span = writer.span("grep");
     if options.sources.is_empty() {
         if options.verbose {
             println!("Reading from stdin");
         }
         let s = io::read_to_string(io::stdin()).unwrap();
-        process_text(&re, s.as_str(), "(stdin)", &options);
+        process_text(&re, s.as_str(), "(stdin)", &options, &styles);
     } else {
         if files.len() > 1 {
             options.show_path = true;
@@ -128,21 +155,17 @@ fn main() {
             if options.debug {
                 println!("Process: {}", pb.display());
             }
-            process_path(&re, pb, &options);
+            process_path(&re, pb, &options, &styles);
         }
     }
-    let duration = start.elapsed();
+    drop(span);
 
     if options.verbose {
-        if files.is_empty() {
-            print!("\nstdin");
-        } else {
-            print!("\n{} file", files.len());
-            if files.len() > 1 {
-                print!("s");
-            }
+        println!();
+        if !files.is_empty() {
+            writer.add_count("file searched", files.len() as u64);
         }
-        println!(" searched in {:.3}s", duration.as_secs_f64());
+        writer.log_summary();
     }
 }
 
@@ -170,7 +193,7 @@ pub fn build_re(options: &Options) -> Result<Regex, regex::Error> {
 }
 
 /// First step processing a file, read text content from path and call process_text.
-fn process_path(re: &Regex, path: &Path, options: &Options) {
+fn process_path(re: &Regex, path: &Path, options: &Options, styles: &OutputStyles) {
     let res = TextAutoDecode::read_text_file(path);
     match res {
         Ok(tad) => {
@@ -181,7 +204,7 @@ fn process_path(re: &Regex, path: &Path, options: &Options) {
                 }
             } else {
                 let filename = path.display().to_string();
-                process_text(re, tad.text.unwrap().as_str(), filename.as_str(), options);
+                process_text(re, tad.text.unwrap().as_str(), filename.as_str(), options, styles);
             }
         }
         Err(e) => {
@@ -191,9 +214,8 @@ fn process_path(re: &Regex, path: &Path, options: &Options) {
 }
 
 /// Core rgrep process, search for re in txt, read from filename, according to options.
-fn process_text(re: &Regex, txt: &str, filename: &str, options: &Options) {
+fn process_text(re: &Regex, txt: &str, filename: &str, options: &Options, styles: &OutputStyles) {
     let mut matchlinecount = 0;
-    //let is_tty = atty::is(atty::Stream::Stdout);
 
     if options.invert_match {
         let mut non_matching_lines = Vec::new();
@@ -215,7 +237,7 @@ fn process_text(re: &Regex, txt: &str, filename: &str, options: &Options) {
         if options.out_level == 0 {
             for line in non_matching_lines {
                 if options.show_path && !options.hide_path {
-                    print!("{}: ", filename.bright_black());
+                    print!("{}: ", styles.path.paint(filename, styles.support));
                 }
                 println!("{}", line);
             }
@@ -231,14 +253,14 @@ fn process_text(re: &Regex, txt: &str, filename: &str, options: &Options) {
 
             if options.out_level == 0 {
                 if options.show_path && !options.hide_path {
-                    print!("{}: ", filename.bright_black());
+                    print!("{}: ", styles.path.paint(filename, styles.support));
                 }
 
                 let mut p: usize = 0;
                 for ma in gi.ranges {
                     if ma.start < gi.line.len() {
                         let e = ma.end;
-                        print!("{}{}", &gi.line[p..ma.start], &gi.line[ma].red().bold());
+                        print!("{}{}", &gi.line[p..ma.start], styles.matched.paint(&gi.line[ma], styles.support));
                         p = e;
                     }
                 }
@@ -7,12 +7,15 @@
 // 2025-09-22   PV      Option -v -> -t to show execution time. Option -v to invert the sense of matching, to select non-matching lines
 // 2025-10-31   PV      Option -n to force hide path
 // 2026-01-19   PV      Removed options 1 and 2 when calling getopt::Parser::new since they appear obsolete and cause unreachable!() panic
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Help, version and default options processed by RUtilsOptions
 
 // Application imports
 use crate::*;
 
 // External crates imports
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, ToolSpec};
 
 // Dedicated struct to store command line arguments
 #[derive(Debug, Default)]
@@ -31,82 +34,60 @@ pub struct Options {
     pub debug: bool,
 }
 
-impl Options {
-    fn header() {
-        println!("{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}");
-    }
+const OPTIONS: [OptionDef; 10] = [
+    OptionDef::flag(&["-i"], "Ignore case during search"),
+    OptionDef::flag(&["-w"], "Whole word search"),
+    OptionDef::flag(&["-F"], "Fixed string search (no regexp interpretation), also for patterns like ? or help"),
+    OptionDef::flag(&["-v"], "Invert the sense of matching, to select non-matching lines"),
+    OptionDef::flag(&["-t"], "Show execution time"),
+    OptionDef::flag(&["-n"], "No path, hide path normally shown automatically when there is more than one file to search"),
+    OptionDef::flag(&["-c"], "Suppress normal output, show count of matching lines for each file"),
+    OptionDef::flag(&["-l"], "Suppress normal output, show matching file names only"),
+    OptionDef::param(&["pattern"], "Regular expression to search"),
+    OptionDef::param(&["source"], "File or directory to search, glob syntax supported. Without source, search stdin"),
+];
+
+const EXTENDED_OPTIONS: [OptionDef; 1] = [OptionDef::flag(&["-a+", "-a-"], "Enable (default) or disable glob autorecurse mode")];
 
-    fn usage() {
-        Options::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} ¬[⦃?⦄|⦃-?⦄|⦃-h⦄|⦃??⦄|⦃-??⦄] [⦃-i⦄] [⦃-w⦄] [⦃-F⦄] [⦃-v⦄] [⦃-t⦄] [⦃-n⦄] [⦃-c⦄] [⦃-l⦄] ⟨pattern⟩ [⟨source⟩...]
-
-⌊Options⌋:
-⦃?⦄|⦃-?⦄|⦃-h⦄  ¬Show this message
-⦃??⦄|⦃-??⦄   ¬Show advanced usage notes
-⦃-i⦄       ¬Ignore case during search
-⦃-w⦄       ¬Whole word search
-⦃-F⦄       ¬Fixed string search (no regexp interpretation), also for patterns like ? or help
-⦃-v⦄       ¬Invert the sense of matching, to select non-matching lines
-⦃-t⦄       ¬Show execution time
-⦃-n⦄       ¬No path, hide path normally shown automatically when there is more than one file to search
-⦃-c⦄       ¬Suppress normal output, show count of matching lines for each file
-⦃-l⦄       ¬Suppress normal output, show matching file names only
-⟨pattern⟩  ¬Regular expression to search
-⟨source⟩   ¬File or directory to search, glob syntax supported. Without source, search stdin";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl Options {
+    fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⦃-i⦄] [⦃-w⦄] [⦃-F⦄] [⦃-v⦄] [⦃-t⦄] [⦃-n⦄] [⦃-c⦄] [⦃-l⦄] ⟨pattern⟩ [⟨source⟩...]")
+            .group("Options", &OPTIONS)
+            .extended_group("Extended options", &EXTENDED_OPTIONS)
     }
 
     fn extended_usage() {
-        Options::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        println!("- MyGlob: {}", MyGlobSearch::version());
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- TextAutoDecode: {}", TextAutoDecode::version());
-        println!("- getopt: {}", env!("DEP_GETOPT_VERSION"));
-        println!("- regex: {}", env!("DEP_REGEX_VERSION"));
-        println!("- colored: {}", env!("DEP_COLORED_VERSION"));
-        // println!("- atty: {}", env!("DEP_ATTY_VERSION"));
-        println!();
-
-        let text = "⟪⌊Advanced usage notes⌋⟫
-
-⌊Extended options⌋:
-⦃-a+⦄|⦃-a-⦄  ¬Enable (default) or disable glob autorecurse mode
-
-Options ⦃-c⦄ (show count of matching lines) and ⦃-l⦄ (show matching file names only) can be used together to show matching lines count only for matching files.
+        let text = "Options ⦃-c⦄ (show count of matching lines) and ⦃-l⦄ (show matching file names only) can be used together to show matching lines count only for matching files.
 Put special characters such as ⟦.⟧, ⟦*⟧ or ⟦?⟧ between brackets such as ⟦[.]⟧, ⟦[*]⟧ or ⟦[?]⟧ to search them as is.
 To search for ⟦[⟧ or ⟦]⟧, use ⟦[\\[]⟧ or ⟦[\\]]⟧.
 To search for a string containing double quotes, surround string by double quotes, and double individual double quotes inside. To search for ⟪\"msg\"⟫: {APP_NAME} ⟪\"\"\"msg\"\"\"⟫ ⟦C:\\Sources\\**\\*.rs⟧
 To search for the string help, use option ⦃-F⦄: {APP_NAME} ⦃-F⦄ ⟪help⟫ ⟦C:\\Sources\\**\\*.rs⟧
 To search for a string starting with - use ⟪[-]⟫: {APP_NAME} -i ⟪[-]2025⟫ ⟦c:\\Development\\GitHub\\Python\\Learning\\**\\*.py⟧
 
-There is no attempt to normalize or denormalize Unicode strings before search.";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
-        println!();
-        MyMarkup::render_markup(MyGlobSearch::glob_syntax());
+There is no attempt to normalize or denormalize Unicode strings before search.\n\n"
+            .replace("{APP_NAME}", APP_NAME)
+            + MyGlobSearch::glob_syntax();
+
+        let dependencies = [
+            ("MyGlob", MyGlobSearch::version()),
+            ("MyMarkup", MyMarkup::version()),
+            ("TextAutoDecode", TextAutoDecode::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("getopt", env!("DEP_GETOPT_VERSION")),
+            ("regex", env!("DEP_REGEX_VERSION")),
+        ];
+        Self::spec().extended_usage(&dependencies, &text);
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<Options, Box<dyn Error>> {
-        let mut args: Vec<String> = std::env::args().collect();
-        if args.len() > 1 {
-            if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
-
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
-        }
+        // Help and version are processed by RUtilsOptions, default options are inserted
+        let Some(mut args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
         let mut options = Options {
             autorecurse: true,
@@ -119,7 +100,7 @@ There is no attempt to normalize or denormalize Unicode strings before search.";
                 None => break,
                 Some(opt) => match opt {
                     Opt('h', None) | Opt('?', None) => {
-                        Self::usage();
+                        Self::spec().usage();
                         return Err("".into());
                     }
                     Opt('i', None) => {
@@ -188,7 +169,7 @@ There is no attempt to normalize or denormalize Unicode strings before search.";
         }
 
         if options.pattern.is_empty() {
-            Self::header();
+            Self::spec().header();
             eprintln!("\nNo pattern specified.\nUse {APP_NAME} ? to show options or {APP_NAME} ?? for advanced usage notes.");
             return Err("".into());
         }
@@ -9,6 +9,9 @@
 // 2025-05-07	PV      1.1.5 "Hors-Serie" -> "HS"
 // 2025-10-31	PV      1.1.6 fn s(n)
 // 2025-11-29   PV      1.1.7 Updated definition of re_date_ymm_std, second month mandatory
+// 2026-10-19   PV      Logging 2.0: LogWriter passed by shared reference
+// 2026-10-19   PV      1.3.0 Counters and final summary provided by Logging, DataBag removed
+// 2026-10-19   PV      1.4.0 Options help, version, defaults and shell completion with RUtilsOptions crate
 
 //#![allow(unused)]
 
@@ -17,13 +20,12 @@ use std::fs;
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::process;
-use std::time::Instant;
 
 // External crates imports
 use getopt::Opt;
 use myglob::{MyGlobMatch, MyGlobSearch};
 use unicode_normalization::UnicodeNormalization;
-use logging::{LogWriter, log, logln};
+use logging::{LogWriter, logln};
 
 // -----------------------------------
 // Submodules
@@ -45,13 +47,6 @@ const APP_DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
 // -----------------------------------
 // Main
 
-#[derive(Debug, Default)]
-struct DataBag {
-    files_count: usize,
-    files_renamed_count: usize,
-    errors_count: usize,
-}
-
 fn main() {
     // Process options
     let options = Options::new().unwrap_or_else(|err| {
@@ -64,20 +59,19 @@ fn main() {
     });
 
     // Prepare log writer
-    let mut writer = logging::new(APP_NAME, APP_VERSION, true);
-    let mut b = DataBag { ..Default::default() };
-    let start = Instant::now();
+    let writer = logging::new(APP_NAME, APP_VERSION, true);
+    writer.add_count("file", 0);
 
     let date_patterns = DatePatterns::new();
     for source in options.sources.iter() {
         let resgs = MyGlobSearch::new(source).autorecurse(true).compile();
         match resgs {
             Ok(gs) => {
-                logln(&mut writer, format!("Processing {}\n", source).as_str());
+                logln(&writer, format!("Processing {}\n", source).as_str());
                 for ma in gs.explore_iter() {
                     match ma {
                         MyGlobMatch::File(pb) => {
-                            process_file(&mut writer, &pb, &date_patterns, &options, &mut b);
+                            process_file(&writer, &pb, &date_patterns, &options);
                         }
 
                         // We ignore matching directories in rnormalizedates, we only look for files
@@ -86,29 +80,16 @@ fn main() {
                         MyGlobMatch::Error(_) => {}
                     }
                 }
-                logln(&mut writer, "");
+                logln(&writer, "");
             }
 
             Err(e) => {
-                logln(&mut writer, format!("*** Error building MyGlob: {:?}", e).as_str());
+                logln(&writer, format!("*** Error building MyGlob: {:?}", e).as_str());
             }
         }
     }
 
-    let duration = start.elapsed();
-    log(&mut writer, format!("{} file{} found", b.files_count, s(b.files_count)).as_str());
-    if b.files_renamed_count > 0 {
-        log(&mut writer, format!(", {} renamed", b.files_renamed_count).as_str());
-        if options.no_action {
-            log(&mut writer, " (no action)");
-        }
-    }
-    if b.errors_count > 0 {
-        log(&mut writer, format!(", {} error(s)", b.errors_count).as_str());
-    }
-    logln(&mut writer, "");
-
-    logln(&mut writer, format!("Duration: {:.3}s", duration.as_secs_f64()).as_str());
+    writer.log_summary();
 
     if options.final_pause {
         print!("\n(pause) ");
@@ -118,13 +99,8 @@ fn main() {
     }
 }
 
-// Helper for plurals
-fn s(n: usize) -> &'static str {
-    if n > 1 { "s" } else { "" }
-}
-
-fn process_file(lw: &mut LogWriter, pb: &Path, dp: &DatePatterns, opt: &Options, b: &mut DataBag) {
-    b.files_count += 1;
+fn process_file(lw: &LogWriter, pb: &Path, dp: &DatePatterns, opt: &Options) {
+    lw.count("file");
 
     let filename_original = pb.file_name().unwrap().to_string_lossy().into_owned();
     let stem_original = pb.file_stem().expect("No stem??").to_string_lossy().into_owned();
@@ -156,17 +132,18 @@ fn process_file(lw: &mut LogWriter, pb: &Path, dp: &DatePatterns, opt: &Options,
             lw,
             format!("{:70} {}", filename_original.nfc().collect::<String>(), filename_new).as_str(),
         );
-        b.files_renamed_count += 1;
 
-        if !opt.no_action {
+        if opt.no_action {
+            lw.count("file to rename");
+        } else {
             let newpb = pb.parent().unwrap().to_path_buf().join(PathBuf::from(filename_new));
-            if let Err(e) = fs::rename(pb, &newpb) {
-                logln(
+            match fs::rename(pb, &newpb) {
+                // Error is counted by Logging
+                Err(e) => logln(
                     lw,
                     format!("*** Error nenaming \"{}\" to \"{}\":\n{}", pb.display(), newpb.display(), e).as_str(),
-                );
-                b.files_renamed_count -= 1;
-                b.errors_count += 1;
+                ),
+                Ok(_) => lw.count("file renamed"),
             }
         }
     } else {
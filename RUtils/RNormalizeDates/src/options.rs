@@ -3,6 +3,8 @@
 // 2025-04-14   PV      Extracted to a separate file
 // 2025-05-04   PV      Use MyMarkup crate to format usage and extended help
 // 2025-10-22   PV      Clippy review
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Options described with RUtilsOptions: help, version, defaults and shell completion
 
 // Application imports
 use crate::*;
@@ -12,6 +14,7 @@ use std::error::Error;
 
 // External crates imports
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, ToolSpec};
 
 // Dedicated struct to store command line arguments
 #[derive(Debug, Default)]
@@ -23,65 +26,45 @@ pub struct Options {
     pub verbose: bool,
 }
 
-impl Options {
-    fn header() {
-        println!("{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}");
-    }
+const OPTIONS: [OptionDef; 5] = [
+    OptionDef::flag(&["-n"], "Do not actually rename (no action)"),
+    OptionDef::flag(&["-p"], "Final pause"),
+    OptionDef::flag(&["-v"], "Verbose output"),
+    OptionDef::flag(&["-s"], "Only process segment ⟨n⟩ (starting at 1) delimited by ' - '").arg("n").values(&["1", "2", "3", "4", "5"]),
+    OptionDef::param(&["source"], "Directory containing PDF files (and recurse) or simple file"),
+];
 
-    fn usage() {
-        Options::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} ¬[⦃?⦄|⦃-?⦄|⦃-h⦄|⦃??⦄] [⦃-n⦄] [⦃-p⦄] [⦃-v⦄] [⦃-s #⦄] ⟨source⟩...
-
-⌊Options⌋:
-⦃?⦄|⦃-?⦄|⦃-h⦄  ¬Show this message
-⦃??⦄       ¬Show advanced usage notes
-⦃-n⦄       ¬Do not actually rename (no action)
-⦃-p⦄       ¬Final pause
-⦃-v⦄       ¬Verbose output
-⦃-s #⦄     ¬Only process segment # (starting at 1) delimited by ' - '
-⟨source⟩   ¬Directory containing PDF files (and recurse) or simple file";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl Options {
+    fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⦃-n⦄] [⦃-p⦄] [⦃-v⦄] [⦃-s⦄ ⟨n⟩] [⟨source⟩...]")
+            .group("Options", &OPTIONS)
     }
 
     fn extended_usage() {
-        Options::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        println!("- MyGlob: {}", MyGlobSearch::version());
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- Logging: {}", logging::version());
-        println!("- getopt: {}", env!("DEP_GETOPT_VERSION"));
-        println!("- regex: {}", env!("DEP_REGEX_VERSION"));
-        println!("- unicode-normalization: {}", env!("DEP_UNICODE_NORMALIZATION_VERSION"));
-        println!();
-
-        let text = "⟪⌊Advanced usage notes⌋⟫
-
-Without ⟨source⟩ argument, default directory is ⟦C:\\Downloads\\A_Trier\\!A_Trier_Revues\\**\\*.pdf⟧\n";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
-        MyMarkup::render_markup(MyGlobSearch::glob_syntax());
+        let text = "Without ⟨source⟩ argument, default directory is ⟦C:\\Downloads\\A_Trier\\!A_Trier_Revues\\**\\*.pdf⟧\n\n".to_string()
+            + MyGlobSearch::glob_syntax();
+
+        let dependencies = [
+            ("MyGlob", MyGlobSearch::version()),
+            ("MyMarkup", MyMarkup::version()),
+            ("Logging", logging::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("getopt", env!("DEP_GETOPT_VERSION")),
+            ("regex", env!("DEP_REGEX_VERSION")),
+            ("unicode-normalization", env!("DEP_UNICODE_NORMALIZATION_VERSION")),
+        ];
+        Self::spec().extended_usage(&dependencies, &text);
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<Options, Box<dyn Error>> {
-        let mut args: Vec<String> = std::env::args().collect();
-        if args.len() > 1 {
-            if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
-
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
-        }
+        // Help and version are processed by RUtilsOptions, default options are inserted
+        let Some(mut args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
         let mut options = Options { ..Default::default() };
         let mut opts = getopt::Parser::new(&args, "h?npvs:");
@@ -91,7 +74,7 @@ Without ⟨source⟩ argument, default directory is ⟦C:\\Downloads\\A_Trier\\!
                 None => break,
                 Some(opt) => match opt {
                     Opt('h', None) | Opt('?', None) => {
-                        Self::usage();
+                        Self::spec().usage();
                         return Err("".into());
                     }
 
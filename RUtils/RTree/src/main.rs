@@ -5,6 +5,8 @@
 // 2025-06-29   PV      Renames (from -h) and parsed correctly option -a, but still don't use it in code
 // 2025-06-29   PV      Renames (from -h) and parsed correctly option -a, but still don't use it in code
 // 2025-07-04   PV      1.2.0 Option -A, option -d. Process junctions metadata correctly
+// 2026-10-19   PV      1.4.0 Options help, version, defaults and shell completion with RUtilsOptions crate
+// 2026-10-19   PV      1.4.1 Final summary provided by Logging
 
 #![allow(unused)]
 
@@ -16,7 +18,6 @@ use std::io;
 use std::path;
 use std::path::{Path, PathBuf};
 use std::process;
-use std::time::Instant;
 
 #[cfg(target_os = "windows")]
 use std::os::windows::fs::MetadataExt;
@@ -29,6 +30,7 @@ use windows::{
 }; // For OsStringExt::encode_wide
 
 // External crates imports
+use logging::logwriter_none;
 
 // -----------------------------------
 // Submodules
@@ -105,7 +107,8 @@ fn main() {
 
     let mut b = DataBag { ..Default::default() };
 
-    let start = Instant::now();
+    // Console only LogWriter, for final summary
+    let writer = logwriter_none();
     match options.source.clone() {
         Some(start_dir) => {
             do_print(&mut b, &start_dir, &options);
@@ -114,14 +117,13 @@ fn main() {
             do_print(&mut b, ".", &options);
         }
     }
-    let duration = start.elapsed();
 
     if options.verbose {
-        print!("{} subdirectorie(s)", b.dirs_count);
+        writer.add_count("subdir", b.dirs_count as u64);
         if b.links_count > 0 {
-            print!(", {} link(s)", b.links_count);
+            writer.add_count("link", b.links_count as u64);
         }
-        println!(" in {:.3}s", duration.as_secs_f64());
+        writer.log_summary();
     }
 }
 
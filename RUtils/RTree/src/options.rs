@@ -4,6 +4,8 @@
 // 2025-04-05   PV      First version
 // 2025-06-25   PV      Option -h renamex -a, and ctually parsed...
 // 2025-07-25   PV      Option -d, option -A
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Options described with RUtilsOptions: help, version, defaults and shell completion
 
 // Application imports
 use crate::*;
@@ -14,6 +16,7 @@ use std::error::Error;
 // External crates imports
 use getopt::Opt;
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, ToolSpec};
 
 // Dedicated struct to store command line arguments
 #[derive(Debug, Default)]
@@ -25,41 +28,24 @@ pub struct Options {
     pub maxdepth: u32,
 }
 
-impl Options {
-    fn header() {
-        println!("{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}");
-    }
+const OPTIONS: [OptionDef; 5] = [
+    OptionDef::flag(&["-a"], "Show hidden directories and directories starting with a dot"),
+    OptionDef::flag(&["-A"], "Show system+hidden directories and hidden directories starting with a dollar sign"),
+    OptionDef::flag(&["-d"], "Limits recursion to max_depth folders, default is 0 meaning no limitation").arg("max_depth"),
+    OptionDef::flag(&["-v"], "Verbose output"),
+    OptionDef::param(&["dir"], "Starting directory"),
+];
 
-    fn usage() {
-        Options::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} ¬[⦃?⦄|⦃-?⦄|⦃-h⦄|⦃??⦄|⦃-??⦄] [-⦃a⦄|-⦃A⦄] [⦃-d⦄ ⟨max_depth⟩] [-⦃v⦄] [⟨dir⟩]
-
-⌊Options⌋:
-⦃?⦄|⦃-?⦄|⦃-h⦄      ¬Show this message
-⦃??⦄|⦃-??⦄       ¬Show advanced usage notes
-⦃-a⦄           ¬Show hidden directories and directories starting with a dot
-⦃-A⦄           ¬Show system+hidden directories and hidden directories starting with a dollar sign
-⦃-d⦄ ⟨max_depth⟩ ¬Limits recursion to max_depth folders, default is 0 meaning no limitation
-⦃-v⦄           ¬Verbose output
-⟨dir⟩          ¬Starting directory";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl Options {
+    fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⦃-a⦄|⦃-A⦄] [⦃-d⦄ ⟨max_depth⟩] [⦃-v⦄] [⟨dir⟩]")
+            .group("Options", &OPTIONS)
     }
 
     fn extended_usage() {
-        Options::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- getopt: {}", env!("DEP_GETOPT_VERSION"));
-        println!();
-
-        let text = "⟪⌊Advanced usage notes⌋⟫
-
-By default, hidden folders are not shown.
+        let text = "By default, hidden folders are not shown.
 Option ⦃-a⦄ shows hidden folders, that is, folders with file attribute H (Windows, Hidden) such as ⟦C:\\ProgramData⟧ or name starting with a . such as ⟦.git⟧.
 Option ⦃-A⦄ (Windows only) shows system hidden folders, folders with file attribute H and S (Windows, Hidden+System) such as ⟦C:\\Recovery⟧ or hidden folders having a name starting with a $ such as ⟦C:\\$SysReset⟧.
 
@@ -71,25 +57,21 @@ Regardless of recursion depth limitation, \"... ?\" at the end of a folder means
 
 Option ⦃-v⦄ show small statistics at the end of tree.";
 
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+        let dependencies = [
+            ("MyMarkup", MyMarkup::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("getopt", env!("DEP_GETOPT_VERSION")),
+        ];
+        Self::spec().extended_usage(&dependencies, text);
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<Options, Box<dyn Error>> {
-        let mut args: Vec<String> = std::env::args().collect();
-
-        if args.len() > 1 {
-            if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
-
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
-        }
+        // Help and version are processed by RUtilsOptions, default options are inserted
+        let Some(mut args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
         let mut options = Options { ..Default::default() };
         let mut opts = getopt::Parser::new(&args, "h?aAvd:");
@@ -99,7 +81,7 @@ Option ⦃-v⦄ show small statistics at the end of tree.";
                 None => break,
                 Some(opt) => match opt {
                     Opt('h', None) | Opt('?', None) => {
-                        Self::usage();
+                        Self::spec().usage();
                         return Err("".into());
                     }
 
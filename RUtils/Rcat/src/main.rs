@@ -4,6 +4,8 @@
 // 2025-10-31	PV      1.0.1 fn s(n)
 // 2025-11-16	PV      1.1   Use MyGlob
 // 2025-11-16	PV      2.0   Use getopts instead of getopt to parse options; Use MyGlobCLOptions to process MyGlob options
+// 2026-10-19   PV      2.2.0 Options help, version, defaults and shell completion with RUtilsOptions crate
+// 2026-10-19   PV      2.2.1 Plural helper and final summary provided by Logging
 
 // ToDo: implement a set of standard options to control glob library, not limited to a+/a-
 // ToDo: option to limit to text inputs and control output text encoding
@@ -15,9 +17,9 @@
 use std::io::{self, Read, Write};
 use std::path::Path;
 use std::process;
-use std::time::Instant;
 
 // External crates imports
+use logging::logwriter_none;
 use myglob::{MyGlobCLOptions, MyGlobMatch, MyGlobSearch};
 
 // -----------------------------------
@@ -56,7 +58,8 @@ fn main() {
 
     // println!("options: {:#?}\n", options);
 
-    let start = Instant::now();
+    // Console only LogWriter, for final summary
+    let writer = logwriter_none();
 
     // Convert String sources into MyGlobSearch structs
     let mut sources: Vec<(&String, MyGlobSearch)> = Vec::new();
@@ -124,8 +127,6 @@ fn main() {
         }
     }
 
-    let duration = start.elapsed();
-
     if b.files_count > 1 {
         let mut name = String::from("total");
         if b.files_count > 1 {
@@ -134,15 +135,11 @@ fn main() {
     }
 
     if options.verbose > 0 {
-        println!("{} file{} searched in {:.3}s", b.files_count, s(b.files_count), duration.as_secs_f64());
+        writer.add_count("file searched", b.files_count as u64);
+        writer.log_summary();
     }
 }
 
-// Helper for plurals
-fn s(n: usize) -> &'static str {
-    if n > 1 { "s" } else { "" }
-}
-
 /// First step processing a file, read text content from path and call process_text.
 fn process_file(b: &mut DataBag, path: &Path, options: &AppOptions) {
     match std::fs::read(path) {
@@ -4,6 +4,8 @@
 // 2025-10-24   PV      First version
 // 2025-11-16   PV      Options -a+/-a-, -d
 // 2025-11-16   PV      Use MyGlobCLOptions
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Options described with RUtilsOptions: help, version, defaults and shell completion
 
 // Application imports
 use crate::*;
@@ -14,6 +16,7 @@ use std::error::Error;
 // External crates imports
 use getopts::{Fail, Options};
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, ToolSpec};
 
 // Dedicated struct to store command line arguments
 #[derive(Debug, Default)]
@@ -25,30 +28,24 @@ pub struct AppOptions {
     pub verbose: usize,
 }
 
-impl AppOptions {
-    fn header() {
-        println!("{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}");
-    }
-
-    fn header_copyright() {
-        Self::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-    }
-
-    fn usage() {
-        Self::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} [⟨option⟩...] [⟨source⟩...]
+const OPTIONS: [OptionDef; 4] = [
+    OptionDef::flag(&["-v", "--verbose"], "Verbose mode, can be repeated to increase verbosity level"),
+    OptionDef::flag(&["--glob"], "Globbing specific options (see extended help), can be repeated").arg("opt[,opt]…").list_values(&MyGlobCLOptions::VALUES),
+    OptionDef::flag(&["-e", "--encoding"], "Only concatenate text files to output after conversion to specified encoding ⟨enc⟩")
+        .arg("enc")
+        .values(&["utf-8", "utf-8-bom", "utf16-le", "utf16-be"]),
+    OptionDef::param(&["source"], "File or directory to read (globbing supported, autorecurse by default). Without source, read stdin"),
+];
 
-⌊Options⌋:
-⦃?⦄|⦃-?⦄|⦃help⦄|⦃-help⦄     ¬Show this message
-⦃??⦄|⦃-??⦄|⦃--help⦄       ¬Show advanced usage notes
-⦃--glob ⟨opt⟩[,⟨opt⟩]⦄... ¬Globbing specific options (see extended help)
-⦃-e|--encoding⦄ ⟨enc⟩   ¬Only concatenate text files to output after conversion to specified encoding ⟨enc⟩ (utf-8|utf-8-bom|utf16-le|utf16-be)
+const ADVANCED_OPTIONS: [OptionDef; 1] = [OptionDef::flag(&["-d", "--debug"], "Debug mode, show internal dev information")];
 
-⟨source⟩              ¬File or directory to read (globbing supported, autorecurse by default). Without source, read stdin";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl AppOptions {
+    fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⟨option⟩...] [⟨source⟩...]")
+            .group("Options", &OPTIONS)
+            .extended_group("Advanced options", &ADVANCED_OPTIONS)
     }
 
     fn print_usage(program: &str, opts: Options) {
@@ -57,44 +54,24 @@ impl AppOptions {
     }
 
     fn extended_usage() {
-        Self::header_copyright();
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        println!("- MyGlob: {}", MyGlobSearch::version());
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- getopts: {}", env!("DEP_GETOPTS_VERSION"));
-        println!();
-
-        let text = "⟪⌊Advanced usage notes⌋⟫
-
-⌊Advanced options⌋:
-⦃-d⦄       ¬Debug mode, show internal dev information\n\n"
-            .to_string()
-            + MyGlobCLOptions::options();
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
-        println!();
-        MyMarkup::render_markup(MyGlobSearch::glob_syntax());
+        let text = MyGlobCLOptions::options().to_string() + "\n\n" + MyGlobSearch::glob_syntax();
+
+        let dependencies = [
+            ("MyGlob", MyGlobSearch::version()),
+            ("MyMarkup", MyMarkup::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("getopts", env!("DEP_GETOPTS_VERSION")),
+        ];
+        Self::spec().extended_usage(&dependencies, &text);
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<AppOptions, Box<dyn Error>> {
-        let mut args: Vec<String> = std::env::args().collect();
-
-        // Special options processing
-        if args.len() > 1 {
-            if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
-
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
-        }
+        // Special options processing by RUtilsOptions, default options are inserted
+        let Some(args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
         let mut opts = Options::new();
         // Common options
@@ -120,7 +97,7 @@ impl AppOptions {
         };
 
         if matches.opt_present("version") {
-            Self::header_copyright();
+            Self::spec().header_copyright();
             return Err("".into());
         }
 
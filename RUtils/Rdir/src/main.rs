@@ -5,15 +5,17 @@
 // 2025-10-28	PV      1.0.2 Added file owner
 // 2025-10-29	PV      1.1.0 Simplifiy names; Better processing of constant globs, and folders . and ..
 // 2025-10-29	PV      1.1.1 No more errors in WSL volumes
+// 2026-10-19   PV      1.3.0 Options help, version, defaults and shell completion with RUtilsOptions crate
+// 2026-10-19   PV      1.3.1 Plural helper and final summary provided by Logging
 
 //#![allow(unused)]
 
 // Standard library imports
 use std::path::Path;
 use std::process;
-use std::time::Instant;
 
 // External imports
+use logging::{logwriter_none, s};
 use myglob::{MyGlobMatch, MyGlobSearch};
 use numfmt::{Formatter, Precision, Scales};
 
@@ -97,7 +99,8 @@ fn main() {
         process::exit(1);
     }
 
-    let start = Instant::now();
+    // Console only LogWriter, for final summary
+    let writer = logwriter_none();
 
     let mut b = DataBag { ..Default::default() };
     for gs in sources.iter() {
@@ -125,8 +128,6 @@ fn main() {
         }
     }
 
-    let duration = start.elapsed();
-
     if b.files_count > 1 {
         let mut name = String::from("total");
         if b.files_count > 1 {
@@ -135,21 +136,16 @@ fn main() {
     }
 
     if options.verbose {
-        let mut msg = Vec::<String>::new();
-        if b.files_count > 0 {
-            msg.push(format!("{} file{}", b.files_count, s(b.files_count)));
-        }
-        if b.dirs_count > 0 {
-            msg.push(format!("{} {}", b.dirs_count, if b.dirs_count > 1 { "directories" } else { "directory" }));
-        };
-        if b.links_count > 0 {
-            msg.push(format!("{} link{}", b.links_count, s(b.links_count)));
-        }
-        if msg.is_empty() {
-            msg.push("No file, no directory".to_string());
+        for (name, count) in [
+            ("file analyzed", b.files_count),
+            ("dir analyzed", b.dirs_count),
+            ("link analyzed", b.links_count),
+        ] {
+            if count > 0 {
+                writer.add_count(name, count.into());
+            }
         }
-
-        println!("{} analyzed in {:.3}s", msg.join(", "), duration.as_secs_f64());
+        writer.log_summary();
     }
 }
 
@@ -390,10 +386,6 @@ fn process_path(b: &mut DataBag, path: &Path, options: &Options) {
     println!();
 }
 
-fn s(n: u32) -> &'static str {
-    if n > 1 { "s" } else { "" }
-}
-
 fn show_invisible_chars(s: &str) -> String {
     let s = format!("{:?}", s).replace(r"\\", r"\");
     strip_quotes(&s).to_string()
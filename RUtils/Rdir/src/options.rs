@@ -2,6 +2,8 @@
 // Options processing
 //
 // 2025-10-24   PV      First version
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Options described with RUtilsOptions: help, version, defaults and shell completion
 
 // Application imports
 use crate::*;
@@ -13,6 +15,7 @@ use std::error::Error;
 use getopt::Opt;
 use myglob::MyGlobSearch;
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, ToolSpec};
 
 // Dedicated struct to store command line arguments
 #[derive(Debug, Default)]
@@ -23,57 +26,40 @@ pub struct Options {
     pub verbose: bool,
 }
 
-impl Options {
-    fn header() {
-        println!("{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}");
-    }
-
-    fn usage() {
-        Options::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} ¬[⦃?⦄|⦃-?⦄|⦃-h⦄|⦃??⦄|⦃-??⦄] [-⦃l⦄] [-⦃s⦄] [-⦃v⦄] ⟨source⟩...
+const OPTIONS: [OptionDef; 4] = [
+    OptionDef::flag(&["-s"], "Displays files in specified directory and all subdirectories (glob autorecurse)"),
+    OptionDef::flag(&["-l"], "Show information of links target instead of link"),
+    OptionDef::flag(&["-v"], "Verbose output"),
+    OptionDef::param(&["source"], "Files or directories to analyze"),
+];
 
-⌊Options⌋:
-⦃?⦄|⦃-?⦄|⦃-h⦄  ¬Show this message
-⦃??⦄|⦃-??⦄   ¬Show advanced usage notes
-⦃-s⦄       ¬Displays files in specified directory and all subdirectories (glob autorecurse)
-⦃-l⦄       ¬Show information of links target instead of link
-⦃-v⦄       ¬Verbose output
-⟨source⟩   ¬Files or directories to analyze";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl Options {
+    fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⦃-l⦄] [⦃-s⦄] [⦃-v⦄] ⟨source⟩...")
+            .group("Options", &OPTIONS)
     }
 
     fn extended_usage() {
-        Options::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- MyGlob: {}", MyGlobSearch::version());
-        println!("- getopt: {}", env!("DEP_GETOPT_VERSION"));
-        println!("- chrono: {}", env!("DEP_CHRONO_VERSION"));
-        println!("- numfmt: {}", env!("DEP_NUMFMT_VERSION"));
-        println!();
-        MyMarkup::render_markup(MyGlobSearch::glob_syntax());
+        let dependencies = [
+            ("MyMarkup", MyMarkup::version()),
+            ("MyGlob", MyGlobSearch::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("getopt", env!("DEP_GETOPT_VERSION")),
+            ("chrono", env!("DEP_CHRONO_VERSION")),
+            ("numfmt", env!("DEP_NUMFMT_VERSION")),
+        ];
+        Self::spec().extended_usage(&dependencies, MyGlobSearch::glob_syntax());
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<Options, Box<dyn Error>> {
-        let mut args: Vec<String> = std::env::args().collect();
-        if args.len() > 1 {
-            if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
-
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
-        }
+        // Help and version are processed by RUtilsOptions, default options are inserted
+        let Some(mut args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
         let mut options = Options { ..Default::default() };
         let mut opts = getopt::Parser::new(&args, "h?lsv");
@@ -83,7 +69,7 @@ impl Options {
                 None => break,
                 Some(opt) => match opt {
                     Opt('h', None) | Opt('?', None) => {
-                        Self::usage();
+                        Self::spec().usage();
                         return Err("".into());
                     }
 
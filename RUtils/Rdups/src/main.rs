@@ -3,6 +3,8 @@
 //
 // 2025-11-04	PV      First version
 // 2025-11-05	PV      1.0.1 Use a fallback for Path::canonicalise that fails on a Cryptomator volume
+// 2026-10-19   PV      1.2.0 Options help, version, defaults and shell completion with RUtilsOptions crate
+// 2026-10-19   PV      1.2.1 Plural helper, search and hashing durations and final summary provided by Logging
 
 //#![allow(unused)]
 
@@ -14,9 +16,9 @@ use std::io;
 use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
 use std::process;
-use std::time::Instant;
 
 // External crates imports
+use logging::{LogWriter, logwriter_none};
 use myglob::{MyGlobMatch, MyGlobSearch};
 
 // -----------------------------------
@@ -54,8 +56,9 @@ fn main() {
         process::exit(1);
     }
 
-    let start = Instant::now();
-    let res = global_process(&options);
+    // Console only LogWriter, for final summary
+    let writer = logwriter_none();
+    let res = global_process(&writer, &options);
     if let Err(e) = res {
         eprintln!("{APP_NAME}: Error during processing: {}", e);
         process::exit(1);
@@ -90,10 +93,13 @@ fn main() {
         }
     }
 
-    let duration = start.elapsed();
-
     if options.verbose {
-        println!("\n# {} file{} analyzed in {:.3}s", files_count, s(files_count), duration.as_secs_f64());
+        // Summary lines are comments, as the rest of output
+        writer.add_count("file analyzed", files_count as u64);
+        println!();
+        for line in writer.summary().lines() {
+            println!("# {line}");
+        }
     }
 }
 
@@ -103,7 +109,7 @@ struct GlobalResult {
 }
 
 // Warp all processing into a separate function so that it's easy to unit test
-fn global_process(options: &Options) -> io::Result<GlobalResult> {
+fn global_process(writer: &LogWriter, options: &Options) -> io::Result<GlobalResult> {
     let mut files_count: usize = 0;
 
     // Step 0: Validate all globs
@@ -135,6 +141,7 @@ fn global_process(options: &Options) -> io::Result<GlobalResult> {
     if options.verbose {
         println!("Step 1: build size_dict");
     }
+    let span = writer.span("search");
     for gs in globs.into_iter() {
         for ma in gs.explore_iter() {
             match ma {
@@ -158,6 +165,7 @@ fn global_process(options: &Options) -> io::Result<GlobalResult> {
             }
         }
     }
+    drop(span);
     if files_count == 0 {
         return Err(io::Error::other("No matching file found, abort"));
     }
@@ -191,6 +199,7 @@ fn global_process(options: &Options) -> io::Result<GlobalResult> {
     if options.verbose {
         println!("Step 2: Hash size dups content");
     }
+    let span = writer.span("hashing");
     let mut hashes_count: usize = 0;
     let mut size_hash_dict: HashMap<(u64, u64), Vec<PathBuf>> = HashMap::new();
     for (size, files) in size_dict.into_iter() {
@@ -215,6 +224,7 @@ fn global_process(options: &Options) -> io::Result<GlobalResult> {
             }
         }
     }
+    drop(span);
     if options.verbose {
         println!("End of step 2, {} groups of dup (size, hash) found", size_hash_dict.len());
         println!("{} hashes calculated", hashes_count);
@@ -261,10 +271,6 @@ fn process_file(already_processed: &mut HashSet<PathBuf>, size_dict: &mut HashMa
     Ok(())
 }
 
-fn s(n: usize) -> &'static str {
-    if n > 1 { "s" } else { "" }
-}
-
 /// Hashes a file's content using DefaultHasher (SipHash).
 /// WARNING: This hash will be DIFFERENT every time you run the program!
 fn hash_file_sip(path: &Path) -> io::Result<u64> {
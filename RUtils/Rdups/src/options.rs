@@ -2,6 +2,8 @@
 // Options processing
 //
 // 2025-11-04   PV      First version
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Options described with RUtilsOptions: help, version, defaults and shell completion
 
 // Application imports
 use crate::*;
@@ -12,6 +14,7 @@ use std::error::Error;
 // External crates imports
 use getopt::Opt;
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, ToolSpec};
 
 // Dedicated struct to store command line arguments
 #[derive(Debug, Default)]
@@ -22,63 +25,43 @@ pub struct Options {
     pub verbose: bool,
 }
 
-impl Options {
-    fn header() {
-        println!("{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}");
-    }
-
-    fn usage() {
-        Options::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} ¬[⦃?⦄|⦃-?⦄|⦃-h⦄|⦃??⦄|⦃-??⦄] [⦃-a+⦄|⦃-a-⦄] [-⦃c⦄] [-⦃v⦄] [⟨source⟩...]
-
-⌊Options⌋:
-⦃?⦄|⦃-?⦄|⦃-h⦄  ¬Show this message
-⦃??⦄|⦃-??⦄   ¬Show advanced usage notes
-⦃-a+|-a-⦄  ¬Enable (default) or disable glob autorecurse mode (see extended usage)
-⦃-c⦄       ¬Use content hashing
-⦃-v⦄       ¬Verbose output
-⟨source⟩   ¬File or directory to search, glob syntax supported (see extended usage)";
+const OPTIONS: [OptionDef; 4] = [
+    OptionDef::flag(&["-a+", "-a-"], "Enable (default) or disable glob autorecurse mode (see extended usage)"),
+    OptionDef::flag(&["-c"], "Use content hashing"),
+    OptionDef::flag(&["-v"], "Verbose output"),
+    OptionDef::param(&["source"], "File or directory to search, glob syntax supported (see extended usage)"),
+];
 
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl Options {
+    fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⦃-a+⦄|⦃-a-⦄] [⦃-c⦄] [⦃-v⦄] [⟨source⟩...]")
+            .group("Options", &OPTIONS)
     }
 
     fn extended_usage() {
-        Options::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        println!("- MyGlob: {}", MyGlobSearch::version());
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- getopt: {}", env!("DEP_GETOPT_VERSION"));
-        println!();
-
-        let text = "⟪⌊Advanced usage notes⌋⟫
-
-Recommended: 1st pass without content hashing, delete visibly matching files (same folder, same issue #, ...) otherwise don't delete them.
-2nd pass with content hashing, slower, to be sure";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
-        println!();
-        MyMarkup::render_markup(MyGlobSearch::glob_syntax());
+        let text = "Recommended: 1st pass without content hashing, delete visibly matching files (same folder, same issue #, ...) otherwise don't delete them.
+2nd pass with content hashing, slower, to be sure\n\n"
+            .to_string()
+            + MyGlobSearch::glob_syntax();
+
+        let dependencies = [
+            ("MyGlob", MyGlobSearch::version()),
+            ("MyMarkup", MyMarkup::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("getopt", env!("DEP_GETOPT_VERSION")),
+        ];
+        Self::spec().extended_usage(&dependencies, &text);
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<Options, Box<dyn Error>> {
-        let mut args: Vec<String> = std::env::args().collect();
-        if args.len() > 1 {
-            if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
-
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
-        }
+        // Help and version are processed by RUtilsOptions, default options are inserted
+        let Some(mut args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
         let mut options = Options {
             autorecurse: true,
@@ -91,7 +74,7 @@ Recommended: 1st pass without content hashing, delete visibly matching files (sa
                 None => break,
                 Some(opt) => match opt {
                     Opt('h', None) | Opt('?', None) => {
-                        Self::usage();
+                        Self::spec().usage();
                         return Err("".into());
                     }
 
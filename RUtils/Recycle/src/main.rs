@@ -6,17 +6,19 @@
 // 2025-05-05	PV      1.1.3 Logging crate
 // 2025-09-15	PV      1.1.4 logwriter_none
 // 2025-10-22   PV      1.2.0 Clippy review, separated options processing in options.rs, use build.rs and dependencies variables
+// 2026-10-19   PV      Logging 2.0: LogWriter passed by shared reference
+// 2026-10-19   PV      1.3.0 Counters and final summary provided by Logging
+// 2026-10-19   PV      1.4.0 Options help, version, defaults and shell completion with RUtilsOptions crate
 
 //#![allow(unused)]
 
 // Standard library imports
 use std::path::Path;
 use std::process;
-use std::time::Instant;
 
 // External crates imports
 use myglob::{MyGlobMatch, MyGlobSearch};
-use logging::{LogWriter, log, logln, logwriter_none};
+use logging::{LogWriter, logln, logwriter_none};
 
 // -----------------------------------
 // Submodules
@@ -48,27 +50,22 @@ fn main() {
             process::exit(0);
         }
         logln(
-            &mut logwriter_none(),
+            &logwriter_none(),
             format!("*** {APP_NAME}: Problem parsing arguments: {}", err).as_str(),
         );
         process::exit(1);
     });
 
     // Prepare log writer
-    let mut writer = logging::new(APP_NAME, APP_VERSION, options.verbose);
-
-    let mut files_count = 0;
-    let mut dirs_count = 0;
-
-    let start = Instant::now();
+    let writer = logging::new(APP_NAME, APP_VERSION, options.verbose);
 
     for source in options.sources.iter() {
         let p = Path::new(&source);
         if p.is_file() {
-            recycle_file(&mut writer, p, &mut files_count, &options);
+            recycle_file(&writer, p, &options);
         } else if p.is_dir() {
             // Check that it's not a reparse point
-            recycle_dir(&mut writer, p, &mut dirs_count, &options);
+            recycle_dir(&writer, p, &options);
         } else {
             let gsres = MyGlobSearch::build(source);
             let gs = match gsres {
@@ -76,7 +73,7 @@ fn main() {
                 Err(_) => {
                     if !options.silent {
                         logln(
-                            &mut writer,
+                            &writer,
                             format!("*** Source {source} is neither a file nor a dir nor a valid glob, ignored").as_str(),
                         );
                     }
@@ -86,62 +83,50 @@ fn main() {
 
             if gs.is_constant() {
                 if !options.silent {
-                    logln(&mut writer, format!("*** Source {source} is neither a file nor a dir, ignored").as_str());
+                    logln(&writer, format!("*** Source {source} is neither a file nor a dir, ignored").as_str());
                 }
                 continue;
             }
 
             for ma in gs.explore_iter() {
                 let problem = match ma {
-                    MyGlobMatch::File(pb) => recycle_file(&mut writer, &pb, &mut files_count, &options),
-                    MyGlobMatch::Dir(pb) => recycle_dir(&mut writer, &pb, &mut dirs_count, &options),
+                    MyGlobMatch::File(pb) => recycle_file(&writer, &pb, &options),
+                    MyGlobMatch::Dir(pb) => recycle_dir(&writer, &pb, &options),
                     MyGlobMatch::Error(e) => {
                         if !options.silent {
-                            logln(&mut writer, format!("*** Error {e}").as_str());
+                            logln(&writer, format!("*** Error {e}").as_str());
                         }
                         false
                     }
                 };
 
                 if problem && !options.silent {
-                    logln(&mut writer, format!("*** Exploration of glob {source} is stopped").as_str());
+                    logln(&writer, format!("*** Exploration of glob {source} is stopped").as_str());
                 }
             }
         }
     }
 
     if options.verbose {
-        let duration = start.elapsed();
-
-        fn s(n: i32) -> &'static str {
-            if n > 1 { "s" } else { "" }
-        }
-
-        if files_count > 0 {
-            log(&mut writer, &format!("{files_count} file{} recycled", s(files_count)));
-            if options.no_action {
-                log(&mut writer, " (noaction)");
-            }
-            logln(&mut writer, "");
-        }
-        if dirs_count > 0 {
-            log(&mut writer, &format!(", {files_count} dir{} recycled", s(dirs_count)));
-            if options.no_action {
-                log(&mut writer, " (noaction)");
-            }
-            logln(&mut writer, "");
-        }
+        writer.log_summary();
+    }
+}
 
-        logln(&mut writer, &format!("Total duration: {:.3}s", duration.as_secs_f64()));
+// Counter names for summary
+fn counter_name(typename: &str, options: &Options) -> String {
+    if options.no_action {
+        format!("{typename} to recycle")
+    } else {
+        format!("{typename} recycled")
     }
 }
 
-fn recycle_dir(writer: &mut LogWriter, path: &Path, dirs_count: &mut i32, options: &Options) -> bool {
+fn recycle_dir(writer: &LogWriter, path: &Path, options: &Options) -> bool {
     if options.no_action {
         if options.verbose {
             logln(writer, format!("RD /S {}", quoted_path(path)).as_str());
         }
-        *dirs_count += 1;
+        writer.count(&counter_name("dir", options));
         return false;
     }
 
@@ -186,7 +171,7 @@ fn recycle_dir(writer: &mut LogWriter, path: &Path, dirs_count: &mut i32, option
             if options.verbose {
                 logln(writer, format!("RD /S {}", quoted_path(path)).as_str());
             }
-            *dirs_count += 1;
+            writer.count(&counter_name("dir", options));
         }
 
         Err(e) => {
@@ -201,12 +186,12 @@ fn recycle_dir(writer: &mut LogWriter, path: &Path, dirs_count: &mut i32, option
     false // No problem
 }
 
-fn recycle_file(writer: &mut LogWriter, path: &Path, files_count: &mut i32, options: &Options) -> bool {
+fn recycle_file(writer: &LogWriter, path: &Path, options: &Options) -> bool {
     if options.no_action {
         if options.verbose {
             logln(writer, format!("DEL {}", quoted_path(path)).as_str());
         }
-        *files_count += 1;
+        writer.count(&counter_name("file", options));
         return false;
     }
 
@@ -240,7 +225,7 @@ fn recycle_file(writer: &mut LogWriter, path: &Path, files_count: &mut i32, opti
             if options.verbose {
                 println!("DEL {}", quoted_path(path));
             }
-            *files_count += 1;
+            writer.count(&counter_name("file", options));
         }
 
         Err(e) => {
@@ -2,6 +2,8 @@
 // Processing command line arguments
 //
 // 2025-10-22   PV      Extracted from main.rs; Added dependencies info in extended help
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Options described with RUtilsOptions: help, version, defaults and shell completion
 
 // Application imports
 use crate::*;
@@ -12,6 +14,7 @@ use std::error::Error;
 // External crates imports
 use getopt::Opt;
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, ToolSpec};
 
 // Dedicated struct to store command line arguments
 #[derive(Debug, Default)]
@@ -22,67 +25,47 @@ pub struct Options {
     pub silent: bool,
 }
 
-impl Options {
-    fn header() {
-        println!("{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}");
-    }
-
-    fn usage() {
-        Options::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} ¬[⦃?⦄|⦃-?⦄|⦃-h⦄|⦃??⦄] [⦃-v⦄] [⦃-s⦄] [⦃-n⦄] ⟨source⟩...
-
-⌊Options⌋:
-⦃?⦄|⦃-?⦄|⦃-h⦄  ¬Show this message
-⦃??⦄       ¬Show advanced usage notes
-⦃-v⦄       ¬Verbose output
-⦃-s⦄       ¬Silent mode, silently ignore files/dirs not found
-⦃-n⦄       ¬No action (nothing deleted)
-⟨source⟩   ¬File or directory to delete, or file glob pattern";
+const OPTIONS: [OptionDef; 4] = [
+    OptionDef::flag(&["-v"], "Verbose output"),
+    OptionDef::flag(&["-s"], "Silent mode, silently ignore files/dirs not found"),
+    OptionDef::flag(&["-n"], "No action (nothing deleted)"),
+    OptionDef::param(&["source"], "File or directory to delete, or file glob pattern"),
+];
 
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl Options {
+    fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⦃-v⦄] [⦃-s⦄] [⦃-n⦄] ⟨source⟩...")
+            .group("Options", &OPTIONS)
     }
 
     fn extended_usage() {
-                Options::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        //println!("- MyGlob: {}", MyGlobSearch::version());
-        println!("- MyGlob: {}", MyGlobSearch::version());
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- Logging: {}", logging::version());
-        println!("- getopt: {}", env!("DEP_GETOPT_VERSION"));
-        println!("- trash: {}", env!("DEP_TRASH_VERSION"));
-        println!("- windows: {}", env!("DEP_WINDOWS_VERSION"));
-        println!();
-
-        let text = "⟪⌊Advanced usage notes⌋⟫
-
-Only local files (local drive or attached USB drive) support trash.
-Network files can't be deleted to recycle bin, so they can't be removed with this command (contrary to PDEL that will remove remote files permanently).\n";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
-        MyMarkup::render_markup(MyGlobSearch::glob_syntax());
+        let text = "Only local files (local drive or attached USB drive) support trash.
+Network files can't be deleted to recycle bin, so they can't be removed with this command (contrary to PDEL that will remove remote files permanently).\n\n"
+            .to_string()
+            + MyGlobSearch::glob_syntax();
+
+        let dependencies = [
+            ("MyGlob", MyGlobSearch::version()),
+            ("MyMarkup", MyMarkup::version()),
+            ("Logging", logging::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("getopt", env!("DEP_GETOPT_VERSION")),
+            ("trash", env!("DEP_TRASH_VERSION")),
+            ("windows", env!("DEP_WINDOWS_VERSION")),
+        ];
+        Self::spec().extended_usage(&dependencies, &text);
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<Options, Box<dyn Error>> {
-        let mut args: Vec<String> = std::env::args().collect();
-        if args.len() > 1 {
-			if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
+        // Help and version are processed by RUtilsOptions, default options are inserted
+        let Some(mut args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
-		}
-		
         let mut options = Options { ..Default::default() };
         let mut opts = getopt::Parser::new(&args, "h?vsn");
 
@@ -91,7 +74,7 @@ Network files can't be deleted to recycle bin, so they can't be removed with thi
                 None => break,
                 Some(opt) => match opt {
                     Opt('h', None) | Opt('?', None) => {
-                        Self::usage();
+                        Self::spec().usage();
                         return Err("".into());
                     }
 
@@ -5,6 +5,8 @@
 // 2025-10-01   PV      1.0.2 Updated list of text extensions
 // 2025-10-22   PV      1.0.3 Clippy review
 // 2025-10-31   PV      1.0.4 fn s(n)
+// 2026-10-19   PV      1.2.0 Options help, version, defaults and shell completion with RUtilsOptions crate
+// 2026-10-19   PV      1.2.1 Plural helper and final summary provided by Logging
 
 //#![allow(unused)]
 
@@ -13,10 +15,10 @@ use std::collections::HashMap;
 use std::io::{self, Read, Write};
 use std::path::Path;
 use std::process;
-use std::time::Instant;
 
 // External crates imports
 use colored::*;
+use logging::logwriter_none;
 use myglob::{MyGlobMatch, MyGlobSearch};
 use tempfile::Builder;
 use textautodecode::{TextAutoDecode, TextFileEncoding};
@@ -158,7 +160,8 @@ fn main() {
         process::exit(1);
     });
 
-    let start = Instant::now();
+    // Console only LogWriter, for final summary
+    let writer = logwriter_none();
 
     let mut b = DataBag { ..Default::default() };
 
@@ -242,27 +245,17 @@ fn main() {
         let _ = process_stdin(&mut b, &options);
     }
 
-    let duration = start.elapsed();
-
     if options.verbose {
         println!("\nGlobal stats:");
         print_files_types_counts(&b.files_types);
         print_eol_styles_counts(&b.eol_styles);
 
-        println!(
-            "\n{} file{} searched in {:.3}s",
-            b.files_types.total,
-            s(b.files_types.total),
-            duration.as_secs_f64()
-        );
+        println!();
+        writer.add_count("file searched", b.files_types.total as u64);
+        writer.log_summary();
     }
 }
 
-// Helper for plurals
-fn s(n: usize) -> &'static str {
-    if n > 1 { "s" } else { "" }
-}
-
 fn process_stdin(b: &mut DataBag, options: &Options) -> Result<(), io::Error> {
     if options.verbose {
         println!("Reading from stdin");
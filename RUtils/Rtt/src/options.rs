@@ -3,6 +3,8 @@
 //
 // 2025-04-21   PV      First version
 // 2025-05-04   PV      Use MyMarkup crate to format usage and extended help
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Options described with RUtilsOptions: help, version, defaults and shell completion
 
 // Application imports
 use crate::*;
@@ -14,6 +16,7 @@ use std::error::Error;
 use getopt::Opt;
 use myglob::MyGlobSearch;
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, ToolSpec};
 
 // Dedicated struct to store command line arguments
 #[derive(Debug, Default)]
@@ -24,44 +27,23 @@ pub struct Options {
     pub verbose: bool,
 }
 
-impl Options {
-    fn header() {
-        println!("{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}");
-    }
-
-    fn usage() {
-        Options::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} ¬[⦃?⦄|⦃-?⦄|⦃-h⦄|⦃??⦄|⦃-??⦄] [⦃-a+⦄|⦃-a-⦄] [⦃-w⦄] [⦃-v⦄] [⟨source⟩...]
+const OPTIONS: [OptionDef; 4] = [
+    OptionDef::flag(&["-a+", "-a-"], "Enable (default) or disable glob autorecurse mode (see extended usage)"),
+    OptionDef::flag(&["-w"], "Only show warnings"),
+    OptionDef::flag(&["-v"], "Verbose output"),
+    OptionDef::param(&["source"], "File or directory to search, glob syntax supported. Without source, search stdin."),
+];
 
-⌊Options⌋:
-⦃?⦄|⦃-?⦄|⦃-h⦄  ¬Show this message
-⦃??⦄|⦃-??⦄   ¬Show advanced usage notes
-⦃-a+⦄|⦃-a-⦄  ¬Enable (default) or disable glob autorecurse mode (see extended usage)
-⦃-w⦄       ¬Only show warnings
-⦃-v⦄       ¬Verbose output
-⟨source⟩   ¬File or directory to search, glob syntax supported. Without source, search stdin.";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl Options {
+    fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⦃-a+⦄|⦃-a-⦄] [⦃-w⦄] [⦃-v⦄] [⟨source⟩...]")
+            .group("Options", &OPTIONS)
     }
 
     fn extended_usage() {
-        Options::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        println!("- MyGlob: {}", MyGlobSearch::version());
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- TextAutoDecode: {}", TextAutoDecode::version());
-        println!("- getopt: {}", env!("DEP_GETOPT_VERSION"));
-        println!("- coloredt: {}", env!("DEP_COLORED_VERSION"));
-        println!("- tempfile: {}", env!("DEP_TEMPFILE_VERSION"));
-        println!();
-
-        let text = "⟪⌊Advanced usage notes⌋⟫
-
-Counts include with and without BOM variants.
+        let text = "Counts include with and without BOM variants.
 8-bit text files are likely Windows 1252/Latin-1/ANSI or OEM 850/OEM 437, there is no detailed analysis.
 
 ⌊EOL styles⌋:
@@ -76,28 +58,29 @@ Counts include with and without BOM variants.
 - ¬UTF-16 files without BOM
 - ¬Different encodings for a given file type (extension) in a directory
 - ¬Mixed EOL styles in a file
-- ¬Different EOL styles for a given file type (extension) in a directory";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
-        println!();
-        MyMarkup::render_markup(MyGlobSearch::glob_syntax());
+- ¬Different EOL styles for a given file type (extension) in a directory\n\n"
+            .to_string()
+            + MyGlobSearch::glob_syntax();
+
+        let dependencies = [
+            ("MyGlob", MyGlobSearch::version()),
+            ("MyMarkup", MyMarkup::version()),
+            ("TextAutoDecode", TextAutoDecode::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("getopt", env!("DEP_GETOPT_VERSION")),
+            ("coloredt", env!("DEP_COLORED_VERSION")),
+            ("tempfile", env!("DEP_TEMPFILE_VERSION")),
+        ];
+        Self::spec().extended_usage(&dependencies, &text);
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<Options, Box<dyn Error>> {
-        let mut args: Vec<String> = std::env::args().collect();
-        if args.len() > 1 {
-            if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
-
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
-        }
+        // Help and version are processed by RUtilsOptions, default options are inserted
+        let Some(mut args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
         let mut options = Options {
             autorecurse: true,
@@ -110,7 +93,7 @@ Counts include with and without BOM variants.
                 None => break,
                 Some(opt) => match opt {
                     Opt('h', None) | Opt('?', None) => {
-                        Self::usage();
+                        Self::spec().usage();
                         return Err("".into());
                     }
 
@@ -1,14 +1,16 @@
 // runiq: Rust version of uniq
 //
 // 2025-10-31	PV      First version
+// 2026-10-19   PV      1.2.0 Options help, version, defaults and shell completion with RUtilsOptions crate
+// 2026-10-19   PV      1.2.1 Final summary provided by Logging
 
 // Standard library imports
 use std::io;
 use std::process;
-use std::time::Instant;
 
 // External crates imports
 use indexmap::IndexMap;
+use logging::logwriter_none;
 
 // -----------------------------------
 // Submodules
@@ -39,7 +41,8 @@ fn main() {
         process::exit(1);
     });
 
-    let start = Instant::now();
+    // Console only LogWriter, for final summary
+    let writer = logwriter_none();
 
     // First, build a hashpap from stdin
     let lines = build_map(io::stdin().lines(), options.ignore_case);
@@ -52,10 +55,8 @@ fn main() {
         println!("{}", item);
     }
 
-    let duration = start.elapsed();
-
     if options.verbose {
-        println!("Processed in {:.3}s", duration.as_secs_f64());
+        writer.log_summary();
     }
 }
 
@@ -2,6 +2,8 @@
 // Options processing
 //
 // 2025-10-31   PV      First version
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Options described with RUtilsOptions: help, version, defaults and shell completion
 
 // Application imports
 use crate::*;
@@ -12,6 +14,7 @@ use std::error::Error;
 // External crates imports
 use getopt::Opt;
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, ToolSpec};
 
 // Program main output
 #[derive(Debug, Default)]
@@ -30,65 +33,45 @@ pub struct Options {
     pub verbose: bool,
 }
 
-impl Options {
-    fn header() {
-        println!("{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}");
-    }
+const OPTIONS: [OptionDef; 5] = [
+    OptionDef::flag(&["-i"], "Ignore case (default: case sensitive)"),
+    OptionDef::flag(&["-u"], "Output: unique lines only"),
+    OptionDef::flag(&["-d"], "Output: duplicate lines only, one copy only"),
+    OptionDef::flag(&["-D"], "Output: duplicate lines only, all copies"),
+    OptionDef::flag(&["-v"], "Verbose output"),
+];
 
-    fn usage() {
-        Options::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} ¬[⦃?⦄|⦃-?⦄|⦃-h⦄|⦃??⦄|⦃-??⦄] [-i] [-⦃u⦄|-⦃d⦄-⦃D⦄] [-⦃v⦄]
-
-⌊Options⌋:
-⦃?⦄|⦃-?⦄|⦃-h⦄  ¬Show this message
-⦃??⦄|⦃-??⦄   ¬Show advanced usage notes
-⦃-i⦄       ¬Ignore case (default: case sensitive)
-⦃-u⦄       ¬Output: unique lines only
-⦃-d⦄       ¬Output: duplicate lines only, one copy only
-⦃-D⦄       ¬Output: duplicate lines only, all copies
-⦃-v⦄       ¬Verbose output";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl Options {
+    fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⦃-i⦄] [⦃-u⦄|⦃-d⦄|⦃-D⦄] [⦃-v⦄]")
+            .group("Options", &OPTIONS)
     }
 
     fn extended_usage() {
-        Options::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- getopt: {}", env!("DEP_GETOPT_VERSION"));
-        println!();
-
-        let text = "⟪⌊Advanced usage notes⌋⟫
-
-⌊Current limitations⌋:
+        let text = "⌊Current limitations⌋:
 There is no attempt to normalize or denormalize Unicode strings before comparison
 End-of-line is ignored during comparison
 Empty lines are not filtered out
 Lines are not stripped, spaces at the end of a line are significant
 No attempt is made to detect non-text standard input: garbage in, garbage out!";
 
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+        let dependencies = [
+            ("MyMarkup", MyMarkup::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("getopt", env!("DEP_GETOPT_VERSION")),
+        ];
+        Self::spec().extended_usage(&dependencies, text);
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<Options, Box<dyn Error>> {
-        let args: Vec<String> = std::env::args().collect();
-        if args.len() > 1 {
-            if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
-
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
-        }
+        // Help and version are processed by RUtilsOptions, default options are inserted
+        let Some(args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
         let mut options = Options { ..Default::default() };
         let mut opts = getopt::Parser::new(&args, "h?iudDv");
@@ -99,7 +82,7 @@ No attempt is made to detect non-text standard input: garbage in, garbage out!";
                 None => break,
                 Some(opt) => match opt {
                     Opt('h', None) | Opt('?', None) => {
-                        Self::usage();
+                        Self::spec().usage();
                         return Err("".into());
                     }
 
@@ -7,6 +7,8 @@
 // 2025-05-05   PV      1.2.2 Linux compatibility; Ignore files larger than 1GB
 // 2025-07-10   PV      1.2.3 Get information from Cargo.toml, and use build script build.rs
 // 2025-10-31   PV      1.2.4 fn s(n)
+// 2026-10-19   PV      1.3.0 Options help, version and defaults from config file or RWC_OPTIONS with RUtilsOptions crate
+// 2026-10-19   PV      1.3.1 Plural helper and final summary provided by Logging
 
 //#![allow(unused)]
 
@@ -14,9 +16,9 @@
 use std::io;
 use std::path::Path;
 use std::process;
-use std::time::Instant;
 
 // External crates imports
+use logging::logwriter_none;
 use myglob::{MyGlobMatch, MyGlobSearch};
 use textautodecode::{TextAutoDecode, TextFileEncoding};
 
@@ -58,7 +60,8 @@ fn main() {
         process::exit(1);
     });
 
-    let start = Instant::now();
+    // Console only LogWriter, for final summary
+    let writer = logwriter_none();
 
     let mut b = DataBag { ..Default::default() };
 
@@ -98,7 +101,6 @@ fn main() {
         let s = io::read_to_string(io::stdin()).unwrap();
         process_text(&mut b, s.as_str(), "(stdin)", &options, s.len());
     }
-    let duration = start.elapsed();
 
     if b.files_count > 1 || options.show_only_total {
         let mut name = String::from("total");
@@ -109,14 +111,11 @@ fn main() {
     }
 
     if options.verbose {
-        println!("{} file{} searched in {:.3}s", b.files_count, s(b.files_count), duration.as_secs_f64());
+        writer.add_count("file searched", b.files_count as u64);
+        writer.log_summary();
     }
 }
 
-fn s(n: usize) -> &'static str {
-    if n > 1 { "s" } else { "" }
-}
-
 fn print_line(lines_count: usize, words_count: usize, chars_count: usize, bytes_count: usize, filename: &str) {
     println!("{:7} {:7} {:8} {:8}  {}", lines_count, words_count, chars_count, bytes_count, filename);
 }
@@ -2,15 +2,17 @@
 //
 // 2025-10-30	PV      First version
 // 2025-11-04	PV      1.0.1 Fixed arguments with space thet got "overquoted"
+// 2026-10-19   PV      1.1.0 Options help, version, defaults and shell completion with RUtilsOptions crate
+// 2026-10-19   PV      1.1.1 Plural helper, grouped execution duration and final summary provided by Logging
 
 //#![allow(unused)]
 
 // Standard library imports
 use std::process;
-use std::time::Instant;
 use std::{io, path::Path};
 
 // External crates imports
+use logging::logwriter_none;
 use textautodecode::{TextAutoDecode, TextFileEncoding};
 
 // -----------------------------------
@@ -50,7 +52,8 @@ fn main() {
         process::exit(1);
     });
 
-    let start = Instant::now();
+    // Console only LogWriter, for final summary
+    let writer = logwriter_none();
 
     let mut b = DataBag { ..Default::default() };
 
@@ -84,6 +87,7 @@ fn main() {
 
     // For grouped execution, we process all lines at the end for now
     if options.group_args {
+        let _span = writer.span("execution");
         let chunks = options.ctr.make_chunks(&b.lines, 7500);
         for chunk in chunks.iter() {
             match chunk.exec(false) {
@@ -99,18 +103,12 @@ fn main() {
         }
     }
 
-    let duration = start.elapsed();
-
     if options.verbose {
-        println!("{} lines{} processed in {:.3}s", b.line_count, s(b.line_count), duration.as_secs_f64());
+        writer.add_count("line processed", b.line_count as u64);
+        writer.log_summary();
     }
 }
 
-// Helper for plurals
-fn s(n: usize) -> &'static str {
-    if n > 1 { "s" } else { "" }
-}
-
 fn process_file(path: &Path, options: &Options, b: &mut DataBag) -> io::Result<()> {
     if options.verbose {
         println!("Reading arguments from file {}", path.display());
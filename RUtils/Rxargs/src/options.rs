@@ -2,6 +2,8 @@
 // Options processing
 //
 // 2025-10-30   PV      First version
+// 2026-10-19   PV      Extended help shown with MyMarkup pager
+// 2026-10-19   PV      Options described with RUtilsOptions: help, version, defaults and shell completion
 
 // Application imports
 use crate::*;
@@ -12,6 +14,7 @@ use std::error::Error;
 // External crates imports
 use getopt::Opt;
 use mymarkup::MyMarkup;
+use rutilsoptions::{OptionDef, ToolSpec};
 
 // Dedicated struct to store command line arguments
 #[derive(Debug, Default)]
@@ -22,64 +25,42 @@ pub struct Options {
     pub verbose: bool,
 }
 
-impl Options {
-    fn header() {
-        println!(
-            "{APP_NAME} {APP_VERSION}\n{APP_DESCRIPTION}"
-        );
-    }
-
-    fn usage() {
-        Options::header();
-        println!();
-        let text = "⌊Usage⌋: {APP_NAME} ¬[⦃?⦄|⦃-?⦄|⦃-h⦄|⦃??⦄|⦃-??⦄] [-⦃1⦄] [-⦃a⦄ ⟨file⟩] [-⦃v⦄] ⟨command⟩
+const OPTIONS: [OptionDef; 4] = [
+    OptionDef::flag(&["-1"], "Group arguments and execute one instance per group of arguments length <= 7800 characters"),
+    OptionDef::flag(&["-a"], "Read arguments from ⟨file⟩ instead of standard input").arg("file"),
+    OptionDef::flag(&["-v"], "Verbose output, print the command line on the standard error output before executing it and show final stats"),
+    OptionDef::param(&["command"], "Command to execute, {} is replaced by auto-quoted arguments (or added at the end without {})"),
+];
 
-⌊Options⌋:
-⦃?⦄|⦃-?⦄|⦃-h⦄   ¬Show this message
-⦃??⦄|⦃-??⦄    ¬Show advanced usage notes
-⦃-1⦄        ¬Group arguments and execute one instance per group of arguments length <= 7800 characters
-[-⦃a⦄ ⟨file⟩] ¬Read arguments from ⟨file⟩ instead of standard input
-⦃-v⦄        ¬Verbose output, print the command line on the standard error output before executing it and show final stats
-⟨command⟩   ¬Command to execute, {} is replaced by auto-quoted arguments (or added at the end without {})";
-
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+impl Options {
+    fn spec() -> ToolSpec {
+        ToolSpec::new(APP_NAME, APP_VERSION, APP_DESCRIPTION)
+            .copyright("Copyright ©2025-2026 Pierre Violent")
+            .synopsis("[⦃-1⦄] [⦃-a⦄ ⟨file⟩] [⦃-v⦄] ⟨command⟩")
+            .group("Options", &OPTIONS)
     }
 
     fn extended_usage() {
-        Options::header();
-        println!("Copyright ©2025-2026 Pierre Violent");
-        println!();
-
-        MyMarkup::render_markup("⌊Dependencies⌋:");
-        println!("- MyMarkup: {}", MyMarkup::version());
-        println!("- TextAutoDecode: {}", TextAutoDecode::version());
-        println!("- getopt: {}", env!("DEP_GETOPT_VERSION"));
-        println!();
-
-        let text = "⟪⌊Advanced usage notes⌋⟫
-
-Command starts at the first argument that does not start with - so a command name cannot start with -
+        let text = "Command starts at the first argument that does not start with - so a command name cannot start with -
 When reading arguments from a file using -⦃a⦄ option, text format is automally detected and non-text files are rejected.
 When reading stdin, it's supposed to be valid ASCII or UTF-8 text, other text encoding and non-text input are not detected and rejected: garbage in, garbage out!";
 
-        MyMarkup::render_markup(text.replace("{APP_NAME}", APP_NAME).as_str());
+        let dependencies = [
+            ("MyMarkup", MyMarkup::version()),
+            ("TextAutoDecode", TextAutoDecode::version()),
+            ("RUtilsOptions", rutilsoptions::version()),
+            ("getopt", env!("DEP_GETOPT_VERSION")),
+        ];
+        Self::spec().extended_usage(&dependencies, text);
     }
 
     /// Build a new struct Options analyzing command line parameters.<br/>
     /// Some invalid/inconsistent options or missing arguments return an error.
     pub fn new() -> Result<Options, Box<dyn Error>> {
-        let mut args: Vec<String> = std::env::args().collect();
-        if args.len() > 1 {
-            if args[1] == "?" || args[1] == "-?" || args[1] == "/?" || args[1].to_lowercase() == "help" || args[1].to_lowercase() == "-help" || args[1].to_lowercase() == "/help" {
-                Self::usage();
-                return Err("".into());
-            }
-
-            if args[1] == "??" || args[1] == "-??" || args[1] == "/??" || args[1].to_lowercase() == "--help" {
-                Self::extended_usage();
-                return Err("".into());
-            }
-        }
+        // Help and version are processed by RUtilsOptions, default options are inserted
+        let Some(mut args) = Self::spec().args(Self::extended_usage)? else {
+            return Err("".into());
+        };
 
         let mut options = Options { ..Default::default() };
 
@@ -90,7 +71,7 @@ When reading stdin, it's supposed to be valid ASCII or UTF-8 text, other text en
                 None => break,
                 Some(opt) => match opt {
                     Opt('h', None) | Opt('?', None) => {
-                        Self::usage();
+                        Self::spec().usage();
                         return Err("".into());
                     }
 